@@ -0,0 +1,66 @@
+use serde::{Deserialize, Serialize};
+
+/// What the launcher found out about a RustFS binary.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BinaryInfo {
+    pub path: String,
+    /// Found by the launcher's own discovery rather than picked by the user.
+    pub bundled: bool,
+    /// Reported by `--version`, if the binary could be run.
+    pub version: Option<String>,
+    /// Platform and architecture read from the executable header, in
+    /// `std::env::consts` terms (`linux`, `macos`, `windows`; `x86_64`, ...).
+    pub os: Option<String>,
+    pub arch: Option<String>,
+    /// Why this binary might not run here, e.g. it was built for another
+    /// platform.
+    pub warning: Option<String>,
+}
+
+/// A RustFS release unpacked into the launcher's managed binaries directory.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InstalledVersion {
+    pub version: String,
+    pub path: String,
+    /// File name of the archive it was installed from.
+    pub source: String,
+    /// RFC 3339.
+    pub installed_at: String,
+    pub os: Option<String>,
+    pub arch: Option<String>,
+}
+
+/// Installed versions, newest install first, plus which one launches.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct VersionList {
+    pub versions: Vec<InstalledVersion>,
+    pub active: Option<String>,
+    /// What `active` was before the last switch; the rollback target.
+    pub previous: Option<String>,
+}
+
+/// Result of comparing the active binary against the releases feed.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UpdateInfo {
+    /// Version of the binary that launches now, if it could be read.
+    pub current: Option<String>,
+    /// Newest version in the feed.
+    pub latest: String,
+    pub available: bool,
+    /// Release page, for the changelog.
+    pub release_url: Option<String>,
+    /// Archive for this platform, if the release ships one.
+    pub asset: Option<String>,
+}
+
+/// What an upgrade ended up doing.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UpgradeOutcome {
+    pub from: Option<String>,
+    pub to: String,
+    /// Instances stopped and started again on the new binary.
+    pub restarted: Vec<String>,
+    /// Set when the new binary failed readiness and the old one was restored.
+    pub rolled_back: bool,
+    pub message: String,
+}
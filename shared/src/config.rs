@@ -0,0 +1,129 @@
+use serde::{Deserialize, Serialize};
+
+pub const DEFAULT_PORT: u16 = 9000;
+pub const DEFAULT_HOST: &str = "127.0.0.1";
+pub const DEFAULT_ACCESS_KEY: &str = "rustfsadmin";
+pub const DEFAULT_SECRET_KEY: &str = "rustfsadmin";
+pub const DEFAULT_SHUTDOWN_GRACE_SECS: u64 = 10;
+pub const DEFAULT_STARTUP_TIMEOUT_SECS: u64 = 30;
+pub const DEFAULT_HEALTH_CHECK_PATH: &str = "/health";
+
+/// Schema version of `RustFsConfig`. Bump it together with a new migration
+/// step in the backend whenever a field is added, renamed, moved or
+/// reinterpreted.
+pub const CONFIG_VERSION: u32 = 3;
+
+/// Which RustFS binary a config launches.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum BinarySource {
+    /// The active version from the version manager, or the bundled binary
+    /// while none is active.
+    #[default]
+    Managed,
+    /// The binary bundled with the launcher, even when a managed version is
+    /// active.
+    Bundled,
+    /// The binary at `binary_path`.
+    Custom,
+}
+
+/// When the supervisor should bring RustFS back after it exits on its own.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum RestartPolicy {
+    #[default]
+    Never,
+    OnFailure,
+    Always,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(default)]
+pub struct SupervisorConfig {
+    pub restart_policy: RestartPolicy,
+    /// Delay before the first restart; doubled for every restart in the window.
+    pub initial_backoff_ms: u64,
+    pub max_backoff_ms: u64,
+    /// Restarts allowed within `restart_window_secs` before giving up.
+    pub max_restarts: u32,
+    pub restart_window_secs: u64,
+}
+
+impl Default for SupervisorConfig {
+    fn default() -> Self {
+        Self {
+            restart_policy: RestartPolicy::Never,
+            initial_backoff_ms: 1000,
+            max_backoff_ms: 30_000,
+            max_restarts: 5,
+            restart_window_secs: 300,
+        }
+    }
+}
+
+/// Everything needed to launch one RustFS server.
+///
+/// Missing fields take their defaults, but `None` is always written out as
+/// `null` so that a cleared port or credential survives the trip to the
+/// other side instead of turning back into the default.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(default)]
+pub struct RustFsConfig {
+    pub version: u32,
+    pub binary_source: BinarySource,
+    /// Custom RustFS binary, launched when `binary_source` is `Custom`.
+    pub binary_path: Option<String>,
+    pub data_path: String,
+    pub port: Option<u16>,
+    /// Accept a port below 1024, which needs root or `CAP_NET_BIND_SERVICE`
+    /// on Unix.
+    pub allow_privileged_port: bool,
+    pub host: Option<String>,
+    pub access_key: Option<String>,
+    pub secret_key: Option<String>,
+    pub console_enable: bool,
+    /// Hand the credentials to RustFS through `RUSTFS_ACCESS_KEY` and
+    /// `RUSTFS_SECRET_KEY` instead of argv, where any local user can read them.
+    pub credentials_via_env: bool,
+    /// Seconds to wait after asking RustFS to shut down before killing it.
+    pub shutdown_grace_secs: u64,
+    pub supervisor: SupervisorConfig,
+    /// How long a launch waits for RustFS to answer before giving up.
+    pub startup_timeout_secs: u64,
+    pub health_check_path: String,
+}
+
+impl RustFsConfig {
+    /// A copy that is safe to write to the logs.
+    pub fn redacted(&self) -> Self {
+        let mask = |key: &Option<String>| key.as_ref().map(|_| "********".to_string());
+        Self {
+            access_key: mask(&self.access_key),
+            secret_key: mask(&self.secret_key),
+            ..self.clone()
+        }
+    }
+}
+
+impl Default for RustFsConfig {
+    fn default() -> Self {
+        Self {
+            version: CONFIG_VERSION,
+            binary_source: BinarySource::Managed,
+            binary_path: None,
+            data_path: String::new(),
+            port: Some(DEFAULT_PORT),
+            allow_privileged_port: false,
+            host: Some(DEFAULT_HOST.to_string()),
+            access_key: Some(DEFAULT_ACCESS_KEY.to_string()),
+            secret_key: Some(DEFAULT_SECRET_KEY.to_string()),
+            console_enable: false,
+            credentials_via_env: true,
+            shutdown_grace_secs: DEFAULT_SHUTDOWN_GRACE_SECS,
+            supervisor: SupervisorConfig::default(),
+            startup_timeout_secs: DEFAULT_STARTUP_TIMEOUT_SECS,
+            health_check_path: DEFAULT_HEALTH_CHECK_PATH.to_string(),
+        }
+    }
+}
@@ -0,0 +1,55 @@
+use serde::{Deserialize, Serialize};
+
+/// Machine-readable kind of a failed command, so the UI can react to a
+/// failure without matching on its message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ErrorCode {
+    Io,
+    DataPathRequired,
+    DataPathNotExist,
+    DataPathInUse,
+    BinaryNotFound,
+    BinaryFailed,
+    InstanceRunning,
+    InstanceNotFound,
+    OrphanNotFound,
+    PortInUse,
+    InvalidHost,
+    CannotBind,
+    FailedToStart,
+    InvalidRedactionPattern,
+    InvalidConfig,
+    ConfigTooNew,
+    ConfigStore,
+    ProfileNameRequired,
+    ProfileNotFound,
+    ProfileExists,
+    InvalidImport,
+    UnresolvedPlaceholder,
+    CredentialsLocked,
+    CredentialStore,
+    InvalidArchive,
+    VersionNotInstalled,
+    NoPreviousVersion,
+    DownloadFailed,
+    ChecksumMismatch,
+    InvalidFeed,
+    NoReleaseAsset,
+    LogFileNotFound,
+}
+
+/// What a command rejects with.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommandError {
+    pub code: ErrorCode,
+    pub message: String,
+}
+
+impl std::fmt::Display for CommandError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for CommandError {}
@@ -0,0 +1,115 @@
+//! Names and payloads of the events the backend emits to the UI.
+
+use serde::{Deserialize, Serialize};
+
+/// A launcher log record (payload: `LogRecord`).
+pub const APP_LOG: &str = "app-log";
+/// A RustFS output record, `instance` set (payload: `LogRecord`).
+pub const RUSTFS_LOG: &str = "rustfs-log";
+pub const RUSTFS_EXIT: &str = "rustfs-exit";
+pub const RUSTFS_STATE: &str = "rustfs-state";
+pub const RUSTFS_SUPERVISOR: &str = "rustfs-supervisor";
+pub const RUSTFS_LIFECYCLE: &str = "rustfs-lifecycle";
+/// Progress of a release download (payload: `DownloadEvent`).
+pub const RUSTFS_DOWNLOAD: &str = "rustfs-download";
+
+/// Lifecycle of one instance. The backend is the single source of truth;
+/// every transition is broadcast as a `rustfs-state` event. Timestamps are
+/// RFC 3339 strings.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "state", rename_all = "kebab-case")]
+pub enum ProcessState {
+    Stopped,
+    Starting,
+    Running { pid: u32, since: String },
+    Stopping,
+    Crashed { code: Option<i32>, at: String },
+    Restarting,
+}
+
+impl ProcessState {
+    /// Whether a process exists or is about to, so launching again is refused.
+    pub fn is_active(&self) -> bool {
+        !matches!(self, ProcessState::Stopped | ProcessState::Crashed { .. })
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ProcessState::Stopped => "Service Offline",
+            ProcessState::Starting => "Starting...",
+            ProcessState::Running { .. } => "Service Online",
+            ProcessState::Stopping => "Stopping...",
+            ProcessState::Crashed { .. } => "Crashed",
+            ProcessState::Restarting => "Restarting...",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InstanceStateEvent {
+    pub instance: String,
+    #[serde(flatten)]
+    pub state: ProcessState,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InstanceExitEvent {
+    pub instance: String,
+    pub status: String,
+    /// Whether the exit is a failure: a non-zero status while no stop was
+    /// requested.
+    #[serde(default)]
+    pub unexpected: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum SupervisorState {
+    Idle,
+    Restarting,
+    CrashLoop,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SupervisorEvent {
+    pub instance: String,
+    pub state: SupervisorState,
+    pub attempt: u32,
+    pub delay_ms: u64,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum LifecyclePhase {
+    Starting,
+    Ready,
+    FailedToStart,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LifecycleEvent {
+    pub instance: String,
+    pub phase: LifecyclePhase,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum DownloadPhase {
+    Downloading,
+    Installing,
+    Finished,
+    Failed,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DownloadEvent {
+    /// File name of the release archive being fetched.
+    pub asset: String,
+    pub phase: DownloadPhase,
+    pub downloaded: u64,
+    /// Size announced by the server, if it sent one.
+    pub total: Option<u64>,
+    pub message: String,
+}
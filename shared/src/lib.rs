@@ -0,0 +1,25 @@
+//! Types exchanged between the Leptos UI and the Tauri backend. Both sides
+//! build against this crate so a command or event payload cannot change on
+//! one side without the other noticing at compile time.
+
+pub mod binary;
+pub mod config;
+pub mod error;
+pub mod events;
+pub mod logs;
+pub mod validation;
+
+pub use binary::{BinaryInfo, InstalledVersion, UpdateInfo, UpgradeOutcome, VersionList};
+pub use config::RustFsConfig;
+pub use error::{CommandError, ErrorCode};
+pub use logs::{LogCapacity, LogField, LogFileInfo, LogLevel, LogRecord, LogStream};
+pub use validation::{ConfigField, ValidationReport};
+
+use serde::{Deserialize, Serialize};
+
+/// Reply of commands that only report whether they worked.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommandResponse {
+    pub success: bool,
+    pub message: String,
+}
@@ -0,0 +1,173 @@
+//! Log records the backend buffers and broadcasts, and the log files it
+//! keeps on disk.
+
+use serde::{Deserialize, Serialize};
+
+/// Severity of a log record, least severe first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LogLevel {
+    Trace,
+    Debug,
+    Info,
+    Warn,
+    Error,
+}
+
+impl LogLevel {
+    pub fn label(&self) -> &'static str {
+        match self {
+            LogLevel::Trace => "TRACE",
+            LogLevel::Debug => "DEBUG",
+            LogLevel::Info => "INFO",
+            LogLevel::Warn => "WARN",
+            LogLevel::Error => "ERROR",
+        }
+    }
+
+    /// Reads a level name such as `warn`, `WARNING` or `err`.
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "trace" => Some(LogLevel::Trace),
+            "debug" => Some(LogLevel::Debug),
+            "info" => Some(LogLevel::Info),
+            "warn" | "warning" => Some(LogLevel::Warn),
+            "error" | "err" | "fatal" => Some(LogLevel::Error),
+            _ => None,
+        }
+    }
+
+    /// Splits a leading `[LEVEL]` tag off `message`, as in `[ERROR] it broke`.
+    pub fn split_tag(message: &str) -> (Option<Self>, &str) {
+        message
+            .strip_prefix('[')
+            .and_then(|rest| rest.split_once(']'))
+            .and_then(|(tag, rest)| Some((Self::parse(tag)?, rest.trim_start())))
+            .map_or((None, message), |(level, rest)| (Some(level), rest))
+    }
+}
+
+/// Where a log record came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LogStream {
+    /// The launcher itself, including notes it makes about an instance.
+    Launcher,
+    Stdout,
+    Stderr,
+    /// RustFS's own log files, tailed for adopted processes.
+    File,
+}
+
+impl LogStream {
+    pub fn label(&self) -> &'static str {
+        match self {
+            LogStream::Launcher => "launcher",
+            LogStream::Stdout => "stdout",
+            LogStream::Stderr => "stderr",
+            LogStream::File => "file",
+        }
+    }
+}
+
+/// One log line with everything known about it. Payload of `app-log` and
+/// `rustfs-log`, and what `get_app_logs` / `get_rustfs_logs` return.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LogRecord {
+    /// Increases by one per record across all logs of a session, so records
+    /// sort and dedupe by it.
+    pub seq: u64,
+    /// When the record was made, RFC 3339 with milliseconds.
+    pub timestamp: String,
+    pub stream: LogStream,
+    pub level: LogLevel,
+    /// The instance it concerns; `None` for the launcher log.
+    pub instance: Option<String>,
+    pub message: String,
+    /// Module path RustFS logged from, e.g. `rustfs::server`.
+    #[serde(default)]
+    pub target: Option<String>,
+    /// Spans the line was logged in, outermost first, as
+    /// `name{field=value}:name`.
+    #[serde(default)]
+    pub span: Option<String>,
+    /// Structured fields logged besides the message.
+    #[serde(default)]
+    pub fields: Vec<LogField>,
+}
+
+impl LogRecord {
+    /// `HH:MM:SS` of the timestamp.
+    pub fn time_of_day(&self) -> &str {
+        self.timestamp.get(11..19).unwrap_or(&self.timestamp)
+    }
+
+    /// The message followed by its fields as `name=value`, on one line.
+    pub fn text(&self) -> String {
+        let mut text = self.message.clone();
+        for field in &self.fields {
+            if !text.is_empty() {
+                text.push(' ');
+            }
+            text.push_str(&field.name);
+            text.push('=');
+            if field.value.is_empty() || field.value.contains(char::is_whitespace) {
+                text.push_str(&format!("{:?}", field.value));
+            } else {
+                text.push_str(&field.value);
+            }
+        }
+        text
+    }
+}
+
+/// A `name=value` pair attached to a log line.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LogField {
+    pub name: String,
+    pub value: String,
+}
+
+/// How many records the backend keeps in memory for the launcher log and for
+/// each instance's output. Older records are still in the log files.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LogCapacity {
+    pub app: usize,
+    pub rustfs: usize,
+}
+
+impl LogCapacity {
+    pub const MIN: usize = 100;
+    pub const MAX: usize = 100_000;
+
+    /// Both limits brought within [`Self::MIN`]..=[`Self::MAX`].
+    pub fn clamped(self) -> Self {
+        Self {
+            app: self.app.clamp(Self::MIN, Self::MAX),
+            rustfs: self.rustfs.clamp(Self::MIN, Self::MAX),
+        }
+    }
+}
+
+impl Default for LogCapacity {
+    fn default() -> Self {
+        Self {
+            app: 1_000,
+            rustfs: 10_000,
+        }
+    }
+}
+
+/// One rotated log file under the app log directory.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LogFileInfo {
+    /// File name, which is also how the file is asked for.
+    pub name: String,
+    /// `launcher` for the app log, `rustfs-<instance>` for RustFS output.
+    pub stream: String,
+    pub size: u64,
+    /// When the file was last written, RFC 3339.
+    pub modified: String,
+    /// Whether this session is still writing to it.
+    pub active: bool,
+}
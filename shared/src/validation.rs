@@ -0,0 +1,76 @@
+use serde::{Deserialize, Serialize};
+
+/// Shortest access key RustFS accepts.
+pub const MIN_ACCESS_KEY_LEN: usize = 3;
+/// Shortest secret key RustFS accepts.
+pub const MIN_SECRET_KEY_LEN: usize = 8;
+
+/// The `RustFsConfig` field an issue belongs to, so the form can show it
+/// next to the matching input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConfigField {
+    BinaryPath,
+    DataPath,
+    Host,
+    Port,
+    AccessKey,
+    SecretKey,
+    ShutdownGraceSecs,
+    StartupTimeoutSecs,
+    HealthCheckPath,
+    Supervisor,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Severity {
+    /// RustFS cannot be launched with this value.
+    Error,
+    /// RustFS can be launched, but probably not the way the user expects.
+    Warning,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ValidationIssue {
+    pub field: ConfigField,
+    pub severity: Severity,
+    pub message: String,
+}
+
+/// Result of `validate_config`: every problem found, not just the first.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ValidationReport {
+    pub issues: Vec<ValidationIssue>,
+}
+
+impl ValidationReport {
+    pub fn error(&mut self, field: ConfigField, message: impl Into<String>) {
+        self.push(field, Severity::Error, message.into());
+    }
+
+    pub fn warning(&mut self, field: ConfigField, message: impl Into<String>) {
+        self.push(field, Severity::Warning, message.into());
+    }
+
+    fn push(&mut self, field: ConfigField, severity: Severity, message: String) {
+        self.issues.push(ValidationIssue {
+            field,
+            severity,
+            message,
+        });
+    }
+
+    pub fn has_errors(&self) -> bool {
+        self.issues.iter().any(|i| i.severity == Severity::Error)
+    }
+
+    pub fn for_field(&self, field: ConfigField) -> impl Iterator<Item = &ValidationIssue> {
+        self.issues.iter().filter(move |i| i.field == field)
+    }
+
+    /// Drops the issues of `field`, e.g. once the user edits it.
+    pub fn clear(&mut self, field: ConfigField) {
+        self.issues.retain(|i| i.field != field);
+    }
+}
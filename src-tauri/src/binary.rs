@@ -0,0 +1,276 @@
+use crate::config::BinarySource;
+use crate::error::{Error, Result};
+use crate::process;
+use crate::state::add_app_log;
+use crate::versions;
+use lazy_static::lazy_static;
+use regex::Regex;
+use rustfs_launcher_shared::BinaryInfo;
+use std::io::Read;
+use std::path::Path;
+use std::process::{Command, Stdio};
+use std::time::{Duration, Instant};
+
+/// How long a binary gets to answer `--version` before it is killed.
+const VERSION_TIMEOUT: Duration = Duration::from_secs(5);
+
+lazy_static! {
+    static ref VERSION_REGEX: Regex =
+        Regex::new(r"\d+\.\d+(?:\.\d+)?(?:-[0-9A-Za-z.-]+)?(?:\+[0-9A-Za-z.-]+)?").unwrap();
+}
+
+/// Platform and architecture an executable was built for, as far as its
+/// header tells. A universal Mach-O binary has no single architecture.
+struct Target {
+    os: &'static str,
+    arch: Option<&'static str>,
+}
+
+fn elf_arch(machine: u16) -> Option<&'static str> {
+    match machine {
+        0x03 => Some("x86"),
+        0x28 => Some("arm"),
+        0x3E => Some("x86_64"),
+        0xB7 => Some("aarch64"),
+        0xF3 => Some("riscv64"),
+        _ => None,
+    }
+}
+
+fn mach_o_arch(cpu_type: u32) -> Option<&'static str> {
+    match cpu_type {
+        0x0100_0007 => Some("x86_64"),
+        0x0100_000C => Some("aarch64"),
+        _ => None,
+    }
+}
+
+fn pe_arch(machine: u16) -> Option<&'static str> {
+    match machine {
+        0x014C => Some("x86"),
+        0x8664 => Some("x86_64"),
+        0xAA64 => Some("aarch64"),
+        _ => None,
+    }
+}
+
+/// Reads the target of an ELF, Mach-O or PE executable from its header.
+fn read_target(path: &Path) -> std::io::Result<Option<Target>> {
+    let mut header = Vec::with_capacity(4096);
+    std::fs::File::open(path)?
+        .take(4096)
+        .read_to_end(&mut header)?;
+    let u16_at = |offset: usize, little_endian: bool| {
+        let bytes = [*header.get(offset)?, *header.get(offset + 1)?];
+        Some(if little_endian {
+            u16::from_le_bytes(bytes)
+        } else {
+            u16::from_be_bytes(bytes)
+        })
+    };
+    let u32_le_at = |offset: usize| {
+        let bytes = header.get(offset..offset + 4)?;
+        Some(u32::from_le_bytes(bytes.try_into().ok()?))
+    };
+
+    let target = match header.get(..4) {
+        Some([0x7F, b'E', b'L', b'F']) => {
+            let little_endian = header.get(5) == Some(&1);
+            Some(Target {
+                os: "linux",
+                arch: u16_at(18, little_endian).and_then(elf_arch),
+            })
+        }
+        Some([0xCF, 0xFA, 0xED, 0xFE]) => Some(Target {
+            os: "macos",
+            arch: u32_le_at(4).and_then(mach_o_arch),
+        }),
+        Some([0xCA, 0xFE, 0xBA, 0xBE]) => Some(Target {
+            os: "macos",
+            arch: None,
+        }),
+        // A wrapper script runs wherever its interpreter does.
+        Some([b'#', b'!', ..]) => Some(Target {
+            os: std::env::consts::OS,
+            arch: None,
+        }),
+        Some([b'M', b'Z', ..]) => {
+            let pe_offset = u32_le_at(0x3C).map(|o| o as usize);
+            let machine = pe_offset
+                .filter(|&o| header.get(o..o + 4) == Some(b"PE\0\0"))
+                .and_then(|o| u16_at(o + 4, true));
+            Some(Target {
+                os: "windows",
+                arch: machine.and_then(pe_arch),
+            })
+        }
+        _ => None,
+    };
+    Ok(target)
+}
+
+/// Explains why a binary built for `target` may not run on this host.
+fn compatibility_warning(target: Option<&Target>) -> Option<String> {
+    use std::env::consts::{ARCH, OS};
+
+    let Some(target) = target else {
+        return Some("Not a recognised executable format".to_string());
+    };
+    if target.os != OS {
+        return Some(format!(
+            "Built for {}, but this machine runs {}",
+            target.os, OS
+        ));
+    }
+    match target.arch {
+        Some(arch) if arch != ARCH => {
+            // Both platforms run x86_64 binaries under emulation on ARM.
+            let emulated = arch == "x86_64" && ARCH == "aarch64" && OS != "linux";
+            Some(if emulated {
+                format!(
+                    "Built for {}; it will run under emulation on {}",
+                    arch, ARCH
+                )
+            } else {
+                format!("Built for {}, but this machine is {}", arch, ARCH)
+            })
+        }
+        _ => None,
+    }
+}
+
+/// Runs `--version`, killing a binary that has not exited within `timeout`.
+fn read_version(path: &Path, timeout: Duration) -> std::result::Result<String, String> {
+    let mut child = Command::new(path)
+        .arg("--version")
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| e.to_string())?;
+    // Drained on their own threads so a chatty binary cannot block on a full pipe.
+    let drain = |pipe: Option<Box<dyn Read + Send>>| {
+        std::thread::spawn(move || {
+            let mut output = Vec::new();
+            if let Some(mut pipe) = pipe {
+                let _ = pipe.read_to_end(&mut output);
+            }
+            output
+        })
+    };
+    let stdout = drain(child.stdout.take().map(|p| Box::new(p) as _));
+    let stderr = drain(child.stderr.take().map(|p| Box::new(p) as _));
+
+    let deadline = Instant::now() + timeout;
+    let status = loop {
+        if let Some(status) = child.try_wait().map_err(|e| e.to_string())? {
+            break status;
+        }
+        if Instant::now() >= deadline {
+            let _ = child.kill();
+            let _ = child.wait();
+            return Err(format!(
+                "--version did not answer within {}s",
+                timeout.as_secs_f32()
+            ));
+        }
+        std::thread::sleep(Duration::from_millis(20));
+    };
+    let stdout = stdout.join().unwrap_or_default();
+    let stderr = stderr.join().unwrap_or_default();
+    let stdout = String::from_utf8_lossy(&stdout);
+    let stderr = String::from_utf8_lossy(&stderr);
+    VERSION_REGEX
+        .find(&stdout)
+        .or_else(|| VERSION_REGEX.find(&stderr))
+        .map(|m| m.as_str().to_string())
+        .ok_or_else(|| {
+            let first_line = stdout.lines().next().unwrap_or_default().trim().to_string();
+            if first_line.is_empty() {
+                format!("--version exited with {}", status)
+            } else {
+                format!("unrecognised --version output '{}'", first_line)
+            }
+        })
+}
+
+/// Describes the binary `source` selects, see `process::resolve_binary_path`.
+pub fn inspect(source: BinarySource, binary_path: Option<&str>) -> Result<BinaryInfo> {
+    let path = process::resolve_binary_path(source, binary_path)?;
+    if !path.is_file() {
+        return Err(Error::BinaryNotFound(path.to_string_lossy().to_string()));
+    }
+
+    let target = read_target(&path)?;
+    let mut warning = compatibility_warning(target.as_ref());
+    let version = match read_version(&path, VERSION_TIMEOUT) {
+        Ok(version) => Some(version),
+        Err(e) => {
+            warning.get_or_insert_with(|| format!("Could not read the version: {}", e));
+            None
+        }
+    };
+
+    add_app_log(format!(
+        "RustFS binary {}: version {}, built for {}-{}",
+        path.display(),
+        version.as_deref().unwrap_or("unknown"),
+        target.as_ref().map(|t| t.os).unwrap_or("unknown"),
+        target.as_ref().and_then(|t| t.arch).unwrap_or("unknown")
+    ));
+    // `Managed` only resolves to something other than the active version by
+    // falling back to the bundled binary.
+    let bundled = match source {
+        BinarySource::Managed => versions::active_binary().as_ref() != Some(&path),
+        BinarySource::Bundled => true,
+        BinarySource::Custom => false,
+    };
+    Ok(BinaryInfo {
+        path: path.to_string_lossy().to_string(),
+        bundled,
+        version,
+        os: target.as_ref().map(|t| t.os.to_string()),
+        arch: target.and_then(|t| t.arch).map(str::to_string),
+        warning,
+    })
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+
+    fn script(dir: &Path, body: &str) -> std::path::PathBuf {
+        use std::os::unix::fs::PermissionsExt;
+        let path = dir.join("rustfs");
+        std::fs::write(&path, format!("#!/bin/sh\n{}\n", body)).unwrap();
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o755)).unwrap();
+        path
+    }
+
+    #[test]
+    fn reads_the_version_from_either_stream() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = script(dir.path(), "echo 'rustfs 1.0.0-alpha.42'");
+        assert_eq!(
+            read_version(&path, VERSION_TIMEOUT).as_deref(),
+            Ok("1.0.0-alpha.42")
+        );
+        let path = script(dir.path(), "echo 'rustfs version 2.1' >&2");
+        assert_eq!(read_version(&path, VERSION_TIMEOUT).as_deref(), Ok("2.1"));
+        let path = script(dir.path(), "echo 'usage: rustfs'; exit 2");
+        assert!(read_version(&path, VERSION_TIMEOUT)
+            .unwrap_err()
+            .contains("usage: rustfs"));
+    }
+
+    #[test]
+    fn gives_up_on_a_binary_that_hangs() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = script(dir.path(), "exec sleep 30");
+
+        let started = Instant::now();
+        let err = read_version(&path, Duration::from_millis(200)).unwrap_err();
+        assert!(err.contains("did not answer"), "{err}");
+        assert!(started.elapsed() < Duration::from_secs(10));
+    }
+}
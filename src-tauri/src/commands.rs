@@ -1,25 +1,193 @@
-use crate::config::RustFsConfig;
+use crate::binary;
+use crate::config::{self, BinarySource, ProfileList, RustFsConfig};
+use crate::config_io::{self, ImportSummary, SecretMode};
+use crate::credentials::{self, CredentialStatus, Credentials};
+use crate::download;
 use crate::error::{Error, Result};
+use crate::log_files;
+use crate::pidfile::{self, PidRecord};
+use crate::port::{self, PortCheck};
 use crate::process;
-use crate::state;
+use crate::redact;
+use crate::state::{self, InstanceInfo, ProcessState, StopOutcome};
+use crate::supervisor;
+use crate::updates;
+use crate::validation;
+use crate::versions;
 use serde::Serialize;
 use std::io::{Error as IoError, ErrorKind};
+use std::time::Duration;
 use tauri::async_runtime;
 
+pub use rustfs_launcher_shared::CommandResponse;
+use rustfs_launcher_shared::{
+    BinaryInfo, InstalledVersion, LogCapacity, LogFileInfo, LogRecord, UpdateInfo, UpgradeOutcome,
+    ValidationReport, VersionList,
+};
+
+#[tauri::command]
+pub async fn launch_rustfs(
+    instance: Option<String>,
+    config: RustFsConfig,
+    profile: Option<String>,
+) -> Result<CommandResponse> {
+    let instance = instance.unwrap_or_else(|| state::DEFAULT_INSTANCE.to_string());
+    let config = credentials::apply(&instance, config);
+    if let Err(e) = config::save_config(&instance, &config) {
+        state::add_app_log(format!("Failed to save config: {}", e));
+    }
+    // Drop any earlier supervision (and a restart it has pending) so that a
+    // process dying during startup is reported as a failed launch rather
+    // than restarted. The new process is supervised once it is ready.
+    if !state::is_instance_running(&instance) {
+        supervisor::cancel(&instance);
+    }
+    let name = instance.clone();
+    let supervised = config.clone();
+    let handle = async_runtime::spawn_blocking(move || process::launch(&name, config));
+    let message = handle.await.map_err(|err| {
+        let io_error = IoError::other(err.to_string());
+        Error::Io(io_error)
+    })??;
+    supervisor::start(&instance, supervised);
+    state::set_instance_profile(&instance, profile);
+
+    Ok(CommandResponse {
+        success: true,
+        message,
+    })
+}
+
 #[derive(Debug, Serialize)]
-pub struct CommandResponse {
+pub struct StopResponse {
     pub success: bool,
     pub message: String,
+    pub outcome: StopOutcome,
 }
 
 #[tauri::command]
-pub async fn launch_rustfs(config: RustFsConfig) -> Result<CommandResponse> {
-    let handle = async_runtime::spawn_blocking(move || process::launch(config));
-    let message = handle.await.map_err(|err| {
+pub async fn stop_rustfs(
+    instance: Option<String>,
+    grace_period_secs: Option<u64>,
+) -> Result<StopResponse> {
+    let instance = instance.unwrap_or_else(|| state::DEFAULT_INSTANCE.to_string());
+    let handle = async_runtime::spawn_blocking(move || match grace_period_secs {
+        Some(secs) => {
+            state::terminate_rustfs_process_with_grace(&instance, Duration::from_secs(secs))
+        }
+        None => state::terminate_rustfs_process(&instance),
+    });
+    let outcome = handle.await.map_err(|err| {
         let io_error = IoError::other(err.to_string());
         Error::Io(io_error)
-    })??;
+    })?;
+
+    Ok(StopResponse {
+        success: outcome != StopOutcome::Failed,
+        message: outcome.describe().to_string(),
+        outcome,
+    })
+}
+
+#[tauri::command]
+pub async fn get_config(instance: Option<String>) -> Result<Option<RustFsConfig>> {
+    let instance = instance.unwrap_or_else(|| state::DEFAULT_INSTANCE.to_string());
+    config::load_config(&instance)
+}
 
+#[tauri::command]
+pub async fn save_config(
+    instance: Option<String>,
+    config: RustFsConfig,
+) -> Result<CommandResponse> {
+    let instance = instance.unwrap_or_else(|| state::DEFAULT_INSTANCE.to_string());
+    let config = credentials::apply(&instance, config);
+    config::save_config(&instance, &config)?;
+    Ok(CommandResponse {
+        success: true,
+        message: format!("Config for '{}' saved", instance),
+    })
+}
+
+#[tauri::command]
+pub async fn list_profiles() -> Result<ProfileList> {
+    config::list_profiles()
+}
+
+/// Returns the profile's config with its stored credentials filled in.
+#[tauri::command]
+pub async fn load_profile(name: String) -> Result<RustFsConfig> {
+    let config = config::load_profile(&name)?;
+    Ok(credentials::apply(&credentials::profile_key(&name), config))
+}
+
+#[tauri::command]
+pub async fn create_profile(name: String, config: RustFsConfig) -> Result<CommandResponse> {
+    credentials::ensure_storable(&config)?;
+    let name = config::create_profile(&name, &config)?;
+    credentials::apply(&credentials::profile_key(&name), config);
+    Ok(CommandResponse {
+        success: true,
+        message: format!("Profile '{}' created", name),
+    })
+}
+
+#[tauri::command]
+pub async fn update_profile(name: String, config: RustFsConfig) -> Result<CommandResponse> {
+    credentials::ensure_storable(&config)?;
+    config::update_profile(&name, &config)?;
+    credentials::apply(&credentials::profile_key(&name), config);
+    Ok(CommandResponse {
+        success: true,
+        message: format!("Profile '{}' saved", name),
+    })
+}
+
+#[tauri::command]
+pub async fn clone_profile(source: String, name: String) -> Result<CommandResponse> {
+    let name = config::clone_profile(&source, &name)?;
+    credentials::copy_entry(
+        &credentials::profile_key(&source),
+        &credentials::profile_key(&name),
+        true,
+    )?;
+    Ok(CommandResponse {
+        success: true,
+        message: format!("Profile '{}' cloned as '{}'", source, name),
+    })
+}
+
+#[tauri::command]
+pub async fn rename_profile(name: String, new_name: String) -> Result<CommandResponse> {
+    let new_name = config::rename_profile(&name, &new_name)?;
+    credentials::copy_entry(
+        &credentials::profile_key(&name),
+        &credentials::profile_key(&new_name),
+        false,
+    )?;
+    Ok(CommandResponse {
+        success: true,
+        message: format!("Profile '{}' renamed to '{}'", name, new_name),
+    })
+}
+
+#[tauri::command]
+pub async fn delete_profile(name: String) -> Result<CommandResponse> {
+    config::delete_profile(&name)?;
+    credentials::remove_entry(&credentials::profile_key(&name))?;
+    Ok(CommandResponse {
+        success: true,
+        message: format!("Profile '{}' deleted", name),
+    })
+}
+
+#[tauri::command]
+pub async fn set_default_profile(name: Option<String>) -> Result<CommandResponse> {
+    config::set_default_profile(name.as_deref())?;
+    let message = match name {
+        Some(name) => format!("'{}' is now the default profile", name),
+        None => "Default profile cleared".to_string(),
+    };
     Ok(CommandResponse {
         success: true,
         message,
@@ -27,23 +195,37 @@ pub async fn launch_rustfs(config: RustFsConfig) -> Result<CommandResponse> {
 }
 
 #[tauri::command]
-pub async fn stop_rustfs() -> Result<CommandResponse> {
-    state::terminate_rustfs_process();
+pub async fn export_config(
+    path: String,
+    config: Option<RustFsConfig>,
+    include_profiles: bool,
+    secrets: Option<SecretMode>,
+) -> Result<CommandResponse> {
+    let secrets = secrets.unwrap_or_default();
+    config_io::export(
+        std::path::Path::new(&path),
+        config,
+        include_profiles,
+        secrets,
+    )?;
     Ok(CommandResponse {
         success: true,
-        message: "RustFS process terminated".to_string(),
+        message: format!("Exported to {}", path),
     })
 }
 
 #[tauri::command]
-pub async fn validate_config(config: RustFsConfig) -> Result<bool> {
-    if config.data_path.is_empty() {
-        return Err(Error::DataPathRequired);
-    }
-    if !std::path::Path::new(&config.data_path).exists() {
-        return Err(Error::DataPathNotExist(config.data_path));
-    }
-    Ok(true)
+pub async fn import_config(path: String) -> Result<ImportSummary> {
+    config_io::import(std::path::Path::new(&path))
+}
+
+#[tauri::command]
+pub async fn validate_config(config: RustFsConfig) -> Result<ValidationReport> {
+    let handle = async_runtime::spawn_blocking(move || validation::validate(&config));
+    handle.await.map_err(|err| {
+        let io_error = IoError::other(err.to_string());
+        Error::Io(io_error)
+    })
 }
 
 #[tauri::command]
@@ -61,13 +243,341 @@ pub async fn diagnose_rustfs_binary() -> Result<CommandResponse> {
 }
 
 #[tauri::command]
-pub async fn get_app_logs() -> Result<Vec<String>> {
+pub async fn inspect_binary(
+    binary_source: BinarySource,
+    binary_path: Option<String>,
+) -> Result<BinaryInfo> {
+    let handle = async_runtime::spawn_blocking(move || {
+        binary::inspect(binary_source, binary_path.as_deref())
+    });
+    handle.await.map_err(|err| {
+        let io_error = IoError::other(err.to_string());
+        Error::Io(io_error)
+    })?
+}
+
+#[tauri::command]
+pub async fn list_versions() -> Result<VersionList> {
+    versions::list()
+}
+
+#[tauri::command]
+pub async fn install_version(archive_path: String) -> Result<InstalledVersion> {
+    let handle = async_runtime::spawn_blocking(move || {
+        versions::install(std::path::Path::new(&archive_path))
+    });
+    handle.await.map_err(|err| {
+        let io_error = IoError::other(err.to_string());
+        Error::Io(io_error)
+    })?
+}
+
+#[tauri::command]
+pub async fn set_active_version(version: String) -> Result<VersionList> {
+    versions::set_active(&version)
+}
+
+#[tauri::command]
+pub async fn rollback_version() -> Result<VersionList> {
+    versions::rollback()
+}
+
+#[tauri::command]
+pub async fn get_release_url() -> Result<String> {
+    download::release_url()
+}
+
+#[tauri::command]
+pub async fn set_release_url(url: String) -> Result<()> {
+    download::set_release_url(&url)
+}
+
+/// Downloads `version` (the latest by default) from the saved release mirror.
+#[tauri::command]
+pub async fn download_version(version: Option<String>) -> Result<InstalledVersion> {
+    let handle = async_runtime::spawn_blocking(move || {
+        let base_url = download::release_url()?;
+        download::download_and_install(Some(&base_url), version.as_deref())
+    });
+    handle.await.map_err(|err| {
+        let io_error = IoError::other(err.to_string());
+        Error::Io(io_error)
+    })?
+}
+
+#[tauri::command]
+pub async fn get_releases_feed() -> Result<String> {
+    updates::releases_feed()
+}
+
+#[tauri::command]
+pub async fn set_releases_feed(url: String) -> Result<()> {
+    updates::set_releases_feed(&url)
+}
+
+#[tauri::command]
+pub async fn check_for_updates(
+    binary_source: BinarySource,
+    binary_path: Option<String>,
+) -> Result<UpdateInfo> {
+    let handle = async_runtime::spawn_blocking(move || {
+        updates::check(binary_source, binary_path.as_deref())
+    });
+    handle.await.map_err(|err| {
+        let io_error = IoError::other(err.to_string());
+        Error::Io(io_error)
+    })?
+}
+
+#[tauri::command]
+pub async fn upgrade_rustfs() -> Result<UpgradeOutcome> {
+    let handle = async_runtime::spawn_blocking(updates::upgrade);
+    handle.await.map_err(|err| {
+        let io_error = IoError::other(err.to_string());
+        Error::Io(io_error)
+    })?
+}
+
+#[tauri::command]
+pub async fn check_credential_env_support(
+    binary_source: BinarySource,
+    binary_path: Option<String>,
+) -> Result<CommandResponse> {
+    let handle = async_runtime::spawn_blocking(move || {
+        process::check_credential_env_support(binary_source, binary_path.as_deref())
+    });
+    let supported = handle.await.map_err(|err| {
+        let io_error = IoError::other(err.to_string());
+        Error::Io(io_error)
+    })??;
+
+    let message = if supported {
+        format!(
+            "The RustFS binary reads {} and {}",
+            process::ACCESS_KEY_ENV,
+            process::SECRET_KEY_ENV
+        )
+    } else {
+        format!(
+            "The RustFS binary does not mention {} or {}; it may ignore credentials passed through the environment",
+            process::ACCESS_KEY_ENV,
+            process::SECRET_KEY_ENV
+        )
+    };
+    Ok(CommandResponse {
+        success: supported,
+        message,
+    })
+}
+
+#[tauri::command]
+pub async fn get_app_logs() -> Result<Vec<LogRecord>> {
     Ok(state::get_app_logs())
 }
 
+/// Adds a message from the UI to the launcher log, so it is numbered and
+/// persisted like the backend's own. A leading `[LEVEL]` tag sets its level.
+#[tauri::command]
+pub async fn log_message(message: String) -> Result<()> {
+    state::add_app_log(message);
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn get_rustfs_logs(instance: Option<String>) -> Result<Vec<LogRecord>> {
+    let instance = instance.unwrap_or_else(|| state::DEFAULT_INSTANCE.to_string());
+    Ok(state::get_rustfs_logs(&instance))
+}
+
+#[tauri::command]
+pub async fn get_log_capacity() -> Result<LogCapacity> {
+    Ok(state::log_capacity())
+}
+
+/// Sets and saves how many records are kept in memory, within the bounds of
+/// [`LogCapacity`]; the limits actually applied are returned.
+#[tauri::command]
+pub async fn set_log_capacity(capacity: LogCapacity) -> Result<LogCapacity> {
+    let applied = state::set_log_capacity(capacity)?;
+    if applied != capacity {
+        state::add_app_log(format!(
+            "[WARN] Log history limited to {}..={} records",
+            LogCapacity::MIN,
+            LogCapacity::MAX
+        ));
+    }
+    Ok(applied)
+}
+
+#[tauri::command]
+pub async fn list_log_files() -> Result<Vec<LogFileInfo>> {
+    Ok(log_files::list())
+}
+
+#[tauri::command]
+pub async fn read_log_file(name: String) -> Result<Vec<String>> {
+    let handle = async_runtime::spawn_blocking(move || log_files::read(&name));
+    handle.await.map_err(|err| {
+        let io_error = IoError::other(err.to_string());
+        Error::Io(io_error)
+    })?
+}
+
+#[tauri::command]
+pub async fn get_status(instance: Option<String>) -> Result<ProcessState> {
+    let instance = instance.unwrap_or_else(|| state::DEFAULT_INSTANCE.to_string());
+    Ok(state::get_process_state(&instance))
+}
+
+#[tauri::command]
+pub async fn list_instances() -> Result<Vec<InstanceInfo>> {
+    Ok(state::list_instances())
+}
+
+#[tauri::command]
+pub async fn remove_instance(instance: String) -> Result<CommandResponse> {
+    state::remove_instance(&instance)?;
+    if let Err(e) = config::delete_config(&instance) {
+        state::add_app_log(format!("Failed to forget saved config: {}", e));
+    }
+    Ok(CommandResponse {
+        success: true,
+        message: format!("Instance '{}' removed", instance),
+    })
+}
+
+#[tauri::command]
+pub async fn list_orphans() -> Result<Vec<PidRecord>> {
+    Ok(async_runtime::spawn_blocking(pidfile::find_orphans)
+        .await
+        .unwrap_or_default())
+}
+
+fn find_orphan(data_path: &str) -> Result<PidRecord> {
+    pidfile::find_orphans()
+        .into_iter()
+        .find(|record| record.config.data_path == data_path)
+        .ok_or_else(|| Error::OrphanNotFound(data_path.to_string()))
+}
+
 #[tauri::command]
-pub async fn get_rustfs_logs() -> Result<Vec<String>> {
-    Ok(state::get_rustfs_logs())
+pub async fn adopt_orphan(data_path: String) -> Result<CommandResponse> {
+    let record = find_orphan(&data_path)?;
+    let message = format!(
+        "Reattached to instance '{}' (PID {})",
+        record.instance, record.pid
+    );
+    state::adopt_process(record)?;
+    Ok(CommandResponse {
+        success: true,
+        message,
+    })
+}
+
+#[tauri::command]
+pub async fn terminate_orphan(data_path: String) -> Result<StopResponse> {
+    let record = find_orphan(&data_path)?;
+    let handle = async_runtime::spawn_blocking(move || {
+        let grace = Duration::from_secs(record.config.shutdown_grace_secs);
+        let outcome = state::terminate_pid(record.pid, grace);
+        if outcome != StopOutcome::Failed {
+            pidfile::remove(&record.config.data_path);
+        }
+        outcome
+    });
+    let outcome = handle.await.map_err(|err| {
+        let io_error = IoError::other(err.to_string());
+        Error::Io(io_error)
+    })?;
+
+    Ok(StopResponse {
+        success: outcome != StopOutcome::Failed,
+        message: outcome.describe().to_string(),
+        outcome,
+    })
+}
+
+#[tauri::command]
+pub async fn get_credential_status() -> Result<CredentialStatus> {
+    Ok(credentials::status())
+}
+
+#[tauri::command]
+pub async fn unlock_credentials(passphrase: Option<String>) -> Result<CredentialStatus> {
+    // Key derivation is deliberately slow; keep it off the async runtime.
+    let handle = async_runtime::spawn_blocking(move || credentials::unlock(passphrase.as_deref()));
+    handle.await.map_err(|err| {
+        let io_error = IoError::other(err.to_string());
+        Error::Io(io_error)
+    })?
+}
+
+#[tauri::command]
+pub async fn lock_credentials() -> Result<CredentialStatus> {
+    credentials::lock();
+    Ok(credentials::status())
+}
+
+#[tauri::command]
+pub async fn rotate_credentials_key(passphrase: Option<String>) -> Result<CredentialStatus> {
+    let handle = async_runtime::spawn_blocking(move || credentials::rotate(passphrase.as_deref()));
+    handle.await.map_err(|err| {
+        let io_error = IoError::other(err.to_string());
+        Error::Io(io_error)
+    })?
+}
+
+#[tauri::command]
+pub async fn wipe_credentials() -> Result<CommandResponse> {
+    credentials::wipe()?;
+    Ok(CommandResponse {
+        success: true,
+        message: "Stored credentials wiped".to_string(),
+    })
+}
+
+#[tauri::command]
+pub async fn get_credentials(instance: Option<String>) -> Result<Option<Credentials>> {
+    let instance = instance.unwrap_or_else(|| state::DEFAULT_INSTANCE.to_string());
+    credentials::get(&instance)
+}
+
+#[tauri::command]
+pub async fn set_credentials(
+    instance: Option<String>,
+    credentials: Credentials,
+) -> Result<CommandResponse> {
+    let instance = instance.unwrap_or_else(|| state::DEFAULT_INSTANCE.to_string());
+    credentials::set(&instance, credentials)?;
+    Ok(CommandResponse {
+        success: true,
+        message: format!("Credentials for '{}' saved", instance),
+    })
+}
+
+#[tauri::command]
+pub async fn get_redaction_patterns() -> Result<Vec<String>> {
+    Ok(redact::user_patterns())
+}
+
+#[tauri::command]
+pub async fn set_redaction_patterns(patterns: Vec<String>) -> Result<CommandResponse> {
+    redact::set_user_patterns(&patterns)?;
+    let count = redact::user_patterns().len();
+    state::add_app_log(format!("Using {} custom redaction pattern(s)", count));
+    Ok(CommandResponse {
+        success: true,
+        message: format!("{} redaction pattern(s) applied", count),
+    })
+}
+
+#[tauri::command]
+pub async fn check_port(host: String, port: u16) -> Result<PortCheck> {
+    let handle = async_runtime::spawn_blocking(move || port::check(&host, port));
+    handle.await.map_err(|err| {
+        let io_error = IoError::other(err.to_string());
+        Error::Io(io_error)
+    })?
 }
 
 #[tauri::command]
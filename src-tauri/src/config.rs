@@ -1,31 +1,507 @@
-use serde::{Deserialize, Serialize};
-
-#[derive(Debug, Serialize, Deserialize, Clone)]
-#[serde(default)]
-pub struct RustFsConfig {
-    pub binary_path: Option<String>,
-    pub data_path: String,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub port: Option<u16>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub host: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub access_key: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub secret_key: Option<String>,
-    pub console_enable: bool,
-}
-
-impl Default for RustFsConfig {
-    fn default() -> Self {
-        Self {
-            binary_path: None,
-            data_path: String::new(),
-            port: Some(9000),
-            host: Some("127.0.0.1".to_string()),
-            access_key: Some("rustfsadmin".to_string()),
-            secret_key: Some("rustfsadmin".to_string()),
-            console_enable: false,
+use crate::error::{Error, Result};
+use crate::state::{add_app_log, APP_HANDLE};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::{Map, Value};
+use std::sync::Arc;
+use tauri::Wry;
+use tauri_plugin_store::{Store, StoreExt};
+
+pub use rustfs_launcher_shared::config::{
+    BinarySource, RestartPolicy, RustFsConfig, CONFIG_VERSION,
+};
+
+const CONFIG_STORE_FILE: &str = "config.json";
+const VERSION_KEY: &str = "version";
+const INSTANCES_KEY: &str = "instances";
+const PROFILES_KEY: &str = "profiles";
+const DEFAULT_PROFILE_KEY: &str = "default_profile";
+const SETTINGS_KEY: &str = "settings";
+/// Custom redaction patterns, see `redact`.
+pub const REDACTION_PATTERNS_SETTING: &str = "redaction_patterns";
+/// In-memory log history limits, see `state::set_log_capacity`.
+pub const LOG_CAPACITY_SETTING: &str = "log_capacity";
+/// Release mirror to download from, see `download`.
+pub const RELEASE_URL_SETTING: &str = "release_url";
+/// Releases feed checked for updates, see `updates`.
+pub const RELEASES_FEED_SETTING: &str = "releases_feed";
+/// Layout version of `config.json`; bump it when the stored shape changes.
+pub const CONFIG_STORE_VERSION: u32 = 1;
+
+/// Upgrades a config from one version to the next, in place.
+type Migration = fn(&mut Map<String, Value>);
+
+/// `MIGRATIONS[n]` upgrades a version `n` config to version `n + 1`; add a
+/// step here whenever `CONFIG_VERSION` is bumped.
+const MIGRATIONS: &[Migration] = &[migrate_v0_to_v1, migrate_v1_to_v2, migrate_v2_to_v3];
+
+/// Version 0 is every config written before configs carried a version: the
+/// original seven fields, later joined by the lifecycle, supervisor and
+/// credential settings. Spell out the defaults for whatever it lacks so that
+/// later steps can rely on every field being present.
+fn migrate_v0_to_v1(config: &mut Map<String, Value>) {
+    let Ok(Value::Object(defaults)) = serde_json::to_value(RustFsConfig::default()) else {
+        return;
+    };
+    for key in [
+        "binary_path",
+        "console_enable",
+        "credentials_via_env",
+        "shutdown_grace_secs",
+        "supervisor",
+        "startup_timeout_secs",
+        "health_check_path",
+    ] {
+        if let Some(default) = defaults.get(key) {
+            config
+                .entry(key.to_string())
+                .or_insert_with(|| default.clone());
         }
     }
 }
+
+/// Version 2 added `allow_privileged_port`; older configs never asked for one.
+fn migrate_v1_to_v2(config: &mut Map<String, Value>) {
+    config
+        .entry("allow_privileged_port".to_string())
+        .or_insert(Value::Bool(false));
+}
+
+/// Version 3 added `binary_source`. Before it, a `binary_path` meant a custom
+/// binary and its absence meant the managed one, falling back to the bundled.
+fn migrate_v2_to_v3(config: &mut Map<String, Value>) {
+    let custom = config
+        .get("binary_path")
+        .is_some_and(|path| !path.is_null());
+    let source = if custom {
+        BinarySource::Custom
+    } else {
+        BinarySource::Managed
+    };
+    if let Ok(source) = serde_json::to_value(source) {
+        config.entry("binary_source".to_string()).or_insert(source);
+    }
+}
+
+/// Brings a persisted config of any supported version up to `CONFIG_VERSION`.
+/// Credentials missing from the stored value stay `None` rather than picking
+/// up the defaults, since they are usually kept in the credential store.
+pub fn migrate(value: Value) -> Result<RustFsConfig> {
+    let Value::Object(mut config) = value else {
+        return Err(Error::InvalidConfig("expected an object".to_string()));
+    };
+    let version = match config.get("version") {
+        None => 0,
+        Some(version) => version
+            .as_u64()
+            .and_then(|v| u32::try_from(v).ok())
+            .ok_or_else(|| Error::InvalidConfig(format!("invalid version {}", version)))?,
+    };
+    if version > CONFIG_VERSION {
+        return Err(Error::ConfigTooNew {
+            found: version,
+            supported: CONFIG_VERSION,
+        });
+    }
+
+    for step in &MIGRATIONS[version as usize..] {
+        step(&mut config);
+    }
+    config.insert("version".to_string(), CONFIG_VERSION.into());
+
+    let has_access_key = config.contains_key("access_key");
+    let has_secret_key = config.contains_key("secret_key");
+    let mut migrated: RustFsConfig = serde_json::from_value(Value::Object(config))
+        .map_err(|e| Error::InvalidConfig(e.to_string()))?;
+    if !has_access_key {
+        migrated.access_key = None;
+    }
+    if !has_secret_key {
+        migrated.secret_key = None;
+    }
+    Ok(migrated)
+}
+
+/// `deserialize_with` helper for persisted structs that embed a config.
+pub fn deserialize_migrated<'de, D>(deserializer: D) -> std::result::Result<RustFsConfig, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let value = Value::deserialize(deserializer)?;
+    migrate(value).map_err(serde::de::Error::custom)
+}
+
+fn open_store() -> Result<Arc<Store<Wry>>> {
+    let handle = APP_HANDLE
+        .lock()
+        .unwrap()
+        .clone()
+        .ok_or_else(|| Error::ConfigStore("Application is not initialised".to_string()))?;
+    let store = handle
+        .store(CONFIG_STORE_FILE)
+        .map_err(|e| Error::ConfigStore(e.to_string()))?;
+
+    match store.get(VERSION_KEY).and_then(|v| v.as_u64()) {
+        Some(version) if version > CONFIG_STORE_VERSION as u64 => Err(Error::ConfigStore(format!(
+            "{} was written by a newer launcher (version {})",
+            CONFIG_STORE_FILE, version
+        ))),
+        _ => Ok(store),
+    }
+}
+
+fn stored_map(store: &Store<Wry>, key: &str) -> Map<String, Value> {
+    match store.get(key) {
+        Some(Value::Object(map)) => map,
+        _ => Map::new(),
+    }
+}
+
+fn stored_instances(store: &Store<Wry>) -> Map<String, Value> {
+    stored_map(store, INSTANCES_KEY)
+}
+
+/// Serializes `config` for the store. Credentials never go into the plain
+/// config file; `credentials` keeps them encrypted.
+fn to_stored(config: &RustFsConfig) -> Result<Value> {
+    let mut config = config.clone();
+    config.access_key = None;
+    config.secret_key = None;
+    serde_json::to_value(&config).map_err(|e| Error::ConfigStore(e.to_string()))
+}
+
+/// Returns the last saved config for `instance`, or `None` if it was never
+/// saved. Credentials are not part of it; see `credentials`.
+pub fn load_config(instance: &str) -> Result<Option<RustFsConfig>> {
+    let store = open_store()?;
+    stored_instances(&store)
+        .remove(instance)
+        .map(|value| {
+            migrate(value).map_err(|e| {
+                Error::ConfigStore(format!(
+                    "Saved config for '{}' is unusable: {}",
+                    instance, e
+                ))
+            })
+        })
+        .transpose()
+}
+
+/// Persists `config` as the config of `instance`, without its credentials.
+pub fn save_config(instance: &str, config: &RustFsConfig) -> Result<()> {
+    let value = to_stored(config)?;
+
+    let store = open_store()?;
+    let mut instances = stored_instances(&store);
+    instances.insert(instance.to_string(), value);
+    store.set(VERSION_KEY, CONFIG_STORE_VERSION);
+    store.set(INSTANCES_KEY, Value::Object(instances));
+    store.save().map_err(|e| Error::ConfigStore(e.to_string()))
+}
+
+/// Forgets the saved config of `instance`.
+pub fn delete_config(instance: &str) -> Result<()> {
+    let store = open_store()?;
+    let mut instances = stored_instances(&store);
+    if instances.remove(instance).is_some() {
+        store.set(INSTANCES_KEY, Value::Object(instances));
+        store
+            .save()
+            .map_err(|e| Error::ConfigStore(e.to_string()))?;
+    }
+    Ok(())
+}
+
+/// A named, reusable `RustFsConfig`, e.g. "local dev" or "demo".
+#[derive(Debug, Clone, Serialize)]
+pub struct Profile {
+    pub name: String,
+    pub config: RustFsConfig,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ProfileList {
+    pub profiles: Vec<Profile>,
+    pub default_profile: Option<String>,
+}
+
+fn save_profiles(store: &Store<Wry>, profiles: Map<String, Value>) -> Result<()> {
+    store.set(VERSION_KEY, CONFIG_STORE_VERSION);
+    store.set(PROFILES_KEY, Value::Object(profiles));
+    store.save().map_err(|e| Error::ConfigStore(e.to_string()))
+}
+
+/// `name` as profiles are stored under it: trimmed, and not empty.
+pub fn validate_profile_name(name: &str) -> Result<String> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(Error::ProfileNameRequired);
+    }
+    Ok(name.to_string())
+}
+
+fn default_profile(store: &Store<Wry>) -> Option<String> {
+    store
+        .get(DEFAULT_PROFILE_KEY)
+        .and_then(|v| v.as_str().map(str::to_string))
+}
+
+pub fn list_profiles() -> Result<ProfileList> {
+    let store = open_store()?;
+    let mut profiles: Vec<Profile> = stored_map(&store, PROFILES_KEY)
+        .into_iter()
+        .filter_map(|(name, value)| match migrate(value) {
+            Ok(config) => Some(Profile { name, config }),
+            Err(e) => {
+                add_app_log(format!("Ignoring invalid profile '{}': {}", name, e));
+                None
+            }
+        })
+        .collect();
+    profiles.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(ProfileList {
+        profiles,
+        default_profile: default_profile(&store),
+    })
+}
+
+pub fn load_profile(name: &str) -> Result<RustFsConfig> {
+    let store = open_store()?;
+    let value = stored_map(&store, PROFILES_KEY)
+        .remove(name)
+        .ok_or_else(|| Error::ProfileNotFound(name.to_string()))?;
+    migrate(value).map_err(|e| Error::ConfigStore(format!("Profile '{}' is unusable: {}", name, e)))
+}
+
+pub fn create_profile(name: &str, config: &RustFsConfig) -> Result<String> {
+    let name = validate_profile_name(name)?;
+    let store = open_store()?;
+    let mut profiles = stored_map(&store, PROFILES_KEY);
+    if profiles.contains_key(&name) {
+        return Err(Error::ProfileExists(name));
+    }
+    profiles.insert(name.clone(), to_stored(config)?);
+    save_profiles(&store, profiles)?;
+    Ok(name)
+}
+
+pub fn update_profile(name: &str, config: &RustFsConfig) -> Result<()> {
+    let store = open_store()?;
+    let mut profiles = stored_map(&store, PROFILES_KEY);
+    if !profiles.contains_key(name) {
+        return Err(Error::ProfileNotFound(name.to_string()));
+    }
+    profiles.insert(name.to_string(), to_stored(config)?);
+    save_profiles(&store, profiles)
+}
+
+pub fn clone_profile(source: &str, name: &str) -> Result<String> {
+    let config = load_profile(source)?;
+    create_profile(name, &config)
+}
+
+pub fn rename_profile(old_name: &str, new_name: &str) -> Result<String> {
+    let new_name = validate_profile_name(new_name)?;
+    let store = open_store()?;
+    let mut profiles = stored_map(&store, PROFILES_KEY);
+    if new_name != old_name && profiles.contains_key(&new_name) {
+        return Err(Error::ProfileExists(new_name));
+    }
+    let value = profiles
+        .remove(old_name)
+        .ok_or_else(|| Error::ProfileNotFound(old_name.to_string()))?;
+    profiles.insert(new_name.clone(), value);
+    if default_profile(&store).as_deref() == Some(old_name) {
+        store.set(DEFAULT_PROFILE_KEY, new_name.clone());
+    }
+    save_profiles(&store, profiles)?;
+    Ok(new_name)
+}
+
+pub fn delete_profile(name: &str) -> Result<()> {
+    let store = open_store()?;
+    let mut profiles = stored_map(&store, PROFILES_KEY);
+    if profiles.remove(name).is_none() {
+        return Err(Error::ProfileNotFound(name.to_string()));
+    }
+    if default_profile(&store).as_deref() == Some(name) {
+        store.delete(DEFAULT_PROFILE_KEY);
+    }
+    save_profiles(&store, profiles)
+}
+
+/// Marks `name` as the profile new instances start from; `None` clears it.
+pub fn set_default_profile(name: Option<&str>) -> Result<()> {
+    let store = open_store()?;
+    match name {
+        Some(name) => {
+            if !stored_map(&store, PROFILES_KEY).contains_key(name) {
+                return Err(Error::ProfileNotFound(name.to_string()));
+            }
+            store.set(DEFAULT_PROFILE_KEY, name);
+        }
+        None => {
+            store.delete(DEFAULT_PROFILE_KEY);
+        }
+    }
+    store.save().map_err(|e| Error::ConfigStore(e.to_string()))
+}
+
+/// Returns the launcher-wide setting `name`, or `None` if it was never saved.
+pub fn load_setting<T: DeserializeOwned>(name: &str) -> Result<Option<T>> {
+    let store = open_store()?;
+    stored_map(&store, SETTINGS_KEY)
+        .remove(name)
+        .map(|value| {
+            serde_json::from_value(value).map_err(|e| {
+                Error::ConfigStore(format!("Saved setting '{}' is unusable: {}", name, e))
+            })
+        })
+        .transpose()
+}
+
+/// Persists the launcher-wide setting `name`.
+pub fn save_setting<T: Serialize>(name: &str, value: &T) -> Result<()> {
+    let value = serde_json::to_value(value).map_err(|e| Error::ConfigStore(e.to_string()))?;
+
+    let store = open_store()?;
+    let mut settings = stored_map(&store, SETTINGS_KEY);
+    settings.insert(name.to_string(), value);
+    store.set(VERSION_KEY, CONFIG_STORE_VERSION);
+    store.set(SETTINGS_KEY, Value::Object(settings));
+    store.save().map_err(|e| Error::ConfigStore(e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixture(contents: &str) -> Value {
+        serde_json::from_str(contents).expect("fixture is valid JSON")
+    }
+
+    #[test]
+    fn migrates_v0_baseline() {
+        let config = migrate(fixture(include_str!(
+            "../tests/fixtures/config/v0_baseline.json"
+        )))
+        .unwrap();
+
+        assert_eq!(config.version, CONFIG_VERSION);
+        assert_eq!(config.data_path, "/srv/rustfs/data");
+        assert_eq!(config.port, Some(9100));
+        assert_eq!(config.host.as_deref(), Some("0.0.0.0"));
+        assert_eq!(config.access_key.as_deref(), Some("baseline-access"));
+        assert_eq!(config.secret_key.as_deref(), Some("baseline-secret"));
+        assert!(config.console_enable);
+
+        let defaults = RustFsConfig::default();
+        assert_eq!(config.binary_source, BinarySource::Managed);
+        assert_eq!(config.binary_path, None);
+        assert_eq!(config.credentials_via_env, defaults.credentials_via_env);
+        assert_eq!(config.shutdown_grace_secs, defaults.shutdown_grace_secs);
+        assert_eq!(
+            serde_json::to_value(&config.supervisor).unwrap(),
+            serde_json::to_value(&defaults.supervisor).unwrap()
+        );
+        assert_eq!(config.startup_timeout_secs, defaults.startup_timeout_secs);
+        assert_eq!(config.health_check_path, defaults.health_check_path);
+    }
+
+    #[test]
+    fn migrates_v0_lifecycle() {
+        let config = migrate(fixture(include_str!(
+            "../tests/fixtures/config/v0_lifecycle.json"
+        )))
+        .unwrap();
+
+        assert_eq!(config.version, CONFIG_VERSION);
+        assert_eq!(config.binary_source, BinarySource::Custom);
+        assert_eq!(
+            config.binary_path.as_deref(),
+            Some("/opt/rustfs/bin/rustfs")
+        );
+        assert_eq!(config.shutdown_grace_secs, 25);
+        assert_eq!(config.supervisor.restart_policy, RestartPolicy::OnFailure);
+        assert_eq!(config.supervisor.max_restarts, 3);
+        assert_eq!(config.startup_timeout_secs, 45);
+        assert_eq!(config.health_check_path, "/minio/health/live");
+        assert!(config.credentials_via_env);
+        // Stored without credentials: they must not turn into the defaults.
+        assert_eq!(config.access_key, None);
+        assert_eq!(config.secret_key, None);
+    }
+
+    #[test]
+    fn migrates_v1() {
+        let config = migrate(fixture(include_str!("../tests/fixtures/config/v1.json"))).unwrap();
+
+        assert_eq!(config.version, CONFIG_VERSION);
+        assert_eq!(config.port, Some(9200));
+        assert!(!config.credentials_via_env);
+        assert_eq!(config.supervisor.restart_policy, RestartPolicy::Always);
+        assert!(!config.allow_privileged_port);
+    }
+
+    #[test]
+    fn migrates_v2() {
+        let config = migrate(fixture(include_str!("../tests/fixtures/config/v2.json"))).unwrap();
+
+        assert_eq!(config.version, CONFIG_VERSION);
+        assert_eq!(config.port, Some(443));
+        assert!(config.allow_privileged_port);
+        assert_eq!(config.binary_source, BinarySource::Managed);
+    }
+
+    #[test]
+    fn loads_current_version_unchanged() {
+        let value = fixture(include_str!("../tests/fixtures/config/v3.json"));
+        let config = migrate(value.clone()).unwrap();
+
+        assert_eq!(config.version, CONFIG_VERSION);
+        assert_eq!(config.binary_source, BinarySource::Bundled);
+        assert_eq!(
+            config.binary_path.as_deref(),
+            Some("/opt/rustfs/bin/rustfs")
+        );
+        assert_eq!(to_stored(&config).unwrap(), value);
+    }
+
+    #[test]
+    fn rejects_newer_version() {
+        let err = migrate(fixture(include_str!(
+            "../tests/fixtures/config/future.json"
+        )))
+        .unwrap_err();
+
+        assert!(matches!(
+            err,
+            Error::ConfigTooNew {
+                found: 99,
+                supported: CONFIG_VERSION
+            }
+        ));
+        assert!(err.to_string().contains("update the launcher"));
+    }
+
+    #[test]
+    fn rejects_malformed_configs() {
+        assert!(matches!(
+            migrate(Value::String("config".to_string())),
+            Err(Error::InvalidConfig(_))
+        ));
+        assert!(matches!(
+            migrate(serde_json::json!({ "version": "one" })),
+            Err(Error::InvalidConfig(_))
+        ));
+        assert!(matches!(
+            migrate(serde_json::json!({ "port": "not a port" })),
+            Err(Error::InvalidConfig(_))
+        ));
+    }
+
+    #[test]
+    fn every_version_has_a_migration() {
+        assert_eq!(MIGRATIONS.len(), CONFIG_VERSION as usize);
+    }
+}
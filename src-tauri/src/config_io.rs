@@ -0,0 +1,494 @@
+use crate::config::{self, RustFsConfig};
+use crate::credentials;
+use crate::error::{Error, Result};
+use crate::process::{ACCESS_KEY_ENV, SECRET_KEY_ENV};
+use crate::state::add_app_log;
+use crate::validation;
+use lazy_static::lazy_static;
+use regex::{Captures, Regex};
+use rustfs_launcher_shared::validation::Severity;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// Version of the exported file layout.
+const EXPORT_VERSION: u32 = 1;
+/// TOML has no null, so a TOML export lists the fields that are `None` under
+/// this key instead of writing them, and import turns them back into nulls.
+const UNSET_KEY: &str = "unset";
+
+lazy_static! {
+    static ref PLACEHOLDER_REGEX: Regex = Regex::new(r"\$\{([A-Za-z_][A-Za-z0-9_]*)\}").unwrap();
+}
+
+/// What an export does with the access and secret keys.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum SecretMode {
+    /// Leave them out; the importer keeps whatever it already has.
+    Exclude,
+    /// Write `${RUSTFS_ACCESS_KEY}` / `${RUSTFS_SECRET_KEY}`, resolved from the
+    /// environment when RustFS is launched.
+    #[default]
+    Placeholder,
+    /// Write them in clear text.
+    Include,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct ExportFile<C> {
+    version: u32,
+    #[serde(default = "Option::default", skip_serializing_if = "Option::is_none")]
+    config: Option<C>,
+    #[serde(default = "BTreeMap::new", skip_serializing_if = "BTreeMap::is_empty")]
+    profiles: BTreeMap<String, C>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ImportSummary {
+    pub config: Option<RustFsConfig>,
+    pub profiles: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Format {
+    Json,
+    Toml,
+}
+
+fn format_of(path: &Path) -> Format {
+    match path.extension().and_then(|e| e.to_str()) {
+        Some(ext) if ext.eq_ignore_ascii_case("toml") => Format::Toml,
+        _ => Format::Json,
+    }
+}
+
+/// Whether every `${` in `value` starts a `${VAR}` placeholder that can be
+/// resolved from the environment.
+pub fn is_placeholder_well_formed(value: &str) -> bool {
+    !value.contains("${") || PLACEHOLDER_REGEX.is_match(value)
+}
+
+/// `config` as TOML can hold it: its `None` fields named under [`UNSET_KEY`].
+fn to_toml_value(config: &RustFsConfig) -> Result<Value> {
+    let Value::Object(mut map) =
+        serde_json::to_value(config).map_err(|e| std::io::Error::other(e.to_string()))?
+    else {
+        return Err(std::io::Error::other("config did not serialize to a table").into());
+    };
+    let unset: Vec<Value> = map
+        .iter()
+        .filter(|(_, value)| value.is_null())
+        .map(|(key, _)| Value::String(key.clone()))
+        .collect();
+    map.retain(|_, value| !value.is_null());
+    if !unset.is_empty() {
+        map.insert(UNSET_KEY.to_string(), Value::Array(unset));
+    }
+    Ok(Value::Object(map))
+}
+
+/// Undoes [`to_toml_value`], putting a null back for every field it names.
+fn from_toml_value(label: &str, value: Value) -> Result<Value> {
+    let Value::Object(mut map) = value else {
+        return Ok(value);
+    };
+    let invalid =
+        || Error::InvalidImport(format!("{}: '{}' must list field names", label, UNSET_KEY));
+    match map.remove(UNSET_KEY) {
+        None => {}
+        Some(Value::Array(fields)) => {
+            for field in fields {
+                let Value::String(field) = field else {
+                    return Err(invalid());
+                };
+                map.insert(field, Value::Null);
+            }
+        }
+        Some(_) => return Err(invalid()),
+    }
+    Ok(Value::Object(map))
+}
+
+fn encode(file: &ExportFile<RustFsConfig>, format: Format) -> Result<String> {
+    let contents = match format {
+        Format::Json => {
+            serde_json::to_string_pretty(file).map_err(|e| std::io::Error::other(e.to_string()))?
+        }
+        Format::Toml => {
+            let file = ExportFile {
+                version: file.version,
+                config: file.config.as_ref().map(to_toml_value).transpose()?,
+                profiles: file
+                    .profiles
+                    .iter()
+                    .map(|(name, config)| Ok((name.clone(), to_toml_value(config)?)))
+                    .collect::<Result<_>>()?,
+            };
+            toml::to_string_pretty(&file).map_err(|e| std::io::Error::other(e.to_string()))?
+        }
+    };
+    Ok(contents)
+}
+
+/// Parses an exported file, leaving the configs loose so older shapes can be
+/// migrated forward.
+fn decode(contents: &str, format: Format) -> Result<ExportFile<Value>> {
+    match format {
+        Format::Json => {
+            serde_json::from_str(contents).map_err(|e| Error::InvalidImport(e.to_string()))
+        }
+        Format::Toml => {
+            let file: ExportFile<Value> =
+                toml::from_str(contents).map_err(|e| Error::InvalidImport(e.to_string()))?;
+            Ok(ExportFile {
+                version: file.version,
+                config: file
+                    .config
+                    .map(|value| from_toml_value("config", value))
+                    .transpose()?,
+                profiles: file
+                    .profiles
+                    .into_iter()
+                    .map(|(name, value)| {
+                        let value = from_toml_value(&format!("profile '{}'", name), value)?;
+                        Ok((name, value))
+                    })
+                    .collect::<Result<_>>()?,
+            })
+        }
+    }
+}
+
+fn apply_secret_mode(mut config: RustFsConfig, mode: SecretMode) -> RustFsConfig {
+    match mode {
+        SecretMode::Include => {}
+        SecretMode::Exclude => {
+            config.access_key = None;
+            config.secret_key = None;
+        }
+        SecretMode::Placeholder => {
+            config.access_key = Some(format!("${{{}}}", ACCESS_KEY_ENV));
+            config.secret_key = Some(format!("${{{}}}", SECRET_KEY_ENV));
+        }
+    }
+    config
+}
+
+/// Writes `config` and, optionally, every saved profile to `path`. The format
+/// follows the extension: `.toml` for TOML, anything else for JSON.
+pub fn export(
+    path: &Path,
+    config: Option<RustFsConfig>,
+    include_profiles: bool,
+    secrets: SecretMode,
+) -> Result<()> {
+    let mut profiles = BTreeMap::new();
+    if include_profiles {
+        for profile in config::list_profiles()?.profiles {
+            let key = credentials::profile_key(&profile.name);
+            let config = credentials::apply(&key, profile.config);
+            profiles.insert(profile.name, apply_secret_mode(config, secrets));
+        }
+    }
+    let file = ExportFile {
+        version: EXPORT_VERSION,
+        config: config.map(|c| apply_secret_mode(c, secrets)),
+        profiles,
+    };
+
+    std::fs::write(path, encode(&file, format_of(path))?)?;
+    add_app_log(format!(
+        "Exported config{} to {}",
+        if file.profiles.is_empty() {
+            String::new()
+        } else {
+            format!(" and {} profile(s)", file.profiles.len())
+        },
+        path.display()
+    ));
+    Ok(())
+}
+
+/// Applies the same rules as the config form, apart from those that depend
+/// on the machine the config is launched on.
+fn validate(label: &str, config: &RustFsConfig) -> Result<()> {
+    let errors: Vec<String> = validation::validate_portable(config)
+        .issues
+        .into_iter()
+        .filter(|issue| issue.severity == Severity::Error)
+        .map(|issue| issue.message)
+        .collect();
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(Error::InvalidImport(format!(
+            "{}: {}",
+            label,
+            errors.join("; ")
+        )))
+    }
+}
+
+/// Reads and validates an exported file, saving any profiles it carries.
+/// Nothing is saved unless every entry in the file is valid.
+pub fn import(path: &Path) -> Result<ImportSummary> {
+    let contents = std::fs::read_to_string(path)?;
+    let file = decode(&contents, format_of(path))?;
+    if file.version > EXPORT_VERSION {
+        return Err(Error::InvalidImport(format!(
+            "file version {} is newer than this launcher supports",
+            file.version
+        )));
+    }
+
+    let upgrade = |label: String, value| -> Result<RustFsConfig> {
+        let config = config::migrate(value)
+            .map_err(|e| Error::InvalidImport(format!("{}: {}", label, e)))?;
+        validate(&label, &config)?;
+        Ok(config)
+    };
+    let mut profiles = BTreeMap::new();
+    for (name, value) in file.profiles {
+        let label = format!("profile '{}'", name);
+        let name = config::validate_profile_name(&name)
+            .map_err(|_| Error::InvalidImport(format!("{}: name must not be empty", label)))?;
+        let profile = upgrade(label, value)?;
+        // Their credentials could not be kept once the profile is saved.
+        credentials::ensure_storable(&profile)?;
+        if profiles.insert(name.clone(), profile).is_some() {
+            return Err(Error::InvalidImport(format!(
+                "profile '{}' appears more than once",
+                name
+            )));
+        }
+    }
+    let file = ExportFile {
+        version: file.version,
+        config: file
+            .config
+            .map(|value| upgrade("config".to_string(), value))
+            .transpose()?,
+        profiles,
+    };
+
+    let existing: Vec<String> = config::list_profiles()?
+        .profiles
+        .into_iter()
+        .map(|p| p.name)
+        .collect();
+    let mut imported = Vec::new();
+    for (name, profile) in file.profiles {
+        let name = if existing.contains(&name) {
+            config::update_profile(&name, &profile)?;
+            name
+        } else {
+            config::create_profile(&name, &profile)?
+        };
+        credentials::apply(&credentials::profile_key(&name), profile);
+        imported.push(name);
+    }
+
+    add_app_log(format!(
+        "Imported {}{} profile(s) from {}",
+        if file.config.is_some() {
+            "config and "
+        } else {
+            ""
+        },
+        imported.len(),
+        path.display()
+    ));
+    Ok(ImportSummary {
+        config: file.config,
+        profiles: imported,
+    })
+}
+
+fn resolve(value: &str) -> Result<String> {
+    let mut missing = None;
+    let resolved = PLACEHOLDER_REGEX.replace_all(value, |caps: &Captures| {
+        std::env::var(&caps[1]).unwrap_or_else(|_| {
+            missing.get_or_insert_with(|| caps[1].to_string());
+            String::new()
+        })
+    });
+    match missing {
+        Some(var) => Err(Error::UnresolvedPlaceholder(var)),
+        None => Ok(resolved.into_owned()),
+    }
+}
+
+/// Replaces `${ENV_VAR}` placeholders in the credentials with the values of
+/// those variables in the launcher's environment.
+pub fn resolve_placeholders(mut config: RustFsConfig) -> Result<RustFsConfig> {
+    if let Some(key) = &config.access_key {
+        config.access_key = Some(resolve(key)?);
+    }
+    if let Some(key) = &config.secret_key {
+        config.secret_key = Some(resolve(key)?);
+    }
+    Ok(config)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn with_keys(access_key: &str, secret_key: &str) -> RustFsConfig {
+        RustFsConfig {
+            access_key: Some(access_key.to_string()),
+            secret_key: Some(secret_key.to_string()),
+            ..RustFsConfig::default()
+        }
+    }
+
+    #[test]
+    fn validate_rejects_each_invalid_field() {
+        assert!(validate("config", &RustFsConfig::default()).is_ok());
+        assert!(validate("config", &with_keys("${ACCESS}", "prefix-${SECRET}")).is_ok());
+
+        let invalid = [
+            RustFsConfig {
+                port: Some(0),
+                ..RustFsConfig::default()
+            },
+            RustFsConfig {
+                host: Some(" ".to_string()),
+                ..RustFsConfig::default()
+            },
+            RustFsConfig {
+                health_check_path: "health".to_string(),
+                ..RustFsConfig::default()
+            },
+            RustFsConfig {
+                startup_timeout_secs: 0,
+                ..RustFsConfig::default()
+            },
+            with_keys("${UNCLOSED", "secret-key"),
+            with_keys("access", "${1NVALID}"),
+        ];
+        for config in invalid {
+            let err = validate("profile 'demo'", &config).unwrap_err();
+            assert!(
+                matches!(&err, Error::InvalidImport(message) if message.starts_with("profile 'demo': ")),
+                "{:?}",
+                config
+            );
+        }
+    }
+
+    fn exported(mode: SecretMode) -> ExportFile<RustFsConfig> {
+        let config = apply_secret_mode(
+            RustFsConfig {
+                binary_source: config::BinarySource::Custom,
+                binary_path: Some("/opt/rustfs/bin/rustfs".to_string()),
+                data_path: "/srv/rustfs".to_string(),
+                // Cleared fields must come back cleared, not as defaults.
+                port: None,
+                host: None,
+                ..with_keys("admin", "hunter22")
+            },
+            mode,
+        );
+        ExportFile {
+            version: EXPORT_VERSION,
+            config: Some(config.clone()),
+            profiles: BTreeMap::from([("staging".to_string(), config)]),
+        }
+    }
+
+    #[test]
+    fn round_trips_json_and_toml_in_every_secret_mode() {
+        for format in [Format::Json, Format::Toml] {
+            for mode in [
+                SecretMode::Exclude,
+                SecretMode::Placeholder,
+                SecretMode::Include,
+            ] {
+                let file = exported(mode);
+                let decoded = decode(&encode(&file, format).unwrap(), format).unwrap();
+                let expected = file.config.unwrap();
+
+                assert_eq!(decoded.version, EXPORT_VERSION);
+                assert_eq!(
+                    config::migrate(decoded.config.unwrap()).unwrap(),
+                    expected,
+                    "{:?} {:?}",
+                    format,
+                    mode
+                );
+                assert_eq!(
+                    config::migrate(decoded.profiles["staging"].clone()).unwrap(),
+                    expected,
+                    "{:?} {:?}",
+                    format,
+                    mode
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn toml_lists_cleared_fields_as_unset() {
+        let toml = encode(&exported(SecretMode::Exclude), Format::Toml).unwrap();
+        assert!(
+            !toml.lines().any(|line| line.starts_with("port ")),
+            "{}",
+            toml
+        );
+        assert!(toml.contains(UNSET_KEY), "{}", toml);
+
+        let invalid = "version = 1\n[config]\nunset = [\"port\", 7]\n";
+        assert!(matches!(
+            decode(invalid, Format::Toml),
+            Err(Error::InvalidImport(message)) if message.starts_with("config: ")
+        ));
+    }
+
+    #[test]
+    fn resolve_substitutes_environment_variables() {
+        std::env::set_var("RUSTFS_LAUNCHER_TEST_VALUE", "s3cr3t");
+        assert_eq!(
+            resolve("pre-${RUSTFS_LAUNCHER_TEST_VALUE}-post").unwrap(),
+            "pre-s3cr3t-post"
+        );
+        assert_eq!(resolve("no placeholders").unwrap(), "no placeholders");
+        assert!(matches!(
+            resolve("${RUSTFS_LAUNCHER_TEST_UNSET}"),
+            Err(Error::UnresolvedPlaceholder(var)) if var == "RUSTFS_LAUNCHER_TEST_UNSET"
+        ));
+
+        let config = resolve_placeholders(RustFsConfig {
+            access_key: Some("${RUSTFS_LAUNCHER_TEST_VALUE}".to_string()),
+            secret_key: None,
+            ..RustFsConfig::default()
+        })
+        .unwrap();
+        assert_eq!(config.access_key.as_deref(), Some("s3cr3t"));
+        assert_eq!(config.secret_key, None);
+    }
+
+    #[test]
+    fn apply_secret_mode_handles_credentials() {
+        let config = with_keys("admin", "hunter22");
+
+        let included = apply_secret_mode(config.clone(), SecretMode::Include);
+        assert_eq!(included.access_key.as_deref(), Some("admin"));
+        assert_eq!(included.secret_key.as_deref(), Some("hunter22"));
+
+        let excluded = apply_secret_mode(config.clone(), SecretMode::Exclude);
+        assert_eq!((excluded.access_key, excluded.secret_key), (None, None));
+
+        let placeholders = apply_secret_mode(config, SecretMode::Placeholder);
+        assert_eq!(
+            placeholders.access_key,
+            Some(format!("${{{}}}", ACCESS_KEY_ENV))
+        );
+        assert_eq!(
+            placeholders.secret_key,
+            Some(format!("${{{}}}", SECRET_KEY_ENV))
+        );
+    }
+}
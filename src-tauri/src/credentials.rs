@@ -0,0 +1,600 @@
+use crate::config::RustFsConfig;
+use crate::error::{Error, Result};
+use crate::state::{add_app_log, APP_HANDLE};
+use argon2::Argon2;
+use chacha20poly1305::aead::rand_core::RngCore;
+use chacha20poly1305::aead::{Aead, AeadCore, KeyInit, OsRng};
+use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce};
+use lazy_static::lazy_static;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use tauri::Manager;
+
+const STORE_FILE: &str = "credentials.json";
+const KEY_FILE: &str = "credentials.key";
+const STORE_VERSION: u32 = 1;
+const SALT_LEN: usize = 16;
+
+/// Where the encryption key of the credential store comes from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum KeySource {
+    /// Derived from a passphrase the user types in to unlock.
+    Passphrase,
+    /// Random key kept in a file next to the store, readable only by this user.
+    KeyFile,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Credentials {
+    pub access_key: Option<String>,
+    pub secret_key: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CredentialStatus {
+    pub exists: bool,
+    pub unlocked: bool,
+    pub key_source: Option<KeySource>,
+}
+
+/// On-disk layout. Only `ciphertext` holds anything sensitive.
+#[derive(Serialize, Deserialize)]
+struct StoreFile {
+    version: u32,
+    key_source: KeySource,
+    /// Hex; present for passphrase-derived keys.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    salt: Option<String>,
+    nonce: String,
+    ciphertext: String,
+}
+
+struct Vault {
+    key: [u8; 32],
+    key_source: KeySource,
+    salt: Option<Vec<u8>>,
+    entries: HashMap<String, Credentials>,
+}
+
+lazy_static! {
+    /// The decrypted store, present only while unlocked.
+    static ref VAULT: Mutex<Option<Vault>> = Mutex::new(None);
+}
+
+fn store_dir() -> Result<PathBuf> {
+    let handle = APP_HANDLE
+        .lock()
+        .unwrap()
+        .clone()
+        .ok_or_else(|| Error::CredentialStore("Application is not initialised".to_string()))?;
+    handle
+        .path()
+        .app_data_dir()
+        .map_err(|e| Error::CredentialStore(e.to_string()))
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn from_hex(hex: &str) -> Result<Vec<u8>> {
+    let invalid = || Error::CredentialStore("Credential store is corrupted".to_string());
+    if !hex.len().is_multiple_of(2) {
+        return Err(invalid());
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).map_err(|_| invalid()))
+        .collect()
+}
+
+fn derive_key(passphrase: &str, salt: &[u8]) -> Result<[u8; 32]> {
+    let mut key = [0u8; 32];
+    Argon2::default()
+        .hash_password_into(passphrase.as_bytes(), salt, &mut key)
+        .map_err(|e| Error::CredentialStore(format!("Key derivation failed: {}", e)))?;
+    Ok(key)
+}
+
+fn tmp_path(path: &Path) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(".tmp");
+    path.with_file_name(name)
+}
+
+/// Writes `contents` to a private file next to `path`, to be renamed into
+/// place once everything it goes with is written too.
+fn write_tmp(path: &Path, contents: &[u8]) -> Result<PathBuf> {
+    let tmp = tmp_path(path);
+    // Left over from a crash; `create_new` below would refuse to reuse it.
+    let _ = std::fs::remove_file(&tmp);
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create_new(true);
+    // Created private rather than narrowed afterwards, so it is never
+    // readable by others, not even briefly.
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    let written = options
+        .open(&tmp)
+        .and_then(|mut file| file.write_all(contents).and_then(|()| file.sync_all()));
+    if let Err(e) = written {
+        let _ = std::fs::remove_file(&tmp);
+        return Err(e.into());
+    }
+    Ok(tmp)
+}
+
+/// Writes `contents` next to `path` and renames it into place, so a crash
+/// never leaves a half-written store.
+fn write_private(path: &Path, contents: &[u8]) -> Result<()> {
+    let tmp = write_tmp(path, contents)?;
+    std::fs::rename(&tmp, path).inspect_err(|_| {
+        let _ = std::fs::remove_file(&tmp);
+    })?;
+    Ok(())
+}
+
+fn read_key_file(dir: &Path) -> Result<[u8; 32]> {
+    let bytes = std::fs::read(dir.join(KEY_FILE))
+        .map_err(|e| Error::CredentialStore(format!("Cannot read credential key file: {}", e)))?;
+    bytes
+        .try_into()
+        .map_err(|_| Error::CredentialStore("Credential key file is corrupted".to_string()))
+}
+
+/// Builds an empty vault keyed from `passphrase`, or from a fresh random key
+/// when no passphrase is given. Nothing is written until [`install`].
+fn new_vault(passphrase: Option<&str>) -> Result<Vault> {
+    match passphrase {
+        Some(passphrase) if !passphrase.is_empty() => {
+            let mut salt = vec![0u8; SALT_LEN];
+            OsRng.fill_bytes(&mut salt);
+            Ok(Vault {
+                key: derive_key(passphrase, &salt)?,
+                key_source: KeySource::Passphrase,
+                salt: Some(salt),
+                entries: HashMap::new(),
+            })
+        }
+        _ => Ok(Vault {
+            key: ChaCha20Poly1305::generate_key(&mut OsRng).into(),
+            key_source: KeySource::KeyFile,
+            salt: None,
+            entries: HashMap::new(),
+        }),
+    }
+}
+
+fn encrypt(vault: &Vault) -> Result<Vec<u8>> {
+    let plaintext =
+        serde_json::to_vec(&vault.entries).map_err(|e| Error::CredentialStore(e.to_string()))?;
+    let cipher = ChaCha20Poly1305::new(Key::from_slice(&vault.key));
+    let nonce = ChaCha20Poly1305::generate_nonce(&mut OsRng);
+    let ciphertext = cipher
+        .encrypt(&nonce, plaintext.as_slice())
+        .map_err(|_| Error::CredentialStore("Encryption failed".to_string()))?;
+
+    let file = StoreFile {
+        version: STORE_VERSION,
+        key_source: vault.key_source,
+        salt: vault.salt.as_deref().map(to_hex),
+        nonce: to_hex(&nonce),
+        ciphertext: to_hex(&ciphertext),
+    };
+    serde_json::to_vec_pretty(&file).map_err(|e| Error::CredentialStore(e.to_string()))
+}
+
+/// Re-encrypts the entries of `vault` under its current key.
+fn save(dir: &Path, vault: &Vault) -> Result<()> {
+    write_private(&dir.join(STORE_FILE), &encrypt(vault)?)
+}
+
+/// Writes `vault` as the store, under a key it does not share with the store
+/// on disk. The new store and key file are written in full before either is
+/// renamed into place, and the old key file is kept until the new store is,
+/// so a failure at any step leaves a store that can still be opened.
+fn install(dir: &Path, vault: &Vault) -> Result<()> {
+    std::fs::create_dir_all(dir)?;
+    let store_path = dir.join(STORE_FILE);
+    let key_path = dir.join(KEY_FILE);
+    let old_key_path = dir.join(format!("{}.old", KEY_FILE));
+
+    let store_tmp = write_tmp(&store_path, &encrypt(vault)?)?;
+    let key_tmp = match vault.key_source {
+        KeySource::KeyFile => match write_tmp(&key_path, &vault.key) {
+            Ok(tmp) => Some(tmp),
+            Err(e) => {
+                let _ = std::fs::remove_file(&store_tmp);
+                return Err(e);
+            }
+        },
+        KeySource::Passphrase => None,
+    };
+
+    let had_key = key_path.exists();
+    let swapped = (|| -> std::io::Result<()> {
+        if had_key {
+            std::fs::rename(&key_path, &old_key_path)?;
+        }
+        if let Some(key_tmp) = &key_tmp {
+            std::fs::rename(key_tmp, &key_path)?;
+        }
+        std::fs::rename(&store_tmp, &store_path)
+    })();
+    if let Err(e) = swapped {
+        // The old store was never replaced; put its key back.
+        if had_key && old_key_path.exists() {
+            let _ = std::fs::rename(&old_key_path, &key_path);
+        }
+        let _ = std::fs::remove_file(&store_tmp);
+        if let Some(key_tmp) = &key_tmp {
+            let _ = std::fs::remove_file(key_tmp);
+        }
+        return Err(e.into());
+    }
+    if had_key {
+        let _ = std::fs::remove_file(&old_key_path);
+    }
+    Ok(())
+}
+
+fn read_store(dir: &Path) -> Result<Option<StoreFile>> {
+    let path = dir.join(STORE_FILE);
+    if !path.exists() {
+        return Ok(None);
+    }
+    let json = std::fs::read(path)?;
+    let file: StoreFile = serde_json::from_slice(&json)
+        .map_err(|_| Error::CredentialStore("Credential store is corrupted".to_string()))?;
+    if file.version > STORE_VERSION {
+        return Err(Error::CredentialStore(format!(
+            "Credential store version {} is newer than this launcher supports",
+            file.version
+        )));
+    }
+    Ok(Some(file))
+}
+
+fn open(dir: &Path, file: StoreFile, passphrase: Option<&str>) -> Result<Vault> {
+    let salt = file.salt.as_deref().map(from_hex).transpose()?;
+    let key = match file.key_source {
+        KeySource::Passphrase => {
+            let passphrase = passphrase.filter(|p| !p.is_empty()).ok_or_else(|| {
+                Error::CredentialStore("A passphrase is required to unlock".to_string())
+            })?;
+            derive_key(passphrase, salt.as_deref().unwrap_or_default())?
+        }
+        KeySource::KeyFile => read_key_file(dir)?,
+    };
+
+    let cipher = ChaCha20Poly1305::new(Key::from_slice(&key));
+    let nonce = from_hex(&file.nonce)?;
+    if nonce.len() != 12 {
+        return Err(Error::CredentialStore(
+            "Credential store is corrupted".to_string(),
+        ));
+    }
+    let plaintext = cipher
+        .decrypt(
+            Nonce::from_slice(&nonce),
+            from_hex(&file.ciphertext)?.as_slice(),
+        )
+        .map_err(|_| {
+            Error::CredentialStore("Wrong passphrase or corrupted credential store".to_string())
+        })?;
+    let entries = serde_json::from_slice(&plaintext)
+        .map_err(|_| Error::CredentialStore("Credential store is corrupted".to_string()))?;
+
+    Ok(Vault {
+        key,
+        key_source: file.key_source,
+        salt,
+        entries,
+    })
+}
+
+pub fn status() -> CredentialStatus {
+    let unlocked = VAULT.lock().unwrap().as_ref().map(|v| v.key_source);
+    let stored = store_dir()
+        .ok()
+        .and_then(|dir| read_store(&dir).ok().flatten())
+        .map(|file| file.key_source);
+    CredentialStatus {
+        exists: stored.is_some(),
+        unlocked: unlocked.is_some(),
+        key_source: unlocked.or(stored),
+    }
+}
+
+/// Unlocks the store, creating it on first use. A new store is protected by
+/// `passphrase`, or by a machine-local key file if none is given.
+pub fn unlock(passphrase: Option<&str>) -> Result<CredentialStatus> {
+    let dir = store_dir()?;
+    let vault = match read_store(&dir)? {
+        Some(file) => open(&dir, file, passphrase)?,
+        None => {
+            let vault = new_vault(passphrase)?;
+            install(&dir, &vault)?;
+            add_app_log("Created encrypted credential store".to_string());
+            vault
+        }
+    };
+    *VAULT.lock().unwrap() = Some(vault);
+    add_app_log("Credential store unlocked".to_string());
+    Ok(status())
+}
+
+/// Opens a key-file protected store without user interaction. Passphrase
+/// protected stores stay locked until the user unlocks them.
+pub fn auto_unlock() {
+    let Ok(dir) = store_dir() else {
+        return;
+    };
+    match read_store(&dir) {
+        Ok(Some(file)) if file.key_source == KeySource::KeyFile => match open(&dir, file, None) {
+            Ok(vault) => *VAULT.lock().unwrap() = Some(vault),
+            Err(e) => add_app_log(format!("Could not unlock credential store: {}", e)),
+        },
+        Ok(_) => {}
+        Err(e) => add_app_log(format!("Could not read credential store: {}", e)),
+    }
+}
+
+pub fn lock() {
+    *VAULT.lock().unwrap() = None;
+    add_app_log("Credential store locked".to_string());
+}
+
+/// `current` re-encrypted and saved under a fresh key. On error both the store
+/// on disk and `current` are left as they were.
+fn rotated(dir: &Path, current: &Vault, passphrase: Option<&str>) -> Result<Vault> {
+    let mut rotated = new_vault(passphrase)?;
+    rotated.entries = current.entries.clone();
+    install(dir, &rotated)?;
+    Ok(rotated)
+}
+
+/// Re-encrypts the store under a fresh key, switching to `passphrase` or to a
+/// new key file.
+pub fn rotate(passphrase: Option<&str>) -> Result<CredentialStatus> {
+    let dir = store_dir()?;
+    let mut guard = VAULT.lock().unwrap();
+    let current = guard.as_ref().ok_or(Error::CredentialsLocked)?;
+
+    let rotated = rotated(&dir, current, passphrase)?;
+    *guard = Some(rotated);
+    drop(guard);
+
+    add_app_log("Credential store key rotated".to_string());
+    Ok(status())
+}
+
+/// Deletes the store and its key file. Stored credentials are lost.
+pub fn wipe() -> Result<()> {
+    let dir = store_dir()?;
+    *VAULT.lock().unwrap() = None;
+    for name in [STORE_FILE, KEY_FILE] {
+        match std::fs::remove_file(dir.join(name)) {
+            Ok(()) => {}
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+            Err(e) => return Err(e.into()),
+        }
+    }
+    add_app_log("Credential store wiped".to_string());
+    Ok(())
+}
+
+pub fn get(instance: &str) -> Result<Option<Credentials>> {
+    let guard = VAULT.lock().unwrap();
+    let vault = guard.as_ref().ok_or(Error::CredentialsLocked)?;
+    Ok(vault.entries.get(instance).cloned())
+}
+
+pub fn set(instance: &str, credentials: Credentials) -> Result<()> {
+    let dir = store_dir()?;
+    let mut guard = VAULT.lock().unwrap();
+    let vault = guard.as_mut().ok_or(Error::CredentialsLocked)?;
+    if vault.entries.get(instance) == Some(&credentials) {
+        return Ok(());
+    }
+    vault.entries.insert(instance.to_string(), credentials);
+    save(&dir, vault)
+}
+
+/// Store entry for the credentials of a named profile, kept apart from the
+/// instance entries.
+pub fn profile_key(profile: &str) -> String {
+    format!("profile:{}", profile)
+}
+
+/// Copies the entry `from` to `to`, deleting `from` when `keep` is false.
+/// Does nothing while the store is locked.
+pub fn copy_entry(from: &str, to: &str, keep: bool) -> Result<()> {
+    let dir = store_dir()?;
+    let mut guard = VAULT.lock().unwrap();
+    let Some(vault) = guard.as_mut() else {
+        return Ok(());
+    };
+    let entry = if keep {
+        vault.entries.get(from).cloned()
+    } else {
+        vault.entries.remove(from)
+    };
+    if let Some(entry) = entry {
+        vault.entries.insert(to.to_string(), entry);
+        save(&dir, vault)?;
+    }
+    Ok(())
+}
+
+/// Drops the entry for `key`. Does nothing while the store is locked.
+pub fn remove_entry(key: &str) -> Result<()> {
+    let dir = store_dir()?;
+    let mut guard = VAULT.lock().unwrap();
+    let Some(vault) = guard.as_mut() else {
+        return Ok(());
+    };
+    if vault.entries.remove(key).is_some() {
+        save(&dir, vault)?;
+    }
+    Ok(())
+}
+
+/// Fails while the store is locked if `config` carries credentials, which
+/// would otherwise be lost once the config is saved without them.
+pub fn ensure_storable(config: &RustFsConfig) -> Result<()> {
+    let has_credentials = config.access_key.is_some() || config.secret_key.is_some();
+    if has_credentials && VAULT.lock().unwrap().is_none() {
+        return Err(Error::CredentialsLocked);
+    }
+    Ok(())
+}
+
+/// Reconciles `config` with the store before a launch: credentials sent by
+/// the frontend are saved, missing ones are filled in from the store.
+pub fn apply(instance: &str, mut config: RustFsConfig) -> RustFsConfig {
+    if VAULT.lock().unwrap().is_none() {
+        if config.access_key.is_none() && config.secret_key.is_none() && status().exists {
+            add_app_log(
+                "WARNING: Credential store is locked; launching without stored credentials"
+                    .to_string(),
+            );
+        }
+        return config;
+    }
+
+    if config.access_key.is_some() || config.secret_key.is_some() {
+        let credentials = Credentials {
+            access_key: config.access_key.clone(),
+            secret_key: config.secret_key.clone(),
+        };
+        if let Err(e) = set(instance, credentials) {
+            add_app_log(format!("Failed to save credentials: {}", e));
+        }
+    } else if let Ok(Some(stored)) = get(instance) {
+        config.access_key = stored.access_key;
+        config.secret_key = stored.secret_key;
+    }
+    config
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entries() -> HashMap<String, Credentials> {
+        HashMap::from([(
+            "default".to_string(),
+            Credentials {
+                access_key: Some("admin".to_string()),
+                secret_key: Some("correct horse".to_string()),
+            },
+        )])
+    }
+
+    fn installed(dir: &Path, passphrase: Option<&str>) -> Vault {
+        let mut vault = new_vault(passphrase).unwrap();
+        vault.entries = entries();
+        install(dir, &vault).unwrap();
+        vault
+    }
+
+    fn reopen(dir: &Path, passphrase: Option<&str>) -> Result<Vault> {
+        open(dir, read_store(dir)?.expect("store exists"), passphrase)
+    }
+
+    #[test]
+    fn round_trips_with_passphrase_and_key_file() {
+        let dir = tempfile::tempdir().unwrap();
+
+        installed(dir.path(), Some("passphrase"));
+        assert!(!dir.path().join(KEY_FILE).exists());
+        assert_eq!(
+            reopen(dir.path(), Some("passphrase")).unwrap().entries,
+            entries()
+        );
+
+        installed(dir.path(), None);
+        assert!(dir.path().join(KEY_FILE).exists());
+        assert_eq!(reopen(dir.path(), None).unwrap().entries, entries());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn store_and_key_file_are_private() {
+        use std::os::unix::fs::PermissionsExt;
+        let dir = tempfile::tempdir().unwrap();
+        // A stale temp file from a crash must not block the next write.
+        std::fs::write(tmp_path(&dir.path().join(KEY_FILE)), b"stale").unwrap();
+        installed(dir.path(), None);
+
+        for file in [STORE_FILE, KEY_FILE] {
+            let mode = std::fs::metadata(dir.path().join(file))
+                .unwrap()
+                .permissions()
+                .mode();
+            assert_eq!(mode & 0o777, 0o600, "{}", file);
+        }
+    }
+
+    #[test]
+    fn rejects_wrong_passphrase() {
+        let dir = tempfile::tempdir().unwrap();
+        installed(dir.path(), Some("passphrase"));
+
+        assert!(matches!(
+            reopen(dir.path(), Some("not the passphrase")),
+            Err(Error::CredentialStore(_))
+        ));
+        assert!(matches!(
+            reopen(dir.path(), None),
+            Err(Error::CredentialStore(_))
+        ));
+    }
+
+    #[test]
+    fn rotation_switches_key_and_drops_old_key_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let current = installed(dir.path(), None);
+
+        let rotated = rotated(dir.path(), &current, Some("new passphrase")).unwrap();
+        assert_eq!(rotated.entries, entries());
+        assert!(!dir.path().join(KEY_FILE).exists());
+        assert_eq!(
+            reopen(dir.path(), Some("new passphrase")).unwrap().entries,
+            entries()
+        );
+    }
+
+    #[test]
+    fn failed_rotation_keeps_the_old_store() {
+        let dir = tempfile::tempdir().unwrap();
+        let current = installed(dir.path(), None);
+        let old_key = std::fs::read(dir.path().join(KEY_FILE)).unwrap();
+
+        // A directory where the new store goes makes renaming it fail after
+        // the new key file is already in place.
+        std::fs::remove_file(dir.path().join(STORE_FILE)).unwrap();
+        std::fs::create_dir(dir.path().join(STORE_FILE)).unwrap();
+        std::fs::write(dir.path().join(STORE_FILE).join("keep"), b"").unwrap();
+        assert!(rotated(dir.path(), &current, None).is_err());
+        assert_eq!(std::fs::read(dir.path().join(KEY_FILE)).unwrap(), old_key);
+        assert_eq!(current.entries, entries());
+
+        // Failing to write the new key file leaves the store untouched.
+        std::fs::remove_dir_all(dir.path().join(STORE_FILE)).unwrap();
+        install(dir.path(), &current).unwrap();
+        std::fs::create_dir(tmp_path(&dir.path().join(KEY_FILE))).unwrap();
+        assert!(rotated(dir.path(), &current, None).is_err());
+        assert_eq!(std::fs::read(dir.path().join(KEY_FILE)).unwrap(), old_key);
+        assert_eq!(reopen(dir.path(), None).unwrap().entries, entries());
+        assert!(!tmp_path(&dir.path().join(STORE_FILE)).exists());
+    }
+}
@@ -0,0 +1,439 @@
+use crate::config;
+use crate::error::{Error, Result};
+use crate::state::{add_app_log, APP_HANDLE};
+use crate::versions;
+use rustfs_launcher_shared::events::{DownloadEvent, DownloadPhase, RUSTFS_DOWNLOAD};
+use rustfs_launcher_shared::InstalledVersion;
+use sha2::{Digest, Sha256};
+use std::fs::File;
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use tauri::Emitter;
+
+/// Where release archives are published unless the user picks a mirror.
+pub const DEFAULT_RELEASE_URL: &str = "https://dl.rustfs.com/artifacts/rustfs/release";
+/// Checksum files sit next to their archive with this suffix appended.
+const CHECKSUM_SUFFIX: &str = ".sha256";
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+const READ_TIMEOUT: Duration = Duration::from_secs(30);
+/// Progress is reported at most once per this many bytes.
+const PROGRESS_STEP: u64 = 256 * 1024;
+
+/// The release mirror the user picked; empty means [`DEFAULT_RELEASE_URL`].
+pub fn release_url() -> Result<String> {
+    Ok(config::load_setting(config::RELEASE_URL_SETTING)?.unwrap_or_default())
+}
+
+pub fn set_release_url(url: &str) -> Result<()> {
+    config::save_setting(config::RELEASE_URL_SETTING, &url.trim())
+}
+
+fn emit_download(
+    asset: &str,
+    phase: DownloadPhase,
+    downloaded: u64,
+    total: Option<u64>,
+    message: String,
+) {
+    if let Some(handle) = APP_HANDLE.lock().unwrap().as_ref() {
+        let _ = handle.emit(
+            RUSTFS_DOWNLOAD,
+            DownloadEvent {
+                asset: asset.to_string(),
+                phase,
+                downloaded,
+                total,
+                message,
+            },
+        );
+    }
+}
+
+/// Name of the release archive for this platform, e.g.
+/// `rustfs-macos-aarch64-latest.zip`.
+pub fn asset_name(version: &str) -> String {
+    use std::env::consts::{ARCH, OS};
+    format!("rustfs-{}-{}-{}.zip", OS, ARCH, version)
+}
+
+fn asset_url(base_url: &str, asset: &str) -> String {
+    format!("{}/{}", base_url.trim_end_matches('/'), asset)
+}
+
+fn agent() -> ureq::Agent {
+    ureq::AgentBuilder::new()
+        .timeout_connect(CONNECT_TIMEOUT)
+        .timeout_read(READ_TIMEOUT)
+        .build()
+}
+
+/// Fetches a small text document such as a checksum file or release feed.
+pub(crate) fn get_text(url: &str) -> Result<String> {
+    get(&agent(), url)?
+        .into_string()
+        .map_err(|e| Error::DownloadFailed(format!("{}: {}", url, e)))
+}
+
+fn get(agent: &ureq::Agent, url: &str) -> Result<ureq::Response> {
+    agent.get(url).call().map_err(|e| match e {
+        ureq::Error::Status(code, _) => {
+            Error::DownloadFailed(format!("{} returned HTTP {}", url, code))
+        }
+        ureq::Error::Transport(e) => Error::DownloadFailed(format!("{}: {}", url, e)),
+    })
+}
+
+/// Reads the digest out of a checksum file in `sha256sum` format, where the
+/// hex digest may be followed by the file name.
+fn parse_checksum(contents: &str) -> Option<String> {
+    let digest = contents.split_whitespace().next()?;
+    (digest.len() == 64 && digest.chars().all(|c| c.is_ascii_hexdigit()))
+        .then(|| digest.to_ascii_lowercase())
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// Downloads `asset` from `url` into `dest_dir` and checks it against the
+/// checksum published next to it, reporting `(downloaded, total)` bytes as it
+/// goes. Nothing is left in `dest_dir` unless the checksum matches.
+pub(crate) fn fetch_verified(
+    url: &str,
+    asset: &str,
+    dest_dir: &Path,
+    mut on_progress: impl FnMut(u64, Option<u64>),
+) -> Result<PathBuf> {
+    let checksum_url = format!("{}{}", url, CHECKSUM_SUFFIX);
+    let checksum_file = get_text(&checksum_url)?;
+    let expected = parse_checksum(&checksum_file).ok_or_else(|| {
+        Error::DownloadFailed(format!("{} is not a SHA-256 checksum file", checksum_url))
+    })?;
+
+    let response = get(&agent(), url)?;
+    let total = response
+        .header("Content-Length")
+        .and_then(|len| len.parse::<u64>().ok());
+
+    std::fs::create_dir_all(dest_dir)?;
+    let partial = dest_dir.join(format!("{}.part", asset));
+    let result = (|| {
+        let mut reader = response.into_reader();
+        let mut file = File::create(&partial)?;
+        let mut hasher = Sha256::new();
+        let mut buf = [0u8; 64 * 1024];
+        let mut downloaded = 0u64;
+        let mut reported = 0u64;
+        on_progress(0, total);
+        loop {
+            let n = reader
+                .read(&mut buf)
+                .map_err(|e| Error::DownloadFailed(format!("{}: {}", url, e)))?;
+            if n == 0 {
+                break;
+            }
+            file.write_all(&buf[..n])?;
+            hasher.update(&buf[..n]);
+            downloaded += n as u64;
+            if downloaded - reported >= PROGRESS_STEP {
+                reported = downloaded;
+                on_progress(downloaded, total);
+            }
+        }
+        file.sync_all()?;
+        on_progress(downloaded, total);
+        if total.is_some_and(|total| total != downloaded) {
+            return Err(Error::DownloadFailed(format!(
+                "{} ended after {} of {} bytes",
+                url,
+                downloaded,
+                total.unwrap_or_default()
+            )));
+        }
+
+        let actual = to_hex(&hasher.finalize());
+        if actual != expected {
+            return Err(Error::ChecksumMismatch {
+                asset: asset.to_string(),
+                expected: expected.clone(),
+                actual,
+            });
+        }
+        let path = dest_dir.join(asset);
+        std::fs::rename(&partial, &path)?;
+        Ok(path)
+    })();
+    if result.is_err() {
+        let _ = std::fs::remove_file(&partial);
+    }
+    result
+}
+
+/// Downloads the `version` release (default `latest`) for this platform from
+/// `base_url` (default the official release site), verifies it and installs
+/// it as a managed version.
+pub fn download_and_install(
+    base_url: Option<&str>,
+    version: Option<&str>,
+) -> Result<InstalledVersion> {
+    let base_url = base_url
+        .map(str::trim)
+        .filter(|url| !url.is_empty())
+        .unwrap_or(DEFAULT_RELEASE_URL);
+    let version = version
+        .map(str::trim)
+        .filter(|v| !v.is_empty())
+        .unwrap_or("latest");
+    let asset = asset_name(version);
+    install_from_url(&asset_url(base_url, &asset), &asset)
+}
+
+/// Downloads the archive `asset` from `url`, verifies it and installs it as a
+/// managed version. Progress is broadcast as `rustfs-download`.
+pub fn install_from_url(url: &str, asset: &str) -> Result<InstalledVersion> {
+    install_from_url_in(&versions::binaries_dir()?, url, asset)
+}
+
+fn install_from_url_in(binaries: &Path, url: &str, asset: &str) -> Result<InstalledVersion> {
+    add_app_log(format!("Downloading {}", url));
+
+    let result = (|| {
+        let dir = versions::download_dir(binaries);
+        let archive = fetch_verified(url, asset, &dir, |downloaded, total| {
+            emit_download(
+                asset,
+                DownloadPhase::Downloading,
+                downloaded,
+                total,
+                String::new(),
+            );
+        })?;
+        let size = std::fs::metadata(&archive)?.len();
+        emit_download(
+            asset,
+            DownloadPhase::Installing,
+            size,
+            Some(size),
+            "Checksum verified".to_string(),
+        );
+        let installed = versions::install_in(binaries, &archive);
+        let _ = std::fs::remove_file(&archive);
+        installed.map(|installed| (installed, size))
+    })();
+
+    match result {
+        Ok((installed, size)) => {
+            emit_download(
+                asset,
+                DownloadPhase::Finished,
+                size,
+                Some(size),
+                format!("Installed RustFS {}", installed.version),
+            );
+            Ok(installed)
+        }
+        Err(e) => {
+            add_app_log(format!("[ERROR] Downloading {} failed: {}", asset, e));
+            emit_download(asset, DownloadPhase::Failed, 0, None, e.to_string());
+            Err(e)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rustfs_launcher_shared::VersionList;
+    use std::collections::HashMap;
+    use std::io::{BufRead, BufReader};
+    use std::net::TcpListener;
+
+    /// Serves `files` over plain HTTP/1.1 on a loopback port until the test
+    /// process exits, answering 404 for anything else.
+    fn serve(files: HashMap<String, Vec<u8>>) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                let Ok(mut stream) = stream else {
+                    continue;
+                };
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut request_line = String::new();
+                let _ = reader.read_line(&mut request_line);
+                loop {
+                    let mut line = String::new();
+                    if reader.read_line(&mut line).unwrap_or(0) == 0 || line == "\r\n" {
+                        break;
+                    }
+                }
+                let path = request_line.split_whitespace().nth(1).unwrap_or("/");
+                let (status, body) = match files.get(path) {
+                    Some(body) => ("200 OK", body.as_slice()),
+                    None => ("404 Not Found", &b""[..]),
+                };
+                let _ = write!(
+                    stream,
+                    "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+                    status,
+                    body.len()
+                );
+                let _ = stream.write_all(body);
+            }
+        });
+        format!("http://{}/release", addr)
+    }
+
+    fn sha256_hex(bytes: &[u8]) -> String {
+        to_hex(&Sha256::digest(bytes))
+    }
+
+    #[test]
+    fn downloads_and_verifies_asset() {
+        let body = vec![7u8; 3 * PROGRESS_STEP as usize + 10];
+        let base_url = serve(HashMap::from([
+            ("/release/rustfs-test.zip".to_string(), body.clone()),
+            (
+                "/release/rustfs-test.zip.sha256".to_string(),
+                format!("{}  rustfs-test.zip\n", sha256_hex(&body)).into_bytes(),
+            ),
+        ]));
+        let dir = tempfile::tempdir().unwrap();
+
+        let mut progress = Vec::new();
+        let path = fetch_verified(
+            &asset_url(&base_url, "rustfs-test.zip"),
+            "rustfs-test.zip",
+            dir.path(),
+            |done, total| progress.push((done, total)),
+        )
+        .unwrap();
+
+        assert_eq!(std::fs::read(&path).unwrap(), body);
+        let total = Some(body.len() as u64);
+        assert_eq!(progress.first(), Some(&(0, total)));
+        assert_eq!(progress.last(), Some(&(body.len() as u64, total)));
+        assert!(progress.len() >= 4);
+    }
+
+    #[test]
+    fn rejects_checksum_mismatch() {
+        let base_url = serve(HashMap::from([
+            ("/release/rustfs-test.zip".to_string(), b"tampered".to_vec()),
+            (
+                "/release/rustfs-test.zip.sha256".to_string(),
+                sha256_hex(b"original").into_bytes(),
+            ),
+        ]));
+        let dir = tempfile::tempdir().unwrap();
+
+        let err = fetch_verified(
+            &asset_url(&base_url, "rustfs-test.zip"),
+            "rustfs-test.zip",
+            dir.path(),
+            |_, _| {},
+        )
+        .unwrap_err();
+
+        assert!(matches!(err, Error::ChecksumMismatch { .. }), "{err}");
+        assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 0);
+    }
+
+    #[test]
+    fn reports_missing_asset() {
+        let base_url = serve(HashMap::new());
+        let dir = tempfile::tempdir().unwrap();
+
+        let err = fetch_verified(
+            &asset_url(&base_url, "rustfs-test.zip"),
+            "rustfs-test.zip",
+            dir.path(),
+            |_, _| {},
+        )
+        .unwrap_err();
+
+        assert!(
+            matches!(err, Error::DownloadFailed(ref m) if m.contains("404")),
+            "{err}"
+        );
+    }
+
+    /// Serves `rustfs-test.zip` and its checksum file.
+    fn serve_release(archive: &[u8], checksum: &[u8]) -> String {
+        serve(HashMap::from([
+            ("/release/rustfs-test.zip".to_string(), archive.to_vec()),
+            (
+                "/release/rustfs-test.zip.sha256".to_string(),
+                checksum.to_vec(),
+            ),
+        ]))
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn installs_a_verified_release_end_to_end() {
+        let archive = versions::tests::zip_archive(&[(
+            "rustfs-test/rustfs",
+            &versions::tests::fake_rustfs("1.4.0"),
+        )]);
+        let base_url = serve_release(&archive, sha256_hex(&archive).as_bytes());
+        let binaries = tempfile::tempdir().unwrap();
+
+        let installed = install_from_url_in(
+            binaries.path(),
+            &asset_url(&base_url, "rustfs-test.zip"),
+            "rustfs-test.zip",
+        )
+        .unwrap();
+
+        assert_eq!(installed.version, "1.4.0");
+        assert_eq!(installed.source, "rustfs-test.zip");
+        assert!(Path::new(&installed.path).is_file());
+        let list = versions::list_in(binaries.path());
+        assert_eq!(list.versions, vec![installed]);
+        assert_eq!(list.active.as_deref(), Some("1.4.0"));
+        // The verified archive is not kept once it is installed.
+        let downloads = versions::download_dir(binaries.path());
+        assert_eq!(std::fs::read_dir(downloads).unwrap().count(), 0);
+    }
+
+    #[test]
+    fn installs_nothing_when_the_checksum_does_not_match() {
+        let archive = versions::tests::zip_archive(&[("rustfs", b"#!/bin/sh\n")]);
+        let base_url = serve_release(&archive, sha256_hex(b"another archive").as_bytes());
+        let binaries = tempfile::tempdir().unwrap();
+
+        let err = install_from_url_in(
+            binaries.path(),
+            &asset_url(&base_url, "rustfs-test.zip"),
+            "rustfs-test.zip",
+        )
+        .unwrap_err();
+
+        assert!(matches!(err, Error::ChecksumMismatch { .. }), "{err}");
+        assert_eq!(versions::list_in(binaries.path()), VersionList::default());
+        let entries: Vec<_> = std::fs::read_dir(binaries.path())
+            .unwrap()
+            .flatten()
+            .map(|e| e.path())
+            .collect();
+        // At most the (empty) download directory is left behind.
+        for entry in entries {
+            assert_eq!(entry, versions::download_dir(binaries.path()));
+            assert_eq!(std::fs::read_dir(entry).unwrap().count(), 0);
+        }
+    }
+
+    #[test]
+    fn parses_checksum_files() {
+        let digest = "A".repeat(64);
+        assert_eq!(parse_checksum(&digest), Some("a".repeat(64)));
+        assert_eq!(
+            parse_checksum(&format!("{} *rustfs.zip\n", "b".repeat(64))),
+            Some("b".repeat(64))
+        );
+        assert_eq!(parse_checksum("not a checksum"), None);
+        assert_eq!(parse_checksum(""), None);
+    }
+}
@@ -1,3 +1,5 @@
+use crate::port::PortOwner;
+use rustfs_launcher_shared::{CommandError, ErrorCode};
 use serde::{Serialize, Serializer};
 
 #[derive(Debug, thiserror::Error)]
@@ -22,6 +24,158 @@ pub enum Error {
 
     #[error("RustFS binary failed with exit code: {0}")]
     BinaryFailed(String),
+
+    #[error("Instance is already running: {0}")]
+    InstanceRunning(String),
+
+    #[error("Instance not found: {0}")]
+    InstanceNotFound(String),
+
+    #[error("Data path {0} is already in use by RustFS process {1}")]
+    DataPathInUse(String, u32),
+
+    #[error("No orphaned RustFS process found for {0}")]
+    OrphanNotFound(String),
+
+    #[error(
+        "Address {address} is already in use{}",
+        port_in_use_hint(.owner, .suggested_port)
+    )]
+    PortInUse {
+        address: String,
+        owner: Option<PortOwner>,
+        suggested_port: Option<u16>,
+    },
+
+    #[error("Host {0} cannot be resolved")]
+    InvalidHost(String),
+
+    #[error("Cannot listen on {0}: {1}")]
+    CannotBind(String, std::io::Error),
+
+    #[error("{reason}{}", stderr_hint(.stderr_tail))]
+    FailedToStart { reason: String, stderr_tail: String },
+
+    #[error("Invalid redaction pattern '{0}': {1}")]
+    InvalidRedactionPattern(String, String),
+
+    #[error("Invalid config: {0}")]
+    InvalidConfig(String),
+
+    #[error(
+        "Config version {found} is newer than this launcher supports (up to {supported}); please update the launcher"
+    )]
+    ConfigTooNew { found: u32, supported: u32 },
+
+    #[error("Config store error: {0}")]
+    ConfigStore(String),
+
+    #[error("Profile name is required")]
+    ProfileNameRequired,
+
+    #[error("Profile not found: {0}")]
+    ProfileNotFound(String),
+
+    #[error("A profile named '{0}' already exists")]
+    ProfileExists(String),
+
+    #[error("Invalid config file: {0}")]
+    InvalidImport(String),
+
+    #[error("Environment variable {0} used in a ${{...}} placeholder is not set")]
+    UnresolvedPlaceholder(String),
+
+    #[error("Credential store is locked")]
+    CredentialsLocked,
+
+    #[error("Credential store error: {0}")]
+    CredentialStore(String),
+
+    #[error("Cannot install from archive: {0}")]
+    InvalidArchive(String),
+
+    #[error("RustFS version {0} is not installed")]
+    VersionNotInstalled(String),
+
+    #[error("There is no previous RustFS version to roll back to")]
+    NoPreviousVersion,
+
+    #[error("Download failed: {0}")]
+    DownloadFailed(String),
+
+    #[error("Checksum mismatch for {asset}: expected {expected}, got {actual}")]
+    ChecksumMismatch {
+        asset: String,
+        expected: String,
+        actual: String,
+    },
+
+    #[error("Releases feed is invalid: {0}")]
+    InvalidFeed(String),
+
+    #[error("Release {version} has no download for {target}")]
+    NoReleaseAsset { version: String, target: String },
+
+    #[error("Log file not found: {0}")]
+    LogFileNotFound(String),
+}
+
+fn port_in_use_hint(owner: &Option<PortOwner>, suggested_port: &Option<u16>) -> String {
+    let mut hint = String::new();
+    if let Some(owner) = owner {
+        hint.push_str(&format!(" by {}", owner));
+    }
+    if let Some(port) = suggested_port {
+        hint.push_str(&format!("; port {} is free", port));
+    }
+    hint
+}
+
+fn stderr_hint(stderr_tail: &str) -> String {
+    if stderr_tail.is_empty() {
+        String::new()
+    } else {
+        format!("\n--- stderr ---\n{}", stderr_tail)
+    }
+}
+
+impl Error {
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            Error::Io(_) | Error::Metadata(..) => ErrorCode::Io,
+            Error::DataPathRequired => ErrorCode::DataPathRequired,
+            Error::DataPathNotExist(_) => ErrorCode::DataPathNotExist,
+            Error::DataPathInUse(..) => ErrorCode::DataPathInUse,
+            Error::BinaryNotFound(_) => ErrorCode::BinaryNotFound,
+            Error::BinaryExecution(_) | Error::BinaryFailed(_) => ErrorCode::BinaryFailed,
+            Error::InstanceRunning(_) => ErrorCode::InstanceRunning,
+            Error::InstanceNotFound(_) => ErrorCode::InstanceNotFound,
+            Error::OrphanNotFound(_) => ErrorCode::OrphanNotFound,
+            Error::PortInUse { .. } => ErrorCode::PortInUse,
+            Error::InvalidHost(_) => ErrorCode::InvalidHost,
+            Error::CannotBind(..) => ErrorCode::CannotBind,
+            Error::FailedToStart { .. } => ErrorCode::FailedToStart,
+            Error::InvalidRedactionPattern(..) => ErrorCode::InvalidRedactionPattern,
+            Error::InvalidConfig(_) => ErrorCode::InvalidConfig,
+            Error::ConfigTooNew { .. } => ErrorCode::ConfigTooNew,
+            Error::ConfigStore(_) => ErrorCode::ConfigStore,
+            Error::ProfileNameRequired => ErrorCode::ProfileNameRequired,
+            Error::ProfileNotFound(_) => ErrorCode::ProfileNotFound,
+            Error::ProfileExists(_) => ErrorCode::ProfileExists,
+            Error::InvalidImport(_) => ErrorCode::InvalidImport,
+            Error::UnresolvedPlaceholder(_) => ErrorCode::UnresolvedPlaceholder,
+            Error::CredentialsLocked => ErrorCode::CredentialsLocked,
+            Error::CredentialStore(_) => ErrorCode::CredentialStore,
+            Error::InvalidArchive(_) => ErrorCode::InvalidArchive,
+            Error::VersionNotInstalled(_) => ErrorCode::VersionNotInstalled,
+            Error::NoPreviousVersion => ErrorCode::NoPreviousVersion,
+            Error::DownloadFailed(_) => ErrorCode::DownloadFailed,
+            Error::ChecksumMismatch { .. } => ErrorCode::ChecksumMismatch,
+            Error::InvalidFeed(_) => ErrorCode::InvalidFeed,
+            Error::NoReleaseAsset { .. } => ErrorCode::NoReleaseAsset,
+            Error::LogFileNotFound(_) => ErrorCode::LogFileNotFound,
+        }
+    }
 }
 
 impl Serialize for Error {
@@ -29,7 +183,11 @@ impl Serialize for Error {
     where
         S: Serializer,
     {
-        serializer.serialize_str(&self.to_string())
+        CommandError {
+            code: self.code(),
+            message: self.to_string(),
+        }
+        .serialize(serializer)
     }
 }
 
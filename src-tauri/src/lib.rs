@@ -1,10 +1,24 @@
+mod binary;
 mod commands;
 mod config;
+mod config_io;
+mod credentials;
+mod download;
 mod error;
+mod log_files;
+mod log_parse;
+mod pidfile;
+mod port;
 mod process;
+mod readiness;
+mod redact;
 mod state;
+mod supervisor;
+mod updates;
+mod validation;
+mod versions;
 
-use state::{add_app_log, set_app_handle, terminate_rustfs_process};
+use state::{add_app_log, set_app_handle, terminate_all_instances};
 use tauri::{
     menu::{Menu, MenuItem},
     tray::{MouseButton, TrayIconBuilder, TrayIconEvent},
@@ -33,6 +47,16 @@ pub fn run() {
         .setup(|app| {
             set_app_handle(app.handle().clone());
             add_app_log("RustFS Launcher started".to_string());
+            credentials::auto_unlock();
+            redact::restore_user_patterns();
+            state::restore_log_capacity();
+
+            for orphan in pidfile::find_orphans() {
+                add_app_log(format!(
+                    "Found RustFS process from a previous session: instance '{}', PID {}, data path {}",
+                    orphan.instance, orphan.pid, orphan.config.data_path
+                ));
+            }
 
             // Setup System Tray
             let quit_i = MenuItem::with_id(app, "quit", "Quit", true, None::<&str>)?;
@@ -46,7 +70,7 @@ pub fn run() {
                 .on_menu_event(|app, event| match event.id.as_ref() {
                     "quit" => {
                         add_app_log("Quit requested from tray, terminating...".to_string());
-                        terminate_rustfs_process();
+                        terminate_all_instances();
                         app.exit(0);
                     }
                     "show" => {
@@ -96,20 +120,66 @@ pub fn run() {
         .invoke_handler(tauri::generate_handler![
             commands::launch_rustfs,
             commands::stop_rustfs,
+            commands::get_config,
+            commands::save_config,
+            commands::list_profiles,
+            commands::load_profile,
+            commands::create_profile,
+            commands::update_profile,
+            commands::clone_profile,
+            commands::rename_profile,
+            commands::delete_profile,
+            commands::set_default_profile,
+            commands::export_config,
+            commands::import_config,
             commands::validate_config,
+            commands::inspect_binary,
+            commands::list_versions,
+            commands::install_version,
+            commands::set_active_version,
+            commands::rollback_version,
+            commands::get_release_url,
+            commands::set_release_url,
+            commands::download_version,
+            commands::get_releases_feed,
+            commands::set_releases_feed,
+            commands::check_for_updates,
+            commands::upgrade_rustfs,
             commands::get_app_logs,
+            commands::log_message,
             commands::get_rustfs_logs,
+            commands::get_log_capacity,
+            commands::set_log_capacity,
+            commands::list_log_files,
+            commands::read_log_file,
+            commands::get_status,
+            commands::list_instances,
+            commands::remove_instance,
+            commands::list_orphans,
+            commands::adopt_orphan,
+            commands::terminate_orphan,
             commands::diagnose_rustfs_binary,
+            commands::check_credential_env_support,
+            commands::get_credential_status,
+            commands::unlock_credentials,
+            commands::lock_credentials,
+            commands::rotate_credentials_key,
+            commands::wipe_credentials,
+            commands::get_credentials,
+            commands::set_credentials,
+            commands::get_redaction_patterns,
+            commands::set_redaction_patterns,
+            commands::check_port,
             commands::check_tcp_connection
         ])
         .build(tauri::generate_context!())
         .expect("error building tauri application")
         .run(|_app_handle, event| match event {
             tauri::RunEvent::ExitRequested { .. } => {
-                state::terminate_rustfs_process();
+                state::terminate_all_instances();
             }
             tauri::RunEvent::Exit => {
-                state::terminate_rustfs_process();
+                state::terminate_all_instances();
             }
             // Reopen event is handled by tauri-plugin-single-instance
             _ => {}
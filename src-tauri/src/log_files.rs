@@ -0,0 +1,308 @@
+use crate::error::{Error, Result};
+use crate::state::APP_HANDLE;
+use lazy_static::lazy_static;
+use rustfs_launcher_shared::{LogFileInfo, LogRecord};
+use std::collections::HashMap;
+use std::fs::{File, OpenOptions};
+use std::io::{BufRead, BufReader, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime};
+use tauri::Manager;
+
+/// Stream name of the launcher's own log.
+pub const APP_STREAM: &str = "launcher";
+/// A file is rotated once it grows past this size...
+const MAX_FILE_BYTES: u64 = 5 * 1024 * 1024;
+/// ...or has been written to for this long.
+const MAX_FILE_AGE: Duration = Duration::from_secs(24 * 60 * 60);
+/// Files last written longer ago than this are deleted.
+const RETENTION: Duration = Duration::from_secs(14 * 24 * 60 * 60);
+/// At most this many files are kept per stream, newest first.
+const MAX_FILES_PER_STREAM: usize = 20;
+/// Reading a file returns at most its last this many bytes.
+const READ_LIMIT_BYTES: u64 = 2 * 1024 * 1024;
+const LOG_EXTENSION: &str = "log";
+
+struct OpenLog {
+    file: File,
+    name: String,
+    size: u64,
+    opened: SystemTime,
+}
+
+lazy_static! {
+    /// Files this session writes to, by stream. Each session starts new ones.
+    static ref OPEN_LOGS: Mutex<HashMap<String, OpenLog>> = Mutex::new(HashMap::new());
+}
+
+fn log_dir() -> Option<PathBuf> {
+    let handle = APP_HANDLE.lock().unwrap().clone()?;
+    handle.path().app_log_dir().ok()
+}
+
+/// Stream name of an instance's RustFS output, safe to use in a file name.
+pub fn instance_stream(instance: &str) -> String {
+    let safe: String = instance
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    format!("rustfs-{}", safe)
+}
+
+/// `<stream>.<yyyymmdd-hhmmss-mmm>.log`, so names sort by creation time.
+fn file_name(stream: &str) -> String {
+    format!(
+        "{}.{}.{}",
+        stream,
+        chrono::Local::now().format("%Y%m%d-%H%M%S-%3f"),
+        LOG_EXTENSION
+    )
+}
+
+/// The stream a log file belongs to, or `None` for anything else.
+fn stream_of(name: &str) -> Option<&str> {
+    let rest = name.strip_suffix(LOG_EXTENSION)?.strip_suffix('.')?;
+    let (stream, stamp) = rest.rsplit_once('.')?;
+    (!stream.is_empty() && !stamp.is_empty()).then_some(stream)
+}
+
+fn open(dir: &Path, stream: &str) -> std::io::Result<OpenLog> {
+    std::fs::create_dir_all(dir)?;
+    let name = file_name(stream);
+    let file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(dir.join(&name))?;
+    let size = file.metadata()?.len();
+    Ok(OpenLog {
+        file,
+        name,
+        size,
+        opened: SystemTime::now(),
+    })
+}
+
+/// Deletes files of `stream` beyond the retention age or count, sparing `keep`.
+fn prune(dir: &Path, stream: &str, keep: &str, now: SystemTime) {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+    let mut files: Vec<(String, SystemTime)> = entries
+        .flatten()
+        .filter_map(|entry| {
+            let name = entry.file_name().to_string_lossy().to_string();
+            if stream_of(&name) != Some(stream) {
+                return None;
+            }
+            let modified = entry.metadata().and_then(|m| m.modified()).ok()?;
+            Some((name, modified))
+        })
+        .collect();
+    files.sort_by(|a, b| b.0.cmp(&a.0));
+
+    for (index, (name, modified)) in files.iter().enumerate() {
+        let expired = now.duration_since(*modified).unwrap_or_default() > RETENTION;
+        if name != keep && (index >= MAX_FILES_PER_STREAM || expired) {
+            let _ = std::fs::remove_file(dir.join(name));
+        }
+    }
+}
+
+/// `<timestamp> <LEVEL> [<stream>] <span>: <target>: <message> <fields>`, one
+/// line per record, leaving out a missing span or target.
+fn format_entry(record: &LogRecord) -> String {
+    let mut entry = format!(
+        "{} {:<5} [{}] ",
+        record.timestamp,
+        record.level.label(),
+        record.stream.label()
+    );
+    for context in [&record.span, &record.target].into_iter().flatten() {
+        entry.push_str(context);
+        entry.push_str(": ");
+    }
+    entry.push_str(&record.text());
+    entry.push('\n');
+    entry
+}
+
+/// Appends `record` to the current file of `stream`, rotating it first when
+/// it is too large or too old. Failures are reported to stderr only, since
+/// the app log itself ends up here.
+pub fn append(stream: &str, record: &LogRecord) {
+    let Some(dir) = log_dir() else {
+        return;
+    };
+    let entry = format_entry(record);
+    let mut logs = OPEN_LOGS.lock().unwrap();
+
+    let rotate = logs.get(stream).is_none_or(|log| {
+        log.size + entry.len() as u64 > MAX_FILE_BYTES
+            || log.opened.elapsed().unwrap_or_default() > MAX_FILE_AGE
+    });
+    if rotate {
+        match open(&dir, stream) {
+            Ok(log) => {
+                prune(&dir, stream, &log.name, SystemTime::now());
+                logs.insert(stream.to_string(), log);
+            }
+            Err(e) => {
+                eprintln!("Failed to open a log file in {}: {}", dir.display(), e);
+                return;
+            }
+        }
+    }
+
+    let Some(log) = logs.get_mut(stream) else {
+        return;
+    };
+    match log.file.write_all(entry.as_bytes()) {
+        Ok(()) => log.size += entry.len() as u64,
+        Err(e) => eprintln!("Failed to write {}: {}", log.name, e),
+    }
+}
+
+/// Log files on disk, newest first.
+pub fn list() -> Vec<LogFileInfo> {
+    let Some(dir) = log_dir() else {
+        return Vec::new();
+    };
+    let Ok(entries) = std::fs::read_dir(&dir) else {
+        return Vec::new();
+    };
+    let active: Vec<String> = OPEN_LOGS
+        .lock()
+        .unwrap()
+        .values()
+        .map(|log| log.name.clone())
+        .collect();
+
+    let mut files: Vec<LogFileInfo> = entries
+        .flatten()
+        .filter_map(|entry| {
+            let name = entry.file_name().to_string_lossy().to_string();
+            let stream = stream_of(&name)?.to_string();
+            let metadata = entry.metadata().ok()?;
+            let modified: chrono::DateTime<chrono::Local> = metadata.modified().ok()?.into();
+            Some(LogFileInfo {
+                active: active.contains(&name),
+                name,
+                stream,
+                size: metadata.len(),
+                modified: modified.to_rfc3339(),
+            })
+        })
+        .collect();
+    files.sort_by(|a, b| b.modified.cmp(&a.modified).then(b.name.cmp(&a.name)));
+    files
+}
+
+/// Reads the last `limit` bytes of `path` as lines, dropping a line cut off
+/// at the start.
+fn read_tail(path: &Path, limit: u64) -> std::io::Result<Vec<String>> {
+    let mut file = File::open(path)?;
+    let len = file.metadata()?.len();
+    let truncated = len > limit;
+    if truncated {
+        file.seek(SeekFrom::Start(len - limit))?;
+    }
+    let mut lines = BufReader::new(file.take(limit))
+        .split(b'\n')
+        .map(|line| line.map(|bytes| String::from_utf8_lossy(&bytes).trim_end().to_string()));
+    if truncated {
+        lines.next().transpose()?;
+    }
+    lines.collect()
+}
+
+/// Lines of the log file `name`, as listed by [`list`]. Very large files are
+/// cut to their most recent part.
+pub fn read(name: &str) -> Result<Vec<String>> {
+    let dir = log_dir().ok_or_else(|| Error::LogFileNotFound(name.to_string()))?;
+    // Only bare names of our own files; never a path into somewhere else.
+    if stream_of(name).is_none() || Path::new(name).file_name() != Some(std::ffi::OsStr::new(name))
+    {
+        return Err(Error::LogFileNotFound(name.to_string()));
+    }
+    let path = dir.join(name);
+    if !path.is_file() {
+        return Err(Error::LogFileNotFound(name.to_string()));
+    }
+    Ok(read_tail(&path, READ_LIMIT_BYTES)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn recognises_log_file_names() {
+        assert_eq!(
+            stream_of("launcher.20261017-061335-123.log"),
+            Some("launcher")
+        );
+        assert_eq!(
+            stream_of("rustfs-my_node.20261017-061335-123.log"),
+            Some("rustfs-my_node")
+        );
+        assert_eq!(stream_of("launcher.log"), None);
+        assert_eq!(stream_of("notes.txt"), None);
+        assert_eq!(instance_stream("a b/../c"), "rustfs-a_b____c");
+    }
+
+    #[test]
+    fn prunes_by_count_and_age() {
+        let dir = tempfile::tempdir().unwrap();
+        for i in 0..MAX_FILES_PER_STREAM + 3 {
+            std::fs::write(dir.path().join(format!("launcher.{:03}.log", i)), "x").unwrap();
+        }
+        std::fs::write(dir.path().join("rustfs-default.000.log"), "x").unwrap();
+
+        prune(
+            dir.path(),
+            "launcher",
+            "launcher.000.log",
+            SystemTime::now(),
+        );
+        let mut left: Vec<String> = std::fs::read_dir(dir.path())
+            .unwrap()
+            .map(|e| e.unwrap().file_name().to_string_lossy().to_string())
+            .collect();
+        left.sort();
+        // The kept file survives even though it is the oldest.
+        assert_eq!(left.len(), MAX_FILES_PER_STREAM + 2);
+        assert!(left.contains(&"launcher.000.log".to_string()));
+        assert!(!left.contains(&"launcher.001.log".to_string()));
+        assert!(left.contains(&"rustfs-default.000.log".to_string()));
+
+        let later = SystemTime::now() + RETENTION + Duration::from_secs(60);
+        prune(dir.path(), "launcher", "launcher.022.log", later);
+        let launcher_files = std::fs::read_dir(dir.path())
+            .unwrap()
+            .filter(|e| {
+                let name = e.as_ref().unwrap().file_name();
+                stream_of(&name.to_string_lossy()) == Some("launcher")
+            })
+            .count();
+        assert_eq!(launcher_files, 1);
+    }
+
+    #[test]
+    fn reads_tail_of_large_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("launcher.1.log");
+        let contents: String = (0..100).map(|i| format!("line {}\n", i)).collect();
+        std::fs::write(&path, &contents).unwrap();
+
+        assert_eq!(read_tail(&path, 10_000).unwrap().len(), 100);
+        let tail = read_tail(&path, 20).unwrap();
+        assert_eq!(tail, vec!["line 98", "line 99"]);
+    }
+}
@@ -0,0 +1,364 @@
+//! Reads the lines RustFS's `tracing` subscriber writes, in its text or JSON
+//! format, so their level, target, span and fields become part of the record.
+
+use rustfs_launcher_shared::{LogField, LogLevel};
+use serde_json::{Map, Value};
+
+/// What could be read out of one log line.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParsedLine {
+    pub level: LogLevel,
+    pub target: Option<String>,
+    pub span: Option<String>,
+    pub fields: Vec<LogField>,
+    pub message: String,
+}
+
+/// Keys of a JSON line that describe the event rather than being its fields.
+const JSON_METADATA: &[&str] = &[
+    "timestamp",
+    "time",
+    "level",
+    "target",
+    "span",
+    "spans",
+    "fields",
+    "message",
+    "msg",
+    "filename",
+    "line_number",
+    "threadId",
+    "threadName",
+];
+
+/// A line with no structure to it, except perhaps a leading `[LEVEL]` tag.
+pub fn plain(line: &str) -> ParsedLine {
+    let (level, message) = LogLevel::split_tag(line);
+    ParsedLine {
+        level: level.unwrap_or(LogLevel::Info),
+        target: None,
+        span: None,
+        fields: Vec::new(),
+        message: message.to_string(),
+    }
+}
+
+/// Parses `line` as `tracing` JSON or text output, or `None` when it is
+/// neither, e.g. a startup banner.
+pub fn parse(line: &str) -> Option<ParsedLine> {
+    let line = line.trim();
+    if line.starts_with('{') {
+        if let Some(parsed) = parse_json(line) {
+            return Some(parsed);
+        }
+    }
+    parse_text(line)
+}
+
+fn json_text(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+/// `name{field=value ...}` for a span object of the JSON format.
+fn format_span(span: &Map<String, Value>) -> Option<String> {
+    let name = span.get("name")?.as_str()?;
+    let fields: Vec<String> = span
+        .iter()
+        .filter(|(key, _)| key.as_str() != "name")
+        .map(|(key, value)| format!("{}={}", key, json_text(value)))
+        .collect();
+    Some(if fields.is_empty() {
+        name.to_string()
+    } else {
+        format!("{}{{{}}}", name, fields.join(" "))
+    })
+}
+
+/// The JSON format: `{"timestamp":…,"level":"INFO","fields":{"message":…},
+/// "target":…,"spans":[…]}`, with event fields possibly flattened to the top.
+fn parse_json(line: &str) -> Option<ParsedLine> {
+    let Value::Object(object) = serde_json::from_str(line).ok()? else {
+        return None;
+    };
+    let level = LogLevel::parse(object.get("level")?.as_str()?)?;
+    let nested = object.get("fields").and_then(Value::as_object);
+    let event_fields = nested.into_iter().flatten().chain(
+        object
+            .iter()
+            .filter(|(key, _)| !JSON_METADATA.contains(&key.as_str())),
+    );
+
+    let mut message = None;
+    let mut fields = Vec::new();
+    for (name, value) in event_fields {
+        if message.is_none() && (name == "message" || name == "msg") {
+            message = Some(json_text(value));
+        } else {
+            fields.push(LogField {
+                name: name.clone(),
+                value: json_text(value),
+            });
+        }
+    }
+    let message = message
+        .or_else(|| object.get("message").or(object.get("msg")).map(json_text))
+        .unwrap_or_default();
+
+    let span = match object.get("spans").and_then(Value::as_array) {
+        Some(spans) if !spans.is_empty() => Some(
+            spans
+                .iter()
+                .filter_map(|span| format_span(span.as_object()?))
+                .collect::<Vec<_>>()
+                .join(":"),
+        ),
+        _ => object
+            .get("span")
+            .and_then(Value::as_object)
+            .and_then(format_span),
+    };
+
+    Some(ParsedLine {
+        level,
+        target: object
+            .get("target")
+            .and_then(Value::as_str)
+            .map(str::to_string),
+        span,
+        fields,
+        message,
+    })
+}
+
+/// The first whitespace-separated token of `s` and what follows it.
+fn split_token(s: &str) -> (&str, &str) {
+    let s = s.trim_start();
+    match s.find(char::is_whitespace) {
+        Some(end) => (&s[..end], s[end..].trim_start()),
+        None => (s, ""),
+    }
+}
+
+/// A `segment: ` prefix of `s` with no space outside braces, as the span
+/// chain and target are written.
+fn split_prefix(s: &str) -> Option<(&str, &str)> {
+    let mut depth = 0usize;
+    let mut chars = s.char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '{' => depth += 1,
+            '}' => depth = depth.saturating_sub(1),
+            ' ' if depth == 0 => return None,
+            ':' if depth == 0 && chars.peek().is_some_and(|(_, next)| *next == ' ') => {
+                return (i > 0).then(|| (&s[..i], &s[i + 2..]));
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+fn is_module_path(s: &str) -> bool {
+    !s.is_empty()
+        && !s.starts_with(':')
+        && s.chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == ':')
+}
+
+/// Splits `s` on whitespace outside double quotes, keeping each token's
+/// byte offset.
+fn tokenize(s: &str) -> Vec<(usize, &str)> {
+    let mut tokens = Vec::new();
+    let mut start = None;
+    let mut quoted = false;
+    let mut escaped = false;
+    for (i, c) in s.char_indices() {
+        if c.is_whitespace() && !quoted {
+            if let Some(begin) = start.take() {
+                tokens.push((begin, &s[begin..i]));
+            }
+            continue;
+        }
+        if start.is_none() {
+            start = Some(i);
+        }
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if quoted => escaped = true,
+            '"' => quoted = !quoted,
+            _ => {}
+        }
+    }
+    if let Some(begin) = start {
+        tokens.push((begin, &s[begin..]));
+    }
+    tokens
+}
+
+/// Reads `name=value`, unquoting a `"…"` value.
+fn as_field(token: &str) -> Option<LogField> {
+    let (name, value) = token.split_once('=')?;
+    let valid_name = name.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '-'));
+    if !valid_name {
+        return None;
+    }
+    let value = match value.strip_prefix('"').and_then(|v| v.strip_suffix('"')) {
+        Some(inner) => inner.replace("\\\"", "\"").replace("\\\\", "\\"),
+        None => value.to_string(),
+    };
+    Some(LogField {
+        name: name.to_string(),
+        value,
+    })
+}
+
+/// Splits the trailing `name=value` pairs off a message, the way `tracing`
+/// appends an event's fields after it.
+fn split_fields(text: &str) -> (&str, Vec<LogField>) {
+    let tokens = tokenize(text);
+    let first = tokens
+        .iter()
+        .rposition(|(_, token)| as_field(token).is_none())
+        .map_or(0, |i| i + 1);
+    let fields = tokens[first..]
+        .iter()
+        .filter_map(|(_, token)| as_field(token))
+        .collect();
+    let message = match tokens.get(first) {
+        Some((start, _)) => &text[..*start],
+        None => text,
+    };
+    (message.trim_end(), fields)
+}
+
+/// The text format:
+/// `2025-01-01T00:00:00.000000Z  INFO span{a=1}:inner: rustfs::server: message key=value`,
+/// where the timestamp, thread ids, spans and target are each optional.
+fn parse_text(line: &str) -> Option<ParsedLine> {
+    let (first, after_first) = split_token(line);
+    let (level, mut rest) = match LogLevel::parse(first) {
+        Some(level) => (level, after_first),
+        None if first.starts_with(|c: char| c.is_ascii_digit()) => {
+            let (second, after_second) = split_token(after_first);
+            (LogLevel::parse(second)?, after_second)
+        }
+        None => return None,
+    };
+    while rest.starts_with("ThreadId(") {
+        rest = split_token(rest).1;
+    }
+
+    let mut span = None;
+    let mut target = None;
+    if let Some((first, after)) = split_prefix(rest) {
+        let second = split_prefix(after).filter(|(segment, _)| {
+            is_module_path(segment) && segment.contains("::") && !first.contains("::")
+        });
+        match second {
+            Some((segment, after_second)) => {
+                span = Some(first.to_string());
+                target = Some(segment.to_string());
+                rest = after_second;
+            }
+            None if is_module_path(first) => {
+                target = Some(first.to_string());
+                rest = after;
+            }
+            None => {
+                span = Some(first.to_string());
+                rest = after;
+            }
+        }
+    }
+
+    let (message, fields) = split_fields(rest);
+    Some(ParsedLine {
+        level,
+        target,
+        span,
+        fields,
+        message: message.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn field(name: &str, value: &str) -> LogField {
+        LogField {
+            name: name.to_string(),
+            value: value.to_string(),
+        }
+    }
+
+    #[test]
+    fn parses_text_format() {
+        let parsed = parse(
+            r#"2025-06-01T08:15:42.123456Z  WARN request{id=7 bucket="a b"}:put_object: rustfs::storage: disk slow latency_ms=250 path="/data/x y""#,
+        )
+        .unwrap();
+        assert_eq!(parsed.level, LogLevel::Warn);
+        assert_eq!(
+            parsed.span.as_deref(),
+            Some(r#"request{id=7 bucket="a b"}:put_object"#)
+        );
+        assert_eq!(parsed.target.as_deref(), Some("rustfs::storage"));
+        assert_eq!(parsed.message, "disk slow");
+        assert_eq!(
+            parsed.fields,
+            vec![field("latency_ms", "250"), field("path", "/data/x y")]
+        );
+
+        let parsed = parse("INFO rustfs: listening on 0.0.0.0:9000").unwrap();
+        assert_eq!(parsed.level, LogLevel::Info);
+        assert_eq!(parsed.target.as_deref(), Some("rustfs"));
+        assert_eq!(parsed.span, None);
+        assert_eq!(parsed.message, "listening on 0.0.0.0:9000");
+        assert!(parsed.fields.is_empty());
+
+        let parsed =
+            parse("2025-06-01T08:15:42Z ERROR ThreadId(03) rustfs::ecstore: code=5").unwrap();
+        assert_eq!(parsed.level, LogLevel::Error);
+        assert_eq!(parsed.target.as_deref(), Some("rustfs::ecstore"));
+        assert_eq!(parsed.message, "");
+        assert_eq!(parsed.fields, vec![field("code", "5")]);
+    }
+
+    #[test]
+    fn parses_json_format() {
+        let parsed = parse(
+            r#"{"timestamp":"2025-06-01T08:15:42.123456Z","level":"DEBUG","fields":{"message":"heal started","disks":4,"set":"a"},"target":"rustfs::heal","spans":[{"name":"heal","id":3},{"name":"scan"}]}"#,
+        )
+        .unwrap();
+        assert_eq!(parsed.level, LogLevel::Debug);
+        assert_eq!(parsed.target.as_deref(), Some("rustfs::heal"));
+        assert_eq!(parsed.span.as_deref(), Some("heal{id=3}:scan"));
+        assert_eq!(parsed.message, "heal started");
+        assert_eq!(parsed.fields, vec![field("disks", "4"), field("set", "a")]);
+
+        let flattened =
+            parse(r#"{"level":"error","message":"boom","target":"rustfs","errno":2}"#).unwrap();
+        assert_eq!(flattened.level, LogLevel::Error);
+        assert_eq!(flattened.message, "boom");
+        assert_eq!(flattened.fields, vec![field("errno", "2")]);
+    }
+
+    #[test]
+    fn leaves_other_lines_alone() {
+        assert_eq!(parse("RustFS Object Storage Server"), None);
+        assert_eq!(parse("Error: address in use"), None);
+        assert_eq!(parse(r#"{"not":"a log"}"#), None);
+
+        let line = plain("[WARN] low disk");
+        assert_eq!(line.level, LogLevel::Warn);
+        assert_eq!(line.message, "low disk");
+        assert_eq!(plain("hello").level, LogLevel::Info);
+    }
+}
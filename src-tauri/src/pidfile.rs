@@ -0,0 +1,111 @@
+use crate::config::{self, RustFsConfig};
+use crate::error::{Error, Result};
+use crate::process::is_process_alive;
+use crate::state::{add_app_log, APP_HANDLE};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use tauri::Manager;
+
+/// Written next to every RustFS process we spawn so a later launcher session
+/// can find it again if this one dies without cleaning up.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PidRecord {
+    pub pid: u32,
+    pub instance: String,
+    #[serde(deserialize_with = "config::deserialize_migrated")]
+    pub config: RustFsConfig,
+    pub logs_dir: String,
+    pub started_at: chrono::DateTime<chrono::Local>,
+}
+
+fn pid_dir() -> Option<PathBuf> {
+    let handle = APP_HANDLE.lock().unwrap().clone()?;
+    let dir = handle.path().app_data_dir().ok()?.join("pids");
+    Some(dir)
+}
+
+/// FNV-1a, so the file name for a data directory is stable across builds.
+fn path_hash(data_path: &str) -> u64 {
+    data_path.bytes().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+fn normalize(data_path: &str) -> String {
+    std::fs::canonicalize(data_path)
+        .map(|p| p.to_string_lossy().to_string())
+        .unwrap_or_else(|_| data_path.to_string())
+}
+
+fn record_path(dir: &Path, data_path: &str) -> PathBuf {
+    dir.join(format!("{:016x}.json", path_hash(&normalize(data_path))))
+}
+
+pub fn write(record: &PidRecord) -> Result<()> {
+    let Some(dir) = pid_dir() else {
+        return Ok(());
+    };
+    std::fs::create_dir_all(&dir)?;
+    // Credentials belong in the encrypted store, never in a plain pidfile.
+    let mut record = record.clone();
+    record.config.access_key = None;
+    record.config.secret_key = None;
+    let json = serde_json::to_string_pretty(&record)
+        .map_err(|e| Error::Io(std::io::Error::other(e.to_string())))?;
+    std::fs::write(record_path(&dir, &record.config.data_path), json)?;
+    Ok(())
+}
+
+pub fn remove(data_path: &str) {
+    if let Some(dir) = pid_dir() {
+        let _ = std::fs::remove_file(record_path(&dir, data_path));
+    }
+}
+
+pub fn read(data_path: &str) -> Option<PidRecord> {
+    let dir = pid_dir()?;
+    let json = std::fs::read_to_string(record_path(&dir, data_path)).ok()?;
+    serde_json::from_str(&json).ok()
+}
+
+fn read_all() -> Vec<(PathBuf, PidRecord)> {
+    let Some(dir) = pid_dir() else {
+        return Vec::new();
+    };
+    let Ok(entries) = std::fs::read_dir(&dir) else {
+        return Vec::new();
+    };
+
+    entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+        .filter_map(|path| {
+            let json = std::fs::read_to_string(&path).ok()?;
+            match serde_json::from_str(&json) {
+                Ok(record) => Some((path, record)),
+                Err(e) => {
+                    add_app_log(format!(
+                        "Ignoring unreadable pidfile {}: {}",
+                        path.display(),
+                        e
+                    ));
+                    None
+                }
+            }
+        })
+        .collect()
+}
+
+/// Returns records whose process is still alive, deleting the stale ones.
+pub fn find_orphans() -> Vec<PidRecord> {
+    let mut orphans = Vec::new();
+    for (path, record) in read_all() {
+        if is_process_alive(record.pid) {
+            orphans.push(record);
+        } else {
+            let _ = std::fs::remove_file(path);
+        }
+    }
+    orphans
+}
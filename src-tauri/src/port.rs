@@ -0,0 +1,204 @@
+use crate::error::{Error, Result};
+use serde::Serialize;
+use std::io::ErrorKind;
+use std::net::{SocketAddr, TcpListener, ToSocketAddrs};
+
+/// How many ports above the requested one to try when suggesting another.
+const FREE_PORT_SEARCH_RANGE: u16 = 100;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PortOwner {
+    pub pid: u32,
+    pub name: Option<String>,
+}
+
+impl std::fmt::Display for PortOwner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.name {
+            Some(name) => write!(f, "{} (PID {})", name, self.pid),
+            None => write!(f, "PID {}", self.pid),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PortCheck {
+    pub available: bool,
+    pub owner: Option<PortOwner>,
+    pub suggested_port: Option<u16>,
+}
+
+fn resolve(host: &str, port: u16) -> Result<SocketAddr> {
+    (host, port)
+        .to_socket_addrs()
+        .ok()
+        .and_then(|mut addrs| addrs.next())
+        .ok_or_else(|| Error::InvalidHost(host.to_string()))
+}
+
+/// Whether `addr` is free. Only a port someone else holds counts as taken;
+/// any other failure, such as a privileged port or an address that is not
+/// local, is an error of its own.
+fn can_bind(addr: SocketAddr) -> Result<bool> {
+    match TcpListener::bind(addr) {
+        Ok(_) => Ok(true),
+        Err(e) if e.kind() == ErrorKind::AddrInUse => Ok(false),
+        Err(e) => Err(Error::CannotBind(addr.to_string(), e)),
+    }
+}
+
+fn find_free_port(addr: SocketAddr) -> Option<u16> {
+    (1..=FREE_PORT_SEARCH_RANGE)
+        .filter_map(|offset| addr.port().checked_add(offset))
+        .find(|port| matches!(can_bind(SocketAddr::new(addr.ip(), *port)), Ok(true)))
+}
+
+/// Tries to bind `host:port` and, if that fails, looks up who holds it and
+/// which nearby port is free. A host that does not resolve or an address
+/// that cannot be bound for another reason is an error, not a conflict.
+pub fn check(host: &str, port: u16) -> Result<PortCheck> {
+    let addr = resolve(host, port)?;
+    if can_bind(addr)? {
+        return Ok(PortCheck {
+            available: true,
+            owner: None,
+            suggested_port: None,
+        });
+    }
+
+    Ok(PortCheck {
+        available: false,
+        owner: find_owner(port),
+        suggested_port: find_free_port(addr),
+    })
+}
+
+#[cfg(target_os = "linux")]
+fn listening_inodes(table: &str, port: u16) -> Vec<u64> {
+    const TCP_LISTEN: &str = "0A";
+
+    table
+        .lines()
+        .skip(1)
+        .filter_map(|line| {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let local = fields.get(1)?;
+            let state = fields.get(3)?;
+            let inode = fields.get(9)?;
+            let local_port = u16::from_str_radix(local.rsplit(':').next()?, 16).ok()?;
+            (local_port == port && *state == TCP_LISTEN)
+                .then(|| inode.parse().ok())
+                .flatten()
+        })
+        .collect()
+}
+
+#[cfg(target_os = "linux")]
+fn find_owner(port: u16) -> Option<PortOwner> {
+    let inodes: Vec<u64> = ["/proc/net/tcp", "/proc/net/tcp6"]
+        .iter()
+        .filter_map(|path| std::fs::read_to_string(path).ok())
+        .flat_map(|table| listening_inodes(&table, port))
+        .filter(|inode| *inode != 0)
+        .collect();
+    if inodes.is_empty() {
+        return None;
+    }
+    let targets: Vec<String> = inodes.iter().map(|i| format!("socket:[{}]", i)).collect();
+
+    for entry in std::fs::read_dir("/proc").ok()?.filter_map(|e| e.ok()) {
+        let Some(pid) = entry
+            .file_name()
+            .to_str()
+            .and_then(|s| s.parse::<u32>().ok())
+        else {
+            continue;
+        };
+        let Ok(fds) = std::fs::read_dir(entry.path().join("fd")) else {
+            continue;
+        };
+        let owns_socket = fds.filter_map(|fd| fd.ok()).any(|fd| {
+            std::fs::read_link(fd.path())
+                .map(|link| targets.iter().any(|t| link.as_os_str() == t.as_str()))
+                .unwrap_or(false)
+        });
+        if owns_socket {
+            let name = std::fs::read_to_string(entry.path().join("comm"))
+                .ok()
+                .map(|n| n.trim().to_string());
+            return Some(PortOwner { pid, name });
+        }
+    }
+    None
+}
+
+#[cfg(all(unix, not(target_os = "linux")))]
+fn find_owner(port: u16) -> Option<PortOwner> {
+    let output = std::process::Command::new("lsof")
+        .args(["-nP", &format!("-iTCP:{}", port), "-sTCP:LISTEN", "-Fpc"])
+        .output()
+        .ok()?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    let mut pid = None;
+    let mut name = None;
+    for line in stdout.lines() {
+        if let Some(value) = line.strip_prefix('p') {
+            pid = value.parse().ok();
+        } else if let Some(value) = line.strip_prefix('c') {
+            name = Some(value.to_string());
+        }
+        if pid.is_some() && name.is_some() {
+            break;
+        }
+    }
+    pid.map(|pid| PortOwner { pid, name })
+}
+
+#[cfg(not(unix))]
+fn find_owner(_port: u16) -> Option<PortOwner> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_bind_reports_a_held_port_as_taken() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        assert!(!can_bind(addr).unwrap());
+
+        let check = check("127.0.0.1", addr.port()).unwrap();
+        assert!(!check.available);
+        assert!(check.suggested_port.is_some_and(|port| port > addr.port()));
+
+        drop(listener);
+        assert!(can_bind(addr).unwrap());
+    }
+
+    #[test]
+    fn can_bind_fails_for_an_address_that_is_not_local() {
+        // TEST-NET-1 is never assigned to a local interface.
+        let addr: SocketAddr = "192.0.2.1:9000".parse().unwrap();
+        assert!(matches!(can_bind(addr), Err(Error::CannotBind(..))));
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn listening_inodes_reads_listeners_on_the_port() {
+        let table = "\
+  sl  local_address rem_address   st tx_queue rx_queue tr tm->when retrnsmt   uid  timeout inode
+   0: 0100007F:2328 00000000:0000 0A 00000000:00000000 00:00000000 00000000  1000        0 4101 1 0000000000000000 100 0 0 10 0
+   1: 0100007F:2328 0100007F:D2F0 01 00000000:00000000 00:00000000 00000000  1000        0 4102 1 0000000000000000 20 4 30 10 -1
+   2: 00000000:0050 00000000:0000 0A 00000000:00000000 00:00000000 00000000     0        0 4103 1 0000000000000000 100 0 0 10 0
+   3: 00000000000000000000000001000000:2328 00000000000000000000000000000000:0000 0A 00000000:00000000 00:00000000 00000000  1000        0 4104 1 0000000000000000 100 0 0 10 0
+   4: garbage
+";
+        assert_eq!(listening_inodes(table, 9000), vec![4101, 4104]);
+        assert_eq!(listening_inodes(table, 80), vec![4103]);
+        assert!(listening_inodes(table, 9001).is_empty());
+        assert!(listening_inodes("", 9000).is_empty());
+    }
+}
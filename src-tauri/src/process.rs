@@ -1,12 +1,25 @@
-use crate::config::RustFsConfig;
+use crate::config::{BinarySource, RustFsConfig};
+use crate::config_io;
 use crate::error::{Error, Result};
-use crate::state::{add_app_log, add_rustfs_log, set_rustfs_process};
+use crate::pidfile::{self, PidRecord};
+use crate::port;
+use crate::readiness;
+use crate::redact;
+use crate::state::{
+    abort_startup, add_app_log, add_rustfs_log, instance_pid, register_instance, set_process_state,
+    set_rustfs_process, LogStream, ProcessState,
+};
+use crate::versions;
 use std::io::{BufRead, BufReader};
 use std::path::{Path, PathBuf};
-use std::process::{Command, Stdio};
+use std::process::{Child, Command, Stdio};
 use std::thread;
+use std::time::Duration;
 
-fn inferred_binary_name() -> &'static str {
+pub const ACCESS_KEY_ENV: &str = "RUSTFS_ACCESS_KEY";
+pub const SECRET_KEY_ENV: &str = "RUSTFS_SECRET_KEY";
+
+pub fn inferred_binary_name() -> &'static str {
     use std::env::consts::{ARCH, OS};
 
     match (OS, ARCH) {
@@ -19,7 +32,7 @@ fn inferred_binary_name() -> &'static str {
     }
 }
 
-fn get_binary_path() -> Result<PathBuf> {
+fn bundled_binary_candidates() -> Result<Vec<PathBuf>> {
     let current_exe = std::env::current_exe().map_err(Error::Io)?;
     let exe_dir = current_exe.parent().ok_or_else(|| {
         Error::Io(std::io::Error::new(
@@ -52,6 +65,20 @@ fn get_binary_path() -> Result<PathBuf> {
 
     push_candidate(PathBuf::from("src-tauri/binaries").join(binary_name));
 
+    Ok(candidates)
+}
+
+fn bundled_binary_not_found(candidates: &[PathBuf]) -> Error {
+    Error::BinaryNotFound(
+        candidates
+            .first()
+            .map(|p| p.to_string_lossy().to_string())
+            .unwrap_or_else(|| "<unknown>".to_string()),
+    )
+}
+
+fn bundled_binary_path() -> Result<PathBuf> {
+    let candidates = bundled_binary_candidates()?;
     for candidate in &candidates {
         add_app_log(format!(
             "Checking RustFS binary candidate: {}",
@@ -68,12 +95,17 @@ fn get_binary_path() -> Result<PathBuf> {
         }
     }
 
-    Err(Error::BinaryNotFound(
-        candidates
-            .first()
-            .map(|p| p.to_string_lossy().to_string())
-            .unwrap_or_else(|| "<unknown>".to_string()),
-    ))
+    Err(bundled_binary_not_found(&candidates))
+}
+
+/// `bundled_binary_path` without logging each candidate it checks.
+fn find_bundled_binary() -> Result<PathBuf> {
+    let candidates = bundled_binary_candidates()?;
+    candidates
+        .iter()
+        .find(|candidate| candidate.exists())
+        .cloned()
+        .ok_or_else(|| bundled_binary_not_found(&candidates))
 }
 
 #[cfg(unix)]
@@ -131,9 +163,218 @@ fn check_permissions(path: &Path) -> Result<()> {
     Ok(())
 }
 
+/// Whether [`send_terminate_signal`] can ask RustFS to shut down.
+pub const GRACEFUL_STOP_SUPPORTED: bool = cfg!(any(unix, windows));
+
+/// Asks the child to shut down with SIGTERM.
+#[cfg(unix)]
+pub fn send_terminate_signal(child: &Child) -> std::io::Result<()> {
+    let pid = child.id() as libc::pid_t;
+    // SAFETY: `kill` has no memory-safety preconditions; the PID belongs to a
+    // child we still own, so it cannot have been recycled yet.
+    if unsafe { libc::kill(pid, libc::SIGTERM) } == 0 {
+        Ok(())
+    } else {
+        Err(std::io::Error::last_os_error())
+    }
+}
+
+/// Asks the child to shut down with CTRL_BREAK.
+#[cfg(windows)]
+pub fn send_terminate_signal(child: &Child) -> std::io::Result<()> {
+    send_ctrl_break(child.id())
+}
+
+/// Delivers CTRL_BREAK to the process group `pid` leads. The launcher is a GUI
+/// process without a console, so it attaches to the hidden console RustFS was
+/// given for the duration of the call. The child is spawned with
+/// `CREATE_NEW_PROCESS_GROUP`, so the event reaches RustFS alone.
+#[cfg(windows)]
+fn send_ctrl_break(pid: u32) -> std::io::Result<()> {
+    use windows_sys::Win32::System::Console::{
+        AttachConsole, FreeConsole, GenerateConsoleCtrlEvent, CTRL_BREAK_EVENT,
+    };
+
+    lazy_static::lazy_static! {
+        // A process has at most one console, so stops must take turns.
+        static ref CONSOLE_LOCK: std::sync::Mutex<()> = std::sync::Mutex::new(());
+    }
+    let _guard = CONSOLE_LOCK.lock().unwrap();
+
+    // SAFETY: plain FFI calls; the console is only borrowed while the lock is
+    // held and released before returning.
+    unsafe {
+        if AttachConsole(pid) == 0 {
+            return Err(std::io::Error::last_os_error());
+        }
+        let ok = GenerateConsoleCtrlEvent(CTRL_BREAK_EVENT, pid) != 0;
+        let err = std::io::Error::last_os_error();
+        FreeConsole();
+        if ok {
+            Ok(())
+        } else {
+            Err(err)
+        }
+    }
+}
+
+#[cfg(not(any(unix, windows)))]
+pub fn send_terminate_signal(_child: &Child) -> std::io::Result<()> {
+    Err(std::io::Error::new(
+        std::io::ErrorKind::Unsupported,
+        "Graceful shutdown is not supported on this platform",
+    ))
+}
+
+// Helpers for processes we only know by PID, such as an orphan left behind
+// by a previous launcher session.
+
+#[cfg(unix)]
+pub fn is_process_alive(pid: u32) -> bool {
+    // SAFETY: signal 0 only performs the existence and permission check.
+    let result = unsafe { libc::kill(pid as libc::pid_t, 0) };
+    result == 0 || std::io::Error::last_os_error().raw_os_error() == Some(libc::EPERM)
+}
+
+#[cfg(unix)]
+pub fn signal_pid(pid: u32, force: bool) -> std::io::Result<()> {
+    let signal = if force { libc::SIGKILL } else { libc::SIGTERM };
+    // SAFETY: `kill` has no memory-safety preconditions.
+    if unsafe { libc::kill(pid as libc::pid_t, signal) } == 0 {
+        Ok(())
+    } else {
+        Err(std::io::Error::last_os_error())
+    }
+}
+
+#[cfg(windows)]
+pub fn is_process_alive(pid: u32) -> bool {
+    use windows_sys::Win32::Foundation::CloseHandle;
+    use windows_sys::Win32::System::Threading::{
+        GetExitCodeProcess, OpenProcess, PROCESS_QUERY_LIMITED_INFORMATION,
+    };
+    const STILL_ACTIVE: u32 = 259;
+
+    // SAFETY: the handle is checked for null and closed before returning.
+    unsafe {
+        let handle = OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, 0, pid);
+        if handle.is_null() {
+            return false;
+        }
+        let mut code = 0u32;
+        let ok = GetExitCodeProcess(handle, &mut code) != 0;
+        CloseHandle(handle);
+        ok && code == STILL_ACTIVE
+    }
+}
+
+/// CTRL_BREAK for a graceful stop, `TerminateProcess` for a forced one.
+#[cfg(windows)]
+pub fn signal_pid(pid: u32, force: bool) -> std::io::Result<()> {
+    use windows_sys::Win32::Foundation::CloseHandle;
+    use windows_sys::Win32::System::Threading::{OpenProcess, TerminateProcess, PROCESS_TERMINATE};
+
+    if !force {
+        return send_ctrl_break(pid);
+    }
+
+    // SAFETY: the handle is checked for null and closed before returning.
+    unsafe {
+        let handle = OpenProcess(PROCESS_TERMINATE, 0, pid);
+        if handle.is_null() {
+            return Err(std::io::Error::last_os_error());
+        }
+        let ok = TerminateProcess(handle, 1) != 0;
+        let err = std::io::Error::last_os_error();
+        CloseHandle(handle);
+        if ok {
+            Ok(())
+        } else {
+            Err(err)
+        }
+    }
+}
+
+#[cfg(not(any(unix, windows)))]
+pub fn is_process_alive(_pid: u32) -> bool {
+    false
+}
+
+#[cfg(not(any(unix, windows)))]
+pub fn signal_pid(_pid: u32, _force: bool) -> std::io::Result<()> {
+    Err(std::io::Error::new(
+        std::io::ErrorKind::Unsupported,
+        "Signalling processes is not supported on this platform",
+    ))
+}
+
+/// The binary `source` selects; `binary_path` only matters for `Custom`.
+pub fn resolve_binary_path(source: BinarySource, binary_path: Option<&str>) -> Result<PathBuf> {
+    match source {
+        BinarySource::Managed => match versions::active_binary() {
+            Some(path) => {
+                add_app_log(format!("Using managed RustFS binary at {}", path.display()));
+                Ok(path)
+            }
+            None => bundled_binary_path(),
+        },
+        BinarySource::Bundled => bundled_binary_path(),
+        BinarySource::Custom => custom_binary_path(binary_path),
+    }
+}
+
+/// `resolve_binary_path` without writing to the app log, for callers such as
+/// validation that run on every edit.
+pub fn find_binary_path(source: BinarySource, binary_path: Option<&str>) -> Result<PathBuf> {
+    match source {
+        BinarySource::Managed => match versions::active_binary() {
+            Some(path) => Ok(path),
+            None => find_bundled_binary(),
+        },
+        BinarySource::Bundled => find_bundled_binary(),
+        BinarySource::Custom => custom_binary_path(binary_path),
+    }
+}
+
+fn custom_binary_path(binary_path: Option<&str>) -> Result<PathBuf> {
+    binary_path
+        .map(PathBuf::from)
+        .ok_or_else(|| Error::InvalidConfig("no custom RustFS binary has been chosen".to_string()))
+}
+
+/// Checks whether the binary's `--help` documents the credential environment
+/// variables. Older RustFS builds only read `--access-key`/`--secret-key`.
+pub fn check_credential_env_support(
+    source: BinarySource,
+    binary_path: Option<&str>,
+) -> Result<bool> {
+    let binary_path = resolve_binary_path(source, binary_path)?;
+    check_permissions(&binary_path)?;
+
+    let output = Command::new(&binary_path)
+        .arg("--help")
+        .output()
+        .map_err(Error::BinaryExecution)?;
+    let help = String::from_utf8_lossy(&output.stdout);
+
+    let supported = help.contains(ACCESS_KEY_ENV) && help.contains(SECRET_KEY_ENV);
+    add_app_log(format!(
+        "{} {} {} and {}",
+        binary_path.display(),
+        if supported {
+            "supports"
+        } else {
+            "does not document"
+        },
+        ACCESS_KEY_ENV,
+        SECRET_KEY_ENV
+    ));
+    Ok(supported)
+}
+
 pub fn diagnose_binary() -> Result<String> {
     add_app_log("Starting RustFS binary diagnosis...".to_string());
-    let binary_path = get_binary_path()?;
+    let binary_path = resolve_binary_path(BinarySource::Managed, None)?;
 
     check_permissions(&binary_path)?;
 
@@ -159,8 +400,11 @@ pub fn diagnose_binary() -> Result<String> {
     }
 }
 
-pub fn launch(config: RustFsConfig) -> Result<String> {
-    add_app_log("Launch command received".to_string());
+pub fn launch(instance: &str, config: RustFsConfig) -> Result<String> {
+    add_app_log(format!(
+        "Launch command received for instance '{}'",
+        instance
+    ));
     add_app_log(format!(
         "Config: data_path={}, port={:?}, host={:?}",
         config.data_path, config.port, config.host
@@ -169,11 +413,18 @@ pub fn launch(config: RustFsConfig) -> Result<String> {
     if config.data_path.is_empty() {
         return Err(Error::DataPathRequired);
     }
+    let config = config_io::resolve_placeholders(config)?;
 
-    let binary_path = match &config.binary_path {
-        Some(path) => PathBuf::from(path),
-        None => get_binary_path()?,
-    };
+    if let Some(record) = pidfile::read(&config.data_path) {
+        let ours = instance_pid(&record.instance) == Some(record.pid);
+        if !ours && is_process_alive(record.pid) {
+            return Err(Error::DataPathInUse(config.data_path.clone(), record.pid));
+        }
+    }
+
+    register_instance(instance, config.clone())?;
+
+    let binary_path = resolve_binary_path(config.binary_source, config.binary_path.as_deref())?;
     check_permissions(&binary_path)?;
 
     // Create logs directory parallel to data_path
@@ -196,18 +447,48 @@ pub fn launch(config: RustFsConfig) -> Result<String> {
     );
     cmd.arg(&config.data_path);
 
-    let address = format!(
-        "{}:{}",
-        config.host.as_deref().unwrap_or("127.0.0.1"),
-        config.port.unwrap_or(9000)
-    );
+    let host = config.host.as_deref().unwrap_or("127.0.0.1");
+    let port = config.port.unwrap_or(9000);
+    let address = format!("{}:{}", host, port);
+
+    let port_check = port::check(host, port)?;
+    if !port_check.available {
+        let error = Error::PortInUse {
+            address,
+            owner: port_check.owner,
+            suggested_port: port_check.suggested_port,
+        };
+        add_app_log(format!("Port check failed: {}", error));
+        return Err(error);
+    }
     cmd.arg("--address").arg(&address);
 
     if let Some(access_key) = &config.access_key {
-        cmd.arg("--access-key").arg(access_key);
+        redact::register_secret(access_key);
+        if config.credentials_via_env {
+            cmd.env(ACCESS_KEY_ENV, access_key);
+        } else {
+            cmd.arg("--access-key").arg(access_key);
+        }
     }
     if let Some(secret_key) = &config.secret_key {
-        cmd.arg("--secret-key").arg(secret_key);
+        redact::register_secret(secret_key);
+        if config.credentials_via_env {
+            cmd.env(SECRET_KEY_ENV, secret_key);
+        } else {
+            cmd.arg("--secret-key").arg(secret_key);
+        }
+    }
+    if config.credentials_via_env {
+        add_app_log(format!(
+            "Passing credentials via {} and {}",
+            ACCESS_KEY_ENV, SECRET_KEY_ENV
+        ));
+    } else {
+        add_app_log(
+            "WARNING: Passing credentials as command-line arguments; they are visible to other local users"
+                .to_string(),
+        );
     }
     if config.console_enable {
         cmd.arg("--console-enable");
@@ -217,46 +498,86 @@ pub fn launch(config: RustFsConfig) -> Result<String> {
     {
         use std::os::windows::process::CommandExt;
         const CREATE_NO_WINDOW: u32 = 0x08000000;
-        cmd.creation_flags(CREATE_NO_WINDOW);
+        // A separate process group lets us deliver CTRL_BREAK to RustFS alone.
+        const CREATE_NEW_PROCESS_GROUP: u32 = 0x00000200;
+        cmd.creation_flags(CREATE_NO_WINDOW | CREATE_NEW_PROCESS_GROUP);
     }
 
-    add_app_log(format!("Spawning command: {:?}", cmd));
-    let mut child = cmd
-        .stdout(Stdio::piped())
-        .stderr(Stdio::piped())
-        .spawn()
-        .map_err(Error::BinaryExecution)?;
+    add_app_log(format!("Spawning command: {}", redact::command_line(&cmd)));
+    set_process_state(instance, ProcessState::Starting);
+    let mut child = match cmd.stdout(Stdio::piped()).stderr(Stdio::piped()).spawn() {
+        Ok(child) => child,
+        Err(e) => {
+            set_process_state(instance, ProcessState::Stopped);
+            return Err(Error::BinaryExecution(e));
+        }
+    };
 
     let pid = child.id();
-    add_app_log(format!("RustFS launched successfully with PID: {}", pid));
-    add_rustfs_log("RustFS process started, capturing output...".to_string());
+    add_app_log(format!("RustFS spawned with PID: {}", pid));
+    add_rustfs_log(
+        instance,
+        LogStream::Launcher,
+        "RustFS process started, capturing output...".to_string(),
+    );
 
     if let Some(stdout) = child.stdout.take() {
+        let instance = instance.to_string();
         thread::spawn(move || {
             let reader = BufReader::new(stdout);
             for line in reader.lines().map_while(|l| l.ok()) {
                 if line.is_empty() {
                     continue;
                 }
-                add_rustfs_log(format!("[STDOUT] {}", line));
+                add_rustfs_log(&instance, LogStream::Stdout, line);
             }
         });
     }
 
     if let Some(stderr) = child.stderr.take() {
+        let instance = instance.to_string();
         thread::spawn(move || {
             let reader = BufReader::new(stderr);
             for line in reader.lines().map_while(|l| l.ok()) {
                 if line.is_empty() {
                     continue;
                 }
-                add_rustfs_log(format!("[STDERR] {}", line));
+                add_rustfs_log(&instance, LogStream::Stderr, line);
             }
         });
     }
 
+    if let Err(e) = pidfile::write(&PidRecord {
+        pid,
+        instance: instance.to_string(),
+        config: config.clone(),
+        logs_dir: logs_dir.to_string_lossy().to_string(),
+        started_at: chrono::Local::now(),
+    }) {
+        add_app_log(format!("Failed to write pidfile: {}", e));
+    }
+
     // Register the process for tracking
-    set_rustfs_process(child);
+    set_rustfs_process(instance, child);
+
+    if let Err(e) = readiness::wait_until_ready(
+        instance,
+        pid,
+        host,
+        port,
+        &config.health_check_path,
+        Duration::from_secs(config.startup_timeout_secs),
+    ) {
+        abort_startup(instance);
+        return Err(e);
+    }
+    set_process_state(
+        instance,
+        ProcessState::Running {
+            pid,
+            since: chrono::Local::now().to_rfc3339(),
+        },
+    );
 
-    Ok(format!("RustFS launched with PID: {}", pid))
+    Ok(format!("RustFS is ready on {} (PID: {})", address, pid))
 }
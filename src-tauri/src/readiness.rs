@@ -0,0 +1,210 @@
+use crate::error::{Error, Result};
+use crate::state::{add_app_log, instance_pid, stderr_tail, APP_HANDLE};
+use std::io::{Read, Write};
+use std::net::{SocketAddr, TcpStream, ToSocketAddrs};
+use std::time::{Duration, Instant};
+use tauri::Emitter;
+
+use rustfs_launcher_shared::events::RUSTFS_LIFECYCLE;
+pub use rustfs_launcher_shared::events::{LifecycleEvent, LifecyclePhase};
+
+const PROBE_INTERVAL: Duration = Duration::from_millis(250);
+const PROBE_TIMEOUT: Duration = Duration::from_millis(1000);
+const STDERR_TAIL_LINES: usize = 20;
+
+pub fn emit_lifecycle(instance: &str, phase: LifecyclePhase, message: String) {
+    if let Some(handle) = APP_HANDLE.lock().unwrap().as_ref() {
+        let _ = handle.emit(
+            RUSTFS_LIFECYCLE,
+            LifecycleEvent {
+                instance: instance.to_string(),
+                phase,
+                message,
+            },
+        );
+    }
+}
+
+/// The `Host` header value for `host:port`; IPv6 literals need brackets.
+fn host_header(host: &str, port: u16) -> String {
+    if host.contains(':') {
+        format!("[{}]:{}", host, port)
+    } else {
+        format!("{}:{}", host, port)
+    }
+}
+
+fn resolve(host: &str, port: u16) -> std::io::Result<SocketAddr> {
+    (host, port)
+        .to_socket_addrs()?
+        .next()
+        .ok_or_else(|| std::io::Error::new(std::io::ErrorKind::NotFound, "no addresses found"))
+}
+
+/// Sends a bare HTTP/1.1 GET and returns the response status code.
+fn http_status(addr: &SocketAddr, host: &str, path: &str) -> Option<u16> {
+    let mut stream = TcpStream::connect_timeout(addr, PROBE_TIMEOUT).ok()?;
+    stream.set_read_timeout(Some(PROBE_TIMEOUT)).ok()?;
+    stream.set_write_timeout(Some(PROBE_TIMEOUT)).ok()?;
+
+    let request = format!(
+        "GET {} HTTP/1.1\r\nHost: {}\r\nConnection: close\r\n\r\n",
+        path, host
+    );
+    stream.write_all(request.as_bytes()).ok()?;
+
+    let mut head = [0u8; 64];
+    let read = stream.read(&mut head).ok()?;
+    let status_line = String::from_utf8_lossy(&head[..read]);
+    status_line.split_whitespace().nth(1)?.parse().ok()
+}
+
+/// Waits until `instance` accepts TCP connections on `host:port` and answers
+/// `health_path` over HTTP, or fails once `timeout` elapses or the process
+/// exits. Any non-5xx answer counts: it proves the HTTP stack is serving even
+/// when the route is guarded.
+pub fn wait_until_ready(
+    instance: &str,
+    pid: u32,
+    host: &str,
+    port: u16,
+    health_path: &str,
+    timeout: Duration,
+) -> Result<()> {
+    emit_lifecycle(
+        instance,
+        LifecyclePhase::Starting,
+        format!("Waiting for RustFS on {}:{}", host, port),
+    );
+    add_app_log(format!(
+        "Waiting up to {}s for RustFS to become ready on {}:{}",
+        timeout.as_secs(),
+        host,
+        port
+    ));
+
+    // A wildcard bind address is not connectable everywhere; probe loopback.
+    let bare_host = host
+        .strip_prefix('[')
+        .and_then(|h| h.strip_suffix(']'))
+        .unwrap_or(host);
+    let probe_host = match bare_host {
+        "0.0.0.0" => "127.0.0.1",
+        "::" => "::1",
+        other => other,
+    };
+    // Waiting cannot fix a name that does not resolve, so fail right away.
+    let addr = match resolve(probe_host, port) {
+        Ok(addr) => addr,
+        Err(e) => {
+            return Err(startup_failed(
+                instance,
+                format!("Cannot resolve {}: {}", probe_host, e),
+            ))
+        }
+    };
+    let host_header = host_header(probe_host, port);
+    let started = Instant::now();
+    let mut tcp_up = false;
+
+    let failure = loop {
+        if instance_pid(instance) != Some(pid) {
+            break "RustFS exited during startup".to_string();
+        }
+
+        if !tcp_up && TcpStream::connect_timeout(&addr, PROBE_TIMEOUT).is_ok() {
+            tcp_up = true;
+            add_app_log("RustFS is accepting TCP connections".to_string());
+        }
+        if tcp_up {
+            if let Some(status) = http_status(&addr, &host_header, health_path) {
+                if status < 500 {
+                    let message = format!(
+                        "RustFS is ready ({} answered {}) after {}ms",
+                        health_path,
+                        status,
+                        started.elapsed().as_millis()
+                    );
+                    add_app_log(message.clone());
+                    emit_lifecycle(instance, LifecyclePhase::Ready, message);
+                    return Ok(());
+                }
+            }
+        }
+
+        if started.elapsed() >= timeout {
+            break format!(
+                "RustFS did not become ready within {}s{}",
+                timeout.as_secs(),
+                if tcp_up { "" } else { " (port never opened)" }
+            );
+        }
+        std::thread::sleep(PROBE_INTERVAL);
+    };
+
+    Err(startup_failed(instance, failure))
+}
+
+fn startup_failed(instance: &str, reason: String) -> Error {
+    add_app_log(reason.clone());
+    emit_lifecycle(instance, LifecyclePhase::FailedToStart, reason.clone());
+    Error::FailedToStart {
+        reason,
+        stderr_tail: stderr_tail(instance, STDERR_TAIL_LINES).join("\n"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::TcpListener;
+
+    #[test]
+    fn host_header_brackets_ipv6_literals() {
+        assert_eq!(host_header("127.0.0.1", 9000), "127.0.0.1:9000");
+        assert_eq!(host_header("localhost", 9000), "localhost:9000");
+        assert_eq!(host_header("::1", 9000), "[::1]:9000");
+    }
+
+    #[test]
+    fn probe_sends_the_host_header() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let server = std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = [0u8; 256];
+            let read = stream.read(&mut request).unwrap();
+            stream.write_all(b"HTTP/1.1 403 Forbidden\r\n\r\n").unwrap();
+            String::from_utf8_lossy(&request[..read]).into_owned()
+        });
+
+        let header = host_header("::1", addr.port());
+        assert_eq!(http_status(&addr, &header, "/health"), Some(403));
+        let request = server.join().unwrap();
+        assert!(
+            request.contains(&format!("\r\nHost: [::1]:{}\r\n", addr.port())),
+            "{}",
+            request
+        );
+    }
+
+    #[test]
+    fn unresolvable_host_fails_without_waiting() {
+        let started = Instant::now();
+        let err = wait_until_ready(
+            "readiness-test",
+            1,
+            "no-such-host.invalid",
+            9000,
+            "/health",
+            Duration::from_secs(30),
+        )
+        .unwrap_err();
+        assert!(started.elapsed() < Duration::from_secs(15));
+        assert!(
+            matches!(&err, Error::FailedToStart { reason, .. } if reason.starts_with("Cannot resolve no-such-host.invalid")),
+            "{:?}",
+            err
+        );
+    }
+}
@@ -0,0 +1,193 @@
+use crate::config;
+use crate::error::{Error, Result};
+use crate::state::add_app_log;
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::process::Command;
+use std::sync::Mutex;
+
+const MASK: &str = "********";
+
+/// Values shorter than this are too likely to appear in ordinary log text to
+/// be masked wherever they occur.
+const MIN_SECRET_LEN: usize = 4;
+
+/// Command line flags whose following argument is a credential.
+const SECRET_FLAGS: &[&str] = &["--access-key", "--secret-key"];
+
+lazy_static! {
+    /// `--secret-key value` / `--secret-key=value` on a command line.
+    static ref FLAG_REGEX: Regex =
+        Regex::new(r#"(?i)(--[a-z-]*(?:secret|password|token|access-key)[a-z-]*[\s=]+)("[^"]*"|\S+)"#)
+            .unwrap();
+    /// `secret_key: Some("value")`, `"secretKey":"value"`, `RUSTFS_SECRET_KEY=value`
+    /// and similar key/value renderings from Debug output, JSON and env dumps.
+    /// Only whole, known credential keys match, so `max_tokens=100` or
+    /// `password_policy: strict` are left alone.
+    static ref FIELD_REGEX: Regex = Regex::new(
+        r#"(?i)(\b(?:rustfs_|minio_|aws_)?(?:secret|secret_?key|secret_?access_?key|access_?key(?:_?id)?|password|passwd|api_?key|token|(?:auth|access|refresh|session)_?token)\b"?\s*[:=]\s*(?:Some\()?)("(?:[^"\\]|\\.)*"|[^\s,)}\]]+)"#
+    )
+    .unwrap();
+    static ref USER_PATTERNS: Mutex<Vec<Regex>> = Mutex::new(Vec::new());
+    /// Credential values from configs we have launched with, masked verbatim
+    /// wherever they show up (e.g. echoed back by RustFS itself).
+    static ref KNOWN_SECRETS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+}
+
+fn mask_match(caps: &regex::Captures) -> String {
+    let value = &caps[2];
+    if value.starts_with('"') {
+        format!("{}\"{}\"", &caps[1], MASK)
+    } else {
+        format!("{}{}", &caps[1], MASK)
+    }
+}
+
+/// Masks credentials in `message` before it is buffered, emitted or returned.
+pub fn redact(message: &str) -> String {
+    let mut text = FLAG_REGEX.replace_all(message, mask_match).to_string();
+    text = FIELD_REGEX.replace_all(&text, mask_match).to_string();
+
+    for secret in KNOWN_SECRETS.lock().unwrap().iter() {
+        text = text.replace(secret.as_str(), MASK);
+    }
+    for pattern in USER_PATTERNS.lock().unwrap().iter() {
+        text = pattern.replace_all(&text, MASK).to_string();
+    }
+    text
+}
+
+/// Remembers a credential value so it is masked even outside key/value form.
+pub fn register_secret(value: &str) {
+    if value.len() < MIN_SECRET_LEN {
+        return;
+    }
+    let mut secrets = KNOWN_SECRETS.lock().unwrap();
+    if !secrets.iter().any(|s| s == value) {
+        secrets.push(value.to_string());
+        // Longest first, so a secret containing another is masked whole.
+        secrets.sort_by_key(|s| std::cmp::Reverse(s.len()));
+    }
+}
+
+/// Replaces the user-supplied patterns. Every pattern is validated before any
+/// is applied, so a typo never leaves the list half-updated.
+pub fn set_user_patterns(patterns: &[String]) -> Result<()> {
+    compile_user_patterns(patterns)?;
+    config::save_setting(config::REDACTION_PATTERNS_SETTING, &user_patterns())
+}
+
+/// Applies the patterns saved by an earlier session.
+pub fn restore_user_patterns() {
+    let patterns: Vec<String> = match config::load_setting(config::REDACTION_PATTERNS_SETTING) {
+        Ok(patterns) => patterns.unwrap_or_default(),
+        Err(e) => {
+            add_app_log(format!("Failed to load redaction patterns: {}", e));
+            return;
+        }
+    };
+    if let Err(e) = compile_user_patterns(&patterns) {
+        add_app_log(format!("Ignoring saved redaction patterns: {}", e));
+    }
+}
+
+fn compile_user_patterns(patterns: &[String]) -> Result<()> {
+    let compiled = patterns
+        .iter()
+        .map(|p| p.trim())
+        .filter(|p| !p.is_empty())
+        .map(|p| {
+            Regex::new(p).map_err(|e| Error::InvalidRedactionPattern(p.to_string(), e.to_string()))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    *USER_PATTERNS.lock().unwrap() = compiled;
+    Ok(())
+}
+
+pub fn user_patterns() -> Vec<String> {
+    USER_PATTERNS
+        .lock()
+        .unwrap()
+        .iter()
+        .map(|r| r.as_str().to_string())
+        .collect()
+}
+
+/// Renders `cmd` for the launcher log with credential arguments masked.
+pub fn command_line(cmd: &Command) -> String {
+    let mut parts = vec![cmd.get_program().to_string_lossy().to_string()];
+    let mut mask_next = false;
+    for arg in cmd.get_args() {
+        let arg = arg.to_string_lossy();
+        if mask_next {
+            parts.push(MASK.to_string());
+            mask_next = false;
+        } else {
+            mask_next = SECRET_FLAGS.contains(&arg.as_ref());
+            parts.push(arg.to_string());
+        }
+    }
+    redact(&parts.join(" "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn masks_credential_fields() {
+        assert_eq!(
+            redact(r#"RustFsConfig { access_key: Some("admin"), secret_key: Some("hunter22") }"#),
+            r#"RustFsConfig { access_key: Some("********"), secret_key: Some("********") }"#
+        );
+        assert_eq!(
+            redact(r#"{"accessKey":"admin","secretKey": "hunter22"}"#),
+            r#"{"accessKey":"********","secretKey": "********"}"#
+        );
+        assert_eq!(
+            redact("RUSTFS_SECRET_KEY=hunter22 AWS_ACCESS_KEY_ID=AKIA1"),
+            "RUSTFS_SECRET_KEY=******** AWS_ACCESS_KEY_ID=********"
+        );
+        assert_eq!(redact("password: hunter22"), "password: ********");
+        assert_eq!(redact("auth_token=abc123"), "auth_token=********");
+    }
+
+    #[test]
+    fn masks_credential_flags() {
+        assert_eq!(
+            redact("rustfs /data --access-key admin --secret-key=hunter22"),
+            "rustfs /data --access-key ******** --secret-key=********"
+        );
+    }
+
+    #[test]
+    fn leaves_lookalike_fields_alone() {
+        for text in [
+            "tokens: 5",
+            "max_tokens=100",
+            "password_policy: strict",
+            "secrets_dir=/etc/rustfs",
+            "tokenizer=whitespace",
+        ] {
+            assert_eq!(redact(text), text);
+        }
+    }
+
+    #[test]
+    fn masks_registered_secrets_anywhere() {
+        register_secret("s3cr3t-value-for-tests");
+        register_secret("abc");
+        assert_eq!(
+            redact("echoed s3cr3t-value-for-tests back, abc stays"),
+            "echoed ******** back, abc stays"
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_user_patterns() {
+        let err = compile_user_patterns(&["ok".to_string(), "(unclosed".to_string()]).unwrap_err();
+        assert!(
+            matches!(err, Error::InvalidRedactionPattern(pattern, _) if pattern == "(unclosed")
+        );
+    }
+}
@@ -1,16 +1,73 @@
+use crate::config::{self, RustFsConfig};
+use crate::error::{Error, Result};
+use crate::log_files;
+use crate::log_parse;
+use crate::pidfile::{self, PidRecord};
+use crate::process::{
+    is_process_alive, send_terminate_signal, signal_pid, GRACEFUL_STOP_SUPPORTED,
+};
+use crate::redact;
+use crate::supervisor;
 use lazy_static::lazy_static;
 use regex::Regex;
-use std::collections::VecDeque;
+use serde::Serialize;
+use std::collections::{HashMap, VecDeque};
+use std::io::{Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
 use std::process::Child;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 use tauri::{AppHandle, Emitter, Manager};
 
+pub use rustfs_launcher_shared::events::{InstanceExitEvent, InstanceStateEvent, ProcessState};
+use rustfs_launcher_shared::events::{APP_LOG, RUSTFS_EXIT, RUSTFS_LOG, RUSTFS_STATE};
+pub use rustfs_launcher_shared::{LogCapacity, LogRecord, LogStream};
+
+/// Name used when the frontend does not specify an instance.
+pub const DEFAULT_INSTANCE: &str = "default";
+
+/// A named RustFS server managed by the launcher.
+pub struct Instance {
+    pub config: RustFsConfig,
+    pub state: ProcessState,
+    pub process: Option<Child>,
+    /// PID of a process left over from an earlier launcher session that we
+    /// reattached to; we can watch and signal it but hold no `Child` for it.
+    pub adopted_pid: Option<u32>,
+    /// Profile the running config was launched from, if any.
+    pub profile: Option<String>,
+    pub logs: VecDeque<LogRecord>,
+}
+
+impl Instance {
+    fn new(config: RustFsConfig) -> Self {
+        Self {
+            config,
+            state: ProcessState::Stopped,
+            process: None,
+            adopted_pid: None,
+            profile: None,
+            logs: VecDeque::new(),
+        }
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.process.is_some() || self.adopted_pid.is_some()
+    }
+
+    pub fn pid(&self) -> Option<u32> {
+        self.process.as_ref().map(|p| p.id()).or(self.adopted_pid)
+    }
+}
+
 lazy_static! {
-    pub static ref APP_LOGS: Arc<Mutex<VecDeque<String>>> = Arc::new(Mutex::new(VecDeque::new()));
-    pub static ref RUSTFS_LOGS: Arc<Mutex<VecDeque<String>>> =
+    pub static ref APP_LOGS: Arc<Mutex<VecDeque<LogRecord>>> =
         Arc::new(Mutex::new(VecDeque::new()));
     pub static ref APP_HANDLE: Arc<Mutex<Option<AppHandle>>> = Arc::new(Mutex::new(None));
-    pub static ref RUSTFS_PROCESS: Arc<Mutex<Option<Child>>> = Arc::new(Mutex::new(None));
+    pub static ref INSTANCES: Arc<Mutex<HashMap<String, Instance>>> =
+        Arc::new(Mutex::new(HashMap::new()));
+    static ref LOG_CAPACITY: Mutex<LogCapacity> = Mutex::new(LogCapacity::default());
 }
 
 lazy_static! {
@@ -21,89 +78,306 @@ fn clean_ansi_codes(s: &str) -> String {
     ANSI_REGEX.replace_all(s, "").to_string()
 }
 
-fn buffer_log(logs: &Arc<Mutex<VecDeque<String>>>, message: String, capacity: usize) -> String {
-    let cleaned_message = clean_ansi_codes(&message);
-    let log_entry = format!(
-        "[{}] {}",
-        chrono::Local::now().format("%H:%M:%S"),
-        cleaned_message
-    );
+/// `message` as it may be shown or stored: no colour codes, secrets masked.
+fn clean_message(message: &str) -> String {
+    redact::redact(&clean_ansi_codes(message))
+}
 
-    {
-        let mut logs = logs.lock().unwrap();
-        logs.push_back(log_entry.clone());
-        if logs.len() > capacity {
-            logs.pop_front();
+/// Sequence number of the next log record, shared by all logs.
+static NEXT_LOG_SEQ: AtomicU64 = AtomicU64::new(1);
+
+/// Builds the record for `message`, without a sequence number yet; see
+/// [`push_capped`]. RustFS output is parsed for its level, target, span and
+/// fields; otherwise a leading `[LEVEL]` tag becomes the record's level and
+/// anything else is logged at info.
+fn new_record(instance: Option<&str>, stream: LogStream, message: &str) -> LogRecord {
+    let message = clean_message(message);
+    let parsed = match stream {
+        LogStream::Launcher => log_parse::plain(&message),
+        _ => log_parse::parse(&message).unwrap_or_else(|| log_parse::plain(&message)),
+    };
+    LogRecord {
+        seq: 0,
+        timestamp: chrono::Local::now().to_rfc3339_opts(chrono::SecondsFormat::Millis, false),
+        stream,
+        level: parsed.level,
+        instance: instance.map(str::to_string),
+        message: parsed.message,
+        target: parsed.target,
+        span: parsed.span,
+        fields: parsed.fields,
+    }
+}
+
+/// Numbers `record` and appends it to `logs`, returning the numbered record.
+/// Called with the buffer's lock held, so that records from concurrent
+/// reader threads land in `seq` order; the log file is written under the same
+/// lock for the same reason.
+fn push_capped(
+    logs: &mut VecDeque<LogRecord>,
+    mut record: LogRecord,
+    capacity: usize,
+) -> LogRecord {
+    record.seq = NEXT_LOG_SEQ.fetch_add(1, Ordering::Relaxed);
+    logs.push_back(record.clone());
+    trim_logs(logs, capacity);
+    record
+}
+
+fn trim_logs(logs: &mut VecDeque<LogRecord>, capacity: usize) {
+    if logs.len() > capacity {
+        logs.drain(..logs.len() - capacity);
+    }
+}
+
+/// Changes and saves how many records are kept in memory, dropping the
+/// oldest ones beyond the new limits. Returns the limits applied.
+pub fn set_log_capacity(capacity: LogCapacity) -> Result<LogCapacity> {
+    let capacity = apply_log_capacity(capacity);
+    config::save_setting(config::LOG_CAPACITY_SETTING, &capacity)?;
+    Ok(capacity)
+}
+
+/// Applies the limits saved by an earlier session.
+pub fn restore_log_capacity() {
+    match config::load_setting(config::LOG_CAPACITY_SETTING) {
+        Ok(Some(capacity)) => {
+            apply_log_capacity(capacity);
         }
+        Ok(None) => {}
+        Err(e) => add_app_log(format!("Failed to load the log history limits: {}", e)),
     }
+}
 
-    log_entry
+fn apply_log_capacity(capacity: LogCapacity) -> LogCapacity {
+    let capacity = capacity.clamped();
+    *LOG_CAPACITY.lock().unwrap() = capacity;
+    trim_logs(&mut APP_LOGS.lock().unwrap(), capacity.app);
+    for instance in INSTANCES.lock().unwrap().values_mut() {
+        trim_logs(&mut instance.logs, capacity.rustfs);
+    }
+    capacity
 }
 
-fn emit_log(event_name: &str, log_entry: String) {
+pub fn log_capacity() -> LogCapacity {
+    *LOG_CAPACITY.lock().unwrap()
+}
+
+fn emit_event<S: Serialize + Clone>(event_name: &str, payload: S) {
     if let Some(handle) = APP_HANDLE.lock().unwrap().as_ref() {
         if let Some(window) = handle.get_webview_window("main") {
-            let _ = window.emit(event_name, log_entry);
+            let _ = window.emit(event_name, payload);
         }
     }
 }
 
-const APP_LOG_EVENT: &str = "app-log";
-const RUSTFS_LOG_EVENT: &str = "rustfs-log";
-const APP_LOG_CAPACITY: usize = 100;
-const RUSTFS_LOG_CAPACITY: usize = 1000;
+#[derive(Debug, Clone, Serialize)]
+pub struct InstanceInfo {
+    pub name: String,
+    pub running: bool,
+    pub state: ProcessState,
+    pub pid: Option<u32>,
+    pub profile: Option<String>,
+    pub config: RustFsConfig,
+}
 
 pub fn add_app_log(message: String) {
-    let entry = buffer_log(&APP_LOGS, message, APP_LOG_CAPACITY);
-    emit_log(APP_LOG_EVENT, entry);
+    let record = new_record(None, LogStream::Launcher, &message);
+    let capacity = log_capacity().app;
+    let record = {
+        let mut logs = APP_LOGS.lock().unwrap();
+        let record = push_capped(&mut logs, record, capacity);
+        log_files::append(log_files::APP_STREAM, &record);
+        record
+    };
+    emit_event(APP_LOG, record);
 }
 
-pub fn add_rustfs_log(message: String) {
-    let entry = buffer_log(&RUSTFS_LOGS, message, RUSTFS_LOG_CAPACITY);
-    emit_log(RUSTFS_LOG_EVENT, entry);
+/// Records a line of `instance`'s output read from `stream`, or a launcher
+/// note about it when `stream` is [`LogStream::Launcher`].
+pub fn add_rustfs_log(instance: &str, stream: LogStream, message: String) {
+    let record = new_record(Some(instance), stream, &message);
+    let capacity = log_capacity().rustfs;
+    let record = {
+        let mut instances = INSTANCES.lock().unwrap();
+        let Some(target) = instances.get_mut(instance) else {
+            return;
+        };
+        let record = push_capped(&mut target.logs, record, capacity);
+        log_files::append(&log_files::instance_stream(instance), &record);
+        record
+    };
+    emit_event(RUSTFS_LOG, record);
 }
 
 pub fn set_app_handle(handle: AppHandle) {
     *APP_HANDLE.lock().unwrap() = Some(handle);
 }
 
-pub fn get_app_logs() -> Vec<String> {
+pub fn get_app_logs() -> Vec<LogRecord> {
     APP_LOGS.lock().unwrap().iter().cloned().collect()
 }
 
-pub fn get_rustfs_logs() -> Vec<String> {
-    RUSTFS_LOGS.lock().unwrap().iter().cloned().collect()
+pub fn get_rustfs_logs(instance: &str) -> Vec<LogRecord> {
+    INSTANCES
+        .lock()
+        .unwrap()
+        .get(instance)
+        .map(|i| i.logs.iter().cloned().collect())
+        .unwrap_or_default()
+}
+
+/// Creates `name` or updates its config, keeping its log history.
+/// Fails if the instance is currently running.
+pub fn register_instance(name: &str, config: RustFsConfig) -> Result<()> {
+    let mut instances = INSTANCES.lock().unwrap();
+    match instances.get_mut(name) {
+        Some(instance) if instance.is_running() => Err(Error::InstanceRunning(name.to_string())),
+        Some(instance) => {
+            instance.config = config;
+            Ok(())
+        }
+        None => {
+            instances.insert(name.to_string(), Instance::new(config));
+            Ok(())
+        }
+    }
+}
+
+pub fn remove_instance(name: &str) -> Result<()> {
+    let mut instances = INSTANCES.lock().unwrap();
+    match instances.get(name) {
+        None => Err(Error::InstanceNotFound(name.to_string())),
+        Some(instance) if instance.is_running() => Err(Error::InstanceRunning(name.to_string())),
+        Some(_) => {
+            instances.remove(name);
+            Ok(())
+        }
+    }
+}
+
+/// The last `lines` lines RustFS wrote to stderr.
+pub fn stderr_tail(name: &str, lines: usize) -> Vec<String> {
+    let instances = INSTANCES.lock().unwrap();
+    let Some(instance) = instances.get(name) else {
+        return Vec::new();
+    };
+    let mut tail: Vec<String> = instance
+        .logs
+        .iter()
+        .rev()
+        .filter(|record| record.stream == LogStream::Stderr)
+        .take(lines)
+        .map(LogRecord::text)
+        .collect();
+    tail.reverse();
+    tail
+}
+
+pub fn set_process_state(name: &str, state: ProcessState) {
+    {
+        let mut instances = INSTANCES.lock().unwrap();
+        let Some(instance) = instances.get_mut(name) else {
+            return;
+        };
+        if instance.state == state {
+            return;
+        }
+        instance.state = state.clone();
+    }
+    emit_event(
+        RUSTFS_STATE,
+        InstanceStateEvent {
+            instance: name.to_string(),
+            state,
+        },
+    );
+}
+
+pub fn is_instance_running(name: &str) -> bool {
+    INSTANCES
+        .lock()
+        .unwrap()
+        .get(name)
+        .is_some_and(Instance::is_running)
+}
+
+pub fn get_process_state(name: &str) -> ProcessState {
+    INSTANCES
+        .lock()
+        .unwrap()
+        .get(name)
+        .map(|i| i.state.clone())
+        .unwrap_or(ProcessState::Stopped)
+}
+
+pub fn instance_pid(name: &str) -> Option<u32> {
+    INSTANCES.lock().unwrap().get(name).and_then(|i| i.pid())
+}
+
+pub fn list_instances() -> Vec<InstanceInfo> {
+    let instances = INSTANCES.lock().unwrap();
+    let mut list: Vec<InstanceInfo> = instances
+        .iter()
+        .map(|(name, instance)| InstanceInfo {
+            name: name.clone(),
+            running: instance.is_running(),
+            state: instance.state.clone(),
+            pid: instance.pid(),
+            profile: instance.profile.clone(),
+            config: instance.config.clone(),
+        })
+        .collect();
+    list.sort_by(|a, b| a.name.cmp(&b.name));
+    list
 }
 
-pub fn set_rustfs_process(process: Child) {
+pub fn set_instance_profile(name: &str, profile: Option<String>) {
+    let message = profile.as_ref().map(|profile| {
+        format!(
+            "RustFS instance '{}' is running profile '{}'",
+            name, profile
+        )
+    });
+    {
+        let mut instances = INSTANCES.lock().unwrap();
+        let Some(instance) = instances.get_mut(name) else {
+            return;
+        };
+        instance.profile = profile;
+    }
+    if let Some(message) = message {
+        add_app_log(message);
+    }
+}
+
+pub fn set_rustfs_process(instance: &str, process: Child) {
     let pid = process.id();
-    *RUSTFS_PROCESS.lock().unwrap() = Some(process);
-    add_app_log(format!("RustFS process registered with PID: {}", pid));
+    if let Some(target) = INSTANCES.lock().unwrap().get_mut(instance) {
+        target.process = Some(process);
+    }
+    add_app_log(format!(
+        "RustFS instance '{}' registered with PID: {}",
+        instance, pid
+    ));
 
     // Spawn a monitor thread
+    let instance = instance.to_string();
     std::thread::spawn(move || {
         loop {
             std::thread::sleep(std::time::Duration::from_secs(1));
 
             let mut should_break = false;
+            let mut exited = None;
+            let mut data_path = String::new();
             {
-                let mut process_guard = RUSTFS_PROCESS.lock().unwrap();
-                if let Some(child) = process_guard.as_mut() {
+                let mut instances = INSTANCES.lock().unwrap();
+                let slot = instances.get_mut(&instance).map(|i| &mut i.process);
+                if let Some(Some(child)) = slot {
                     if child.id() == pid {
                         match child.try_wait() {
                             Ok(Some(status)) => {
-                                add_app_log(format!(
-                                    "RustFS process exited with status: {}",
-                                    status
-                                ));
-
-                                // Emit exit event
-                                if let Some(handle) = APP_HANDLE.lock().unwrap().as_ref() {
-                                    let _ = handle.emit("rustfs-exit", format!("{}", status));
-                                }
-
-                                // Clear the process from state since it exited
-                                *process_guard = None;
+                                exited = Some(status);
                                 should_break = true;
                             }
                             Ok(None) => {
@@ -114,6 +388,13 @@ pub fn set_rustfs_process(process: Child) {
                                 should_break = true;
                             }
                         }
+                        if exited.is_some() {
+                            // Clear the process from state since it exited
+                            if let Some(target) = instances.get_mut(&instance) {
+                                target.process = None;
+                                data_path = target.config.data_path.clone();
+                            }
+                        }
                     } else {
                         // PID mismatch, likely a new process was started
                         should_break = true;
@@ -124,6 +405,40 @@ pub fn set_rustfs_process(process: Child) {
                 }
             }
 
+            if let Some(status) = exited {
+                pidfile::remove(&data_path);
+                let requested = matches!(get_process_state(&instance), ProcessState::Stopping);
+                set_process_state(
+                    &instance,
+                    if status.success() {
+                        ProcessState::Stopped
+                    } else {
+                        ProcessState::Crashed {
+                            code: status.code(),
+                            at: chrono::Local::now().to_rfc3339(),
+                        }
+                    },
+                );
+                add_app_log(format!(
+                    "RustFS instance '{}' exited with status: {}",
+                    instance, status
+                ));
+
+                // Emit exit event
+                if let Some(handle) = APP_HANDLE.lock().unwrap().as_ref() {
+                    let _ = handle.emit(
+                        RUSTFS_EXIT,
+                        InstanceExitEvent {
+                            instance: instance.clone(),
+                            status: format!("{}", status),
+                            unexpected: !requested && !status.success(),
+                        },
+                    );
+                }
+
+                supervisor::on_process_exit(&instance, Some(status));
+            }
+
             if should_break {
                 break;
             }
@@ -131,23 +446,474 @@ pub fn set_rustfs_process(process: Child) {
     });
 }
 
-pub fn terminate_rustfs_process() {
-    let mut process_guard = RUSTFS_PROCESS.lock().unwrap();
-    if let Some(mut process) = process_guard.take() {
-        let pid = process.id();
-        add_app_log(format!("Terminating RustFS process with PID: {}", pid));
+/// How a stop request was carried out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum StopOutcome {
+    /// There was no process to stop.
+    NotRunning,
+    /// The process exited on its own within the grace period.
+    Graceful,
+    /// The process outlived the grace period and had to be killed.
+    Killed,
+    /// The process was killed without a graceful shutdown attempt, because
+    /// there was no grace period or the shutdown request could not be sent.
+    KilledImmediately,
+    /// Killing the process failed; it may still be running.
+    Failed,
+}
+
+impl StopOutcome {
+    pub fn describe(&self) -> &'static str {
+        match self {
+            StopOutcome::NotRunning => "No RustFS process was running",
+            StopOutcome::Graceful => "RustFS shut down gracefully",
+            StopOutcome::Killed => "RustFS did not exit in time and was killed",
+            StopOutcome::KilledImmediately => "RustFS was killed without a graceful shutdown",
+            StopOutcome::Failed => "RustFS could not be killed and may still be running",
+        }
+    }
+}
+
+pub fn terminate_rustfs_process(instance: &str) -> StopOutcome {
+    let grace = INSTANCES
+        .lock()
+        .unwrap()
+        .get(instance)
+        .map(|i| Duration::from_secs(i.config.shutdown_grace_secs))
+        .unwrap_or_default();
+    terminate_rustfs_process_with_grace(instance, grace)
+}
+
+/// Stops every running instance; used when the launcher exits.
+pub fn terminate_all_instances() {
+    let names: Vec<String> = INSTANCES.lock().unwrap().keys().cloned().collect();
+    for name in names {
+        terminate_rustfs_process(&name);
+    }
+}
+
+pub fn terminate_rustfs_process_with_grace(instance: &str, grace: Duration) -> StopOutcome {
+    // A deliberate stop must not be undone by the restart supervisor.
+    supervisor::cancel(instance);
 
-        match process.kill() {
-            Ok(_) => {
-                add_app_log("RustFS process terminated successfully".to_string());
-                // Wait for the process to actually exit
-                let _ = process.wait();
+    // Take the child out of the slot so the monitor thread stops watching it
+    // and the lock is not held while we wait for it to exit.
+    let (process, adopted_pid, data_path) = {
+        let mut instances = INSTANCES.lock().unwrap();
+        match instances.get_mut(instance) {
+            Some(target) => (
+                target.process.take(),
+                target.adopted_pid.take(),
+                target.config.data_path.clone(),
+            ),
+            None => (None, None, String::new()),
+        }
+    };
+
+    let previous_state = get_process_state(instance);
+    if let Some(pid) = adopted_pid {
+        set_process_state(instance, ProcessState::Stopping);
+        let outcome = terminate_pid(pid, grace);
+        if outcome == StopOutcome::Failed {
+            if let Some(target) = INSTANCES.lock().unwrap().get_mut(instance) {
+                target.adopted_pid = Some(pid);
             }
+            set_process_state(instance, previous_state);
+            return outcome;
+        }
+        pidfile::remove(&data_path);
+        set_process_state(instance, ProcessState::Stopped);
+        return outcome;
+    }
+
+    let Some(mut process) = process else {
+        add_app_log(format!("No RustFS process to terminate for '{}'", instance));
+        // A crashed or restarting instance is settled by an explicit stop.
+        set_process_state(instance, ProcessState::Stopped);
+        return StopOutcome::NotRunning;
+    };
+    set_process_state(instance, ProcessState::Stopping);
+    let outcome = terminate_child(instance, &mut process, grace);
+    if outcome == StopOutcome::Failed {
+        // Keep watching the process we could not get rid of.
+        if let Some(target) = INSTANCES.lock().unwrap().get_mut(instance) {
+            target.process = Some(process);
+        }
+        set_process_state(instance, previous_state);
+        return outcome;
+    }
+    pidfile::remove(&data_path);
+    set_process_state(instance, ProcessState::Stopped);
+    outcome
+}
+
+/// Kills a process that never became ready. Unlike a user stop this leaves
+/// the supervisor alone, so a failed restart still counts against its budget.
+pub fn abort_startup(instance: &str) {
+    let (process, data_path) = {
+        let mut instances = INSTANCES.lock().unwrap();
+        match instances.get_mut(instance) {
+            Some(target) => (target.process.take(), target.config.data_path.clone()),
+            None => (None, String::new()),
+        }
+    };
+    pidfile::remove(&data_path);
+
+    // If the process already exited, the monitor thread has recorded the crash.
+    if let Some(mut process) = process {
+        terminate_child(instance, &mut process, Duration::ZERO);
+        set_process_state(
+            instance,
+            ProcessState::Crashed {
+                code: None,
+                at: chrono::Local::now().to_rfc3339(),
+            },
+        );
+    }
+}
+
+fn terminate_child(instance: &str, process: &mut Child, grace: Duration) -> StopOutcome {
+    let pid = process.id();
+    add_app_log(format!(
+        "Stopping RustFS instance '{}' with PID: {} (grace period: {}s)",
+        instance,
+        pid,
+        grace.as_secs()
+    ));
+
+    let mut waited = false;
+    if !grace.is_zero() && !GRACEFUL_STOP_SUPPORTED {
+        add_app_log(
+            "Graceful shutdown is not available on this platform, killing RustFS".to_string(),
+        );
+    } else if !grace.is_zero() {
+        match send_terminate_signal(process) {
+            Ok(()) => {
+                if wait_for_exit(process, grace) {
+                    add_app_log("RustFS process shut down gracefully".to_string());
+                    return StopOutcome::Graceful;
+                }
+                waited = true;
+                add_app_log(format!(
+                    "RustFS process did not exit within {}s, killing it",
+                    grace.as_secs()
+                ));
+            }
+            Err(e) => {
+                add_app_log(format!(
+                    "Failed to send shutdown signal to RustFS process: {}, killing it",
+                    e
+                ));
+            }
+        }
+    }
+
+    match process.kill() {
+        Ok(_) => {
+            add_app_log("RustFS process terminated successfully".to_string());
+            // Wait for the process to actually exit
+            let _ = process.wait();
+            if waited {
+                StopOutcome::Killed
+            } else {
+                StopOutcome::KilledImmediately
+            }
+        }
+        Err(e) => {
+            add_app_log(format!("[ERROR] Failed to terminate RustFS process: {}", e));
+            StopOutcome::Failed
+        }
+    }
+}
+
+/// Polls `child` until it exits or `grace` elapses. Returns `true` if it exited.
+fn wait_for_exit(child: &mut Child, grace: Duration) -> bool {
+    let started = Instant::now();
+    let mut last_report = 0;
+
+    loop {
+        match child.try_wait() {
+            Ok(Some(status)) => {
+                add_app_log(format!("RustFS process exited with status: {}", status));
+                return true;
+            }
+            Ok(None) => {}
             Err(e) => {
-                add_app_log(format!("Failed to terminate RustFS process: {}", e));
+                add_app_log(format!("Error waiting for RustFS process: {}", e));
+                return false;
+            }
+        }
+
+        let elapsed = started.elapsed();
+        if elapsed >= grace {
+            return false;
+        }
+
+        let elapsed_secs = elapsed.as_secs();
+        if elapsed_secs > last_report {
+            last_report = elapsed_secs;
+            add_app_log(format!(
+                "Waiting for RustFS to shut down... ({}/{}s)",
+                elapsed_secs,
+                grace.as_secs()
+            ));
+        }
+
+        std::thread::sleep(Duration::from_millis(100));
+    }
+}
+
+/// Stops a process known only by PID: SIGTERM (CTRL_BREAK on Windows), wait
+/// out the grace period, then kill it.
+pub fn terminate_pid(pid: u32, grace: Duration) -> StopOutcome {
+    if !is_process_alive(pid) {
+        return StopOutcome::NotRunning;
+    }
+    add_app_log(format!(
+        "Stopping orphaned RustFS process with PID: {} (grace period: {}s)",
+        pid,
+        grace.as_secs()
+    ));
+
+    // Without a graceful signal, `signal_pid` kills outright; waiting after
+    // it would report a kill as a graceful exit.
+    let mut waited = false;
+    if !grace.is_zero() && GRACEFUL_STOP_SUPPORTED && signal_pid(pid, false).is_ok() {
+        let started = Instant::now();
+        while started.elapsed() < grace {
+            if !is_process_alive(pid) {
+                add_app_log("Orphaned RustFS process shut down gracefully".to_string());
+                return StopOutcome::Graceful;
             }
+            std::thread::sleep(Duration::from_millis(100));
         }
-    } else {
-        add_app_log("No RustFS process to terminate".to_string());
+        waited = true;
+    }
+
+    match signal_pid(pid, true) {
+        Ok(()) if waited => StopOutcome::Killed,
+        Ok(()) => StopOutcome::KilledImmediately,
+        Err(e) => {
+            add_app_log(format!(
+                "[ERROR] Failed to kill orphaned RustFS process: {}",
+                e
+            ));
+            StopOutcome::Failed
+        }
+    }
+}
+
+/// Reattaches to a RustFS process from a previous launcher session: the
+/// instance is marked running, a thread watches the PID and the RustFS log
+/// directory is tailed into the instance's log buffer.
+pub fn adopt_process(record: PidRecord) -> Result<()> {
+    register_instance(&record.instance, record.config.clone())?;
+    if let Some(target) = INSTANCES.lock().unwrap().get_mut(&record.instance) {
+        target.adopted_pid = Some(record.pid);
+    }
+    set_process_state(
+        &record.instance,
+        ProcessState::Running {
+            pid: record.pid,
+            since: record.started_at.to_rfc3339(),
+        },
+    );
+    add_app_log(format!(
+        "Reattached to RustFS instance '{}' with PID: {}",
+        record.instance, record.pid
+    ));
+
+    let instance = record.instance.clone();
+    let pid = record.pid;
+    let data_path = record.config.data_path.clone();
+    let address = format!(
+        "{}:{}",
+        record.config.host.as_deref().unwrap_or("127.0.0.1"),
+        record.config.port.unwrap_or(9000)
+    );
+    let mut reachable = None;
+    let mut ticks = 0u64;
+    std::thread::spawn(move || loop {
+        std::thread::sleep(Duration::from_secs(1));
+        ticks += 1;
+
+        let still_adopted = INSTANCES
+            .lock()
+            .unwrap()
+            .get(&instance)
+            .and_then(|i| i.adopted_pid)
+            == Some(pid);
+        if !still_adopted {
+            break;
+        }
+
+        if !is_process_alive(pid) {
+            if let Some(target) = INSTANCES.lock().unwrap().get_mut(&instance) {
+                target.adopted_pid = None;
+            }
+            pidfile::remove(&data_path);
+            let requested = matches!(get_process_state(&instance), ProcessState::Stopping);
+            set_process_state(
+                &instance,
+                ProcessState::Crashed {
+                    code: None,
+                    at: chrono::Local::now().to_rfc3339(),
+                },
+            );
+            add_app_log(format!(
+                "Reattached RustFS instance '{}' (PID {}) has exited",
+                instance, pid
+            ));
+            if let Some(handle) = APP_HANDLE.lock().unwrap().as_ref() {
+                let _ = handle.emit(
+                    RUSTFS_EXIT,
+                    InstanceExitEvent {
+                        instance: instance.clone(),
+                        status: "unknown (reattached process)".to_string(),
+                        unexpected: !requested,
+                    },
+                );
+            }
+            break;
+        }
+
+        // The process is alive; check every few seconds that it still serves.
+        if ticks % 5 == 1 {
+            let now_reachable = address
+                .parse()
+                .map(|addr| {
+                    std::net::TcpStream::connect_timeout(&addr, Duration::from_millis(1000)).is_ok()
+                })
+                .unwrap_or(false);
+            if reachable != Some(now_reachable) {
+                add_app_log(format!(
+                    "Reattached RustFS instance '{}' is {} at {}",
+                    instance,
+                    if now_reachable {
+                        "reachable"
+                    } else {
+                        "not reachable"
+                    },
+                    address
+                ));
+                reachable = Some(now_reachable);
+            }
+        }
+    });
+
+    let instance = record.instance;
+    let logs_dir = PathBuf::from(record.logs_dir);
+    std::thread::spawn(move || tail_log_dir(&instance, pid, &logs_dir));
+
+    Ok(())
+}
+
+fn newest_file(dir: &Path) -> Option<PathBuf> {
+    std::fs::read_dir(dir)
+        .ok()?
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| {
+            let metadata = entry.metadata().ok()?;
+            metadata
+                .is_file()
+                .then(|| (metadata.modified().ok(), entry.path()))
+        })
+        .max_by_key(|(modified, _)| *modified)
+        .map(|(_, path)| path)
+}
+
+/// Follows the newest file in `dir` while the adopted process is alive,
+/// starting from its current end so old output is not replayed.
+fn tail_log_dir(instance: &str, pid: u32, dir: &Path) {
+    let mut current: Option<(PathBuf, u64)> = None;
+    // Bytes after the last newline; kept raw so a character split across two
+    // reads still decodes.
+    let mut partial: Vec<u8> = Vec::new();
+
+    loop {
+        let still_adopted = INSTANCES
+            .lock()
+            .unwrap()
+            .get(instance)
+            .and_then(|i| i.adopted_pid)
+            == Some(pid);
+        if !still_adopted {
+            break;
+        }
+
+        if let Some(newest) = newest_file(dir) {
+            let switched = current.as_ref().map(|(path, _)| path) != Some(&newest);
+            if switched {
+                let start = if current.is_none() {
+                    std::fs::metadata(&newest).map(|m| m.len()).unwrap_or(0)
+                } else {
+                    0
+                };
+                add_rustfs_log(
+                    instance,
+                    LogStream::Launcher,
+                    format!("Following {}", newest.display()),
+                );
+                current = Some((newest, start));
+                partial.clear();
+            }
+        }
+
+        if let Some((path, offset)) = current.as_mut() {
+            if let Ok(mut file) = std::fs::File::open(&*path) {
+                if file.seek(SeekFrom::Start(*offset)).is_ok() {
+                    // Bytes, not a string: an invalid UTF-8 byte must not
+                    // stop the offset from moving past it.
+                    if let Ok(read) = file.read_to_end(&mut partial) {
+                        *offset += read as u64;
+                        while let Some(end) = partial.iter().position(|&b| b == b'\n') {
+                            let line: Vec<u8> = partial.drain(..=end).collect();
+                            let line = String::from_utf8_lossy(&line);
+                            let line = line.trim_end();
+                            if !line.is_empty() {
+                                add_rustfs_log(instance, LogStream::File, line.to_string());
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        std::thread::sleep(Duration::from_secs(1));
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use std::process::Command;
+
+    fn spawn(script: &str) -> Child {
+        let child = Command::new("sh").arg("-c").arg(script).spawn().unwrap();
+        // Give the shell time to install its traps.
+        std::thread::sleep(Duration::from_millis(200));
+        child
+    }
+
+    #[test]
+    fn stop_outcome_says_how_the_process_ended() {
+        let mut child = spawn("exec sleep 30");
+        assert_eq!(
+            terminate_child("test", &mut child, Duration::from_secs(5)),
+            StopOutcome::Graceful
+        );
+
+        let mut child = spawn("exec sleep 30");
+        assert_eq!(
+            terminate_child("test", &mut child, Duration::ZERO),
+            StopOutcome::KilledImmediately
+        );
+
+        let mut child = spawn("trap '' TERM; while :; do sleep 0.1; done");
+        assert_eq!(
+            terminate_child("test", &mut child, Duration::from_millis(300)),
+            StopOutcome::Killed
+        );
     }
 }
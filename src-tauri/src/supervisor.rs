@@ -0,0 +1,268 @@
+use crate::config::{RestartPolicy, RustFsConfig};
+use crate::error::Result;
+use crate::process;
+use crate::state::{add_app_log, set_process_state, ProcessState, APP_HANDLE};
+use lazy_static::lazy_static;
+use std::collections::{HashMap, VecDeque};
+use std::process::ExitStatus;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tauri::Emitter;
+
+use rustfs_launcher_shared::events::RUSTFS_SUPERVISOR;
+pub use rustfs_launcher_shared::events::{SupervisorEvent, SupervisorState};
+
+#[derive(Default)]
+struct Supervisor {
+    /// Bumped whenever the user launches or stops RustFS, so a restart that
+    /// was scheduled before then is abandoned.
+    generation: u64,
+    config: Option<RustFsConfig>,
+    restarts: VecDeque<Instant>,
+    /// Generation whose restart loop is running. A relaunched process that
+    /// dies before it is ready is left to that loop rather than starting a
+    /// second one.
+    restarting: Option<u64>,
+}
+
+lazy_static! {
+    static ref SUPERVISORS: Mutex<HashMap<String, Supervisor>> = Mutex::new(HashMap::new());
+}
+
+fn emit_supervisor_event(event: SupervisorEvent) {
+    if let Some(handle) = APP_HANDLE.lock().unwrap().as_ref() {
+        let _ = handle.emit(RUSTFS_SUPERVISOR, event);
+    }
+}
+
+/// Starts supervising `instance` once a user-initiated launch of `config` is
+/// ready; a process that fails to start is never restarted.
+pub fn start(instance: &str, config: RustFsConfig) {
+    let mut supervisors = SUPERVISORS.lock().unwrap();
+    let supervisor = supervisors.entry(instance.to_string()).or_default();
+    supervisor.generation += 1;
+    supervisor.config = Some(config);
+    supervisor.restarts.clear();
+    drop(supervisors);
+
+    emit_supervisor_event(SupervisorEvent {
+        instance: instance.to_string(),
+        state: SupervisorState::Idle,
+        attempt: 0,
+        delay_ms: 0,
+        message: "Supervisor reset".to_string(),
+    });
+}
+
+/// Stops supervising `instance`, abandoning any pending restart.
+pub fn cancel(instance: &str) {
+    let mut supervisors = SUPERVISORS.lock().unwrap();
+    let Some(supervisor) = supervisors.get_mut(instance) else {
+        return;
+    };
+    supervisor.generation += 1;
+    supervisor.config = None;
+    supervisor.restarts.clear();
+}
+
+fn should_restart(policy: RestartPolicy, status: Option<ExitStatus>) -> bool {
+    match policy {
+        RestartPolicy::Never => false,
+        RestartPolicy::OnFailure => !status.map(|s| s.success()).unwrap_or(false),
+        RestartPolicy::Always => true,
+    }
+}
+
+fn backoff_delay(initial_ms: u64, max_ms: u64, restarts_in_window: usize) -> Duration {
+    let exponent = restarts_in_window.min(16) as u32;
+    let delay = initial_ms.saturating_mul(1u64 << exponent);
+    Duration::from_millis(delay.min(max_ms))
+}
+
+/// Called by the monitor thread once the supervised process has exited on its
+/// own. Blocks for the backoff delay and relaunches if the policy allows it.
+pub fn on_process_exit(instance: &str, status: Option<ExitStatus>) {
+    on_exit(instance, status, process::launch);
+}
+
+fn on_exit<F>(instance: &str, status: Option<ExitStatus>, launch: F)
+where
+    F: Fn(&str, RustFsConfig) -> Result<String>,
+{
+    let generation = {
+        let mut supervisors = SUPERVISORS.lock().unwrap();
+        let Some(supervisor) = supervisors.get_mut(instance) else {
+            return;
+        };
+        if supervisor.restarting == Some(supervisor.generation) {
+            return;
+        }
+        supervisor.restarting = Some(supervisor.generation);
+        supervisor.generation
+    };
+
+    restart(instance, status, launch);
+
+    if let Some(supervisor) = SUPERVISORS.lock().unwrap().get_mut(instance) {
+        if supervisor.restarting == Some(generation) {
+            supervisor.restarting = None;
+        }
+    }
+}
+
+fn restart<F>(instance: &str, status: Option<ExitStatus>, launch: F)
+where
+    F: Fn(&str, RustFsConfig) -> Result<String>,
+{
+    loop {
+        let (generation, config, attempt, delay) = {
+            let mut supervisors = SUPERVISORS.lock().unwrap();
+            let Some(supervisor) = supervisors.get_mut(instance) else {
+                return;
+            };
+            let Some(config) = supervisor.config.clone() else {
+                return;
+            };
+            let policy = &config.supervisor;
+            if !should_restart(policy.restart_policy, status) {
+                return;
+            }
+
+            let window = Duration::from_secs(policy.restart_window_secs);
+            let now = Instant::now();
+            while let Some(oldest) = supervisor.restarts.front() {
+                if now.duration_since(*oldest) > window {
+                    supervisor.restarts.pop_front();
+                } else {
+                    break;
+                }
+            }
+
+            if supervisor.restarts.len() >= policy.max_restarts as usize {
+                let message = format!(
+                    "RustFS instance '{}' restarted {} times within {}s, giving up (crash loop)",
+                    instance,
+                    supervisor.restarts.len(),
+                    policy.restart_window_secs
+                );
+                let attempt = supervisor.restarts.len() as u32;
+                supervisor.config = None;
+                drop(supervisors);
+
+                add_app_log(message.clone());
+                emit_supervisor_event(SupervisorEvent {
+                    instance: instance.to_string(),
+                    state: SupervisorState::CrashLoop,
+                    attempt,
+                    delay_ms: 0,
+                    message,
+                });
+                return;
+            }
+
+            let delay = backoff_delay(
+                policy.initial_backoff_ms,
+                policy.max_backoff_ms,
+                supervisor.restarts.len(),
+            );
+            supervisor.restarts.push_back(now);
+            (
+                supervisor.generation,
+                config,
+                supervisor.restarts.len() as u32,
+                delay,
+            )
+        };
+
+        let message = format!(
+            "Restarting RustFS instance '{}' in {}ms (attempt {})",
+            instance,
+            delay.as_millis(),
+            attempt
+        );
+        add_app_log(message.clone());
+        set_process_state(instance, ProcessState::Restarting);
+        emit_supervisor_event(SupervisorEvent {
+            instance: instance.to_string(),
+            state: SupervisorState::Restarting,
+            attempt,
+            delay_ms: delay.as_millis() as u64,
+            message,
+        });
+
+        std::thread::sleep(delay);
+
+        let current_generation = SUPERVISORS
+            .lock()
+            .unwrap()
+            .get(instance)
+            .map(|s| s.generation);
+        if current_generation != Some(generation) {
+            add_app_log("Pending RustFS restart cancelled".to_string());
+            return;
+        }
+
+        match launch(instance, config) {
+            Ok(message) => {
+                add_app_log(format!("Supervisor restart succeeded: {}", message));
+                emit_supervisor_event(SupervisorEvent {
+                    instance: instance.to_string(),
+                    state: SupervisorState::Idle,
+                    attempt,
+                    delay_ms: 0,
+                    message,
+                });
+                return;
+            }
+            Err(e) => {
+                // Treat a failed relaunch like another crash and go around again.
+                add_app_log(format!("Supervisor restart failed: {}", e));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::Error;
+    use rustfs_launcher_shared::config::SupervisorConfig;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    #[test]
+    fn relaunch_failing_before_readiness_is_retried_by_one_loop() {
+        let instance = "supervisor-early-exit";
+        start(
+            instance,
+            RustFsConfig {
+                supervisor: SupervisorConfig {
+                    restart_policy: RestartPolicy::Always,
+                    initial_backoff_ms: 0,
+                    max_backoff_ms: 0,
+                    ..SupervisorConfig::default()
+                },
+                ..RustFsConfig::default()
+            },
+        );
+
+        let launches = AtomicU32::new(0);
+        on_exit(instance, None, |name, _| {
+            if launches.fetch_add(1, Ordering::SeqCst) > 0 {
+                return Ok("ready".to_string());
+            }
+            // The relaunched process dies while the launch still waits for
+            // it, so its monitor reports the exit first.
+            on_exit(name, None, |_, _| panic!("started a second restart loop"));
+            Err(Error::FailedToStart {
+                reason: "exited before it was ready".to_string(),
+                stderr_tail: String::new(),
+            })
+        });
+
+        assert_eq!(launches.load(Ordering::SeqCst), 2);
+        let supervisors = SUPERVISORS.lock().unwrap();
+        let supervisor = &supervisors[instance];
+        assert_eq!(supervisor.restarts.len(), 2);
+        assert_eq!(supervisor.restarting, None);
+    }
+}
@@ -0,0 +1,375 @@
+use crate::binary;
+use crate::config::{self, BinarySource};
+use crate::download;
+use crate::error::{Error, Result};
+use crate::process;
+use crate::state::{self, add_app_log, InstanceInfo};
+use crate::supervisor;
+use crate::versions;
+use rustfs_launcher_shared::{UpdateInfo, UpgradeOutcome};
+use serde::Deserialize;
+use std::cmp::Ordering;
+use std::time::Duration;
+
+/// GitHub releases API for RustFS, newest first.
+pub const DEFAULT_RELEASES_FEED: &str = "https://api.github.com/repos/rustfs/rustfs/releases";
+
+#[derive(Debug, Deserialize)]
+struct Release {
+    tag_name: String,
+    #[serde(default)]
+    draft: bool,
+    html_url: Option<String>,
+    #[serde(default)]
+    assets: Vec<ReleaseAsset>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ReleaseAsset {
+    name: String,
+    browser_download_url: String,
+}
+
+/// A release list, or the single object `releases/latest` answers with.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Feed {
+    List(Vec<Release>),
+    Single(Release),
+}
+
+impl Release {
+    fn version(&self) -> &str {
+        self.tag_name.trim_start_matches(['v', 'V'])
+    }
+
+    /// The archive built for this platform, e.g. `rustfs-linux-x86_64-…zip`.
+    fn platform_asset(&self) -> Option<&ReleaseAsset> {
+        use std::env::consts::{ARCH, OS};
+        let prefix = format!("rustfs-{}-{}", OS, ARCH);
+        self.assets.iter().find(|asset| {
+            let name = asset.name.to_ascii_lowercase();
+            name.starts_with(&prefix)
+                && (name.ends_with(".zip") || name.ends_with(".tar.gz") || name.ends_with(".tgz"))
+        })
+    }
+}
+
+/// Orders versions the way semver does: numeric core first, then a
+/// pre-release sorts before its release. A leading `v` and build metadata
+/// are ignored.
+fn compare_versions(a: &str, b: &str) -> Ordering {
+    fn split(version: &str) -> (Vec<u64>, Option<&str>) {
+        let version = version.trim().trim_start_matches(['v', 'V']);
+        let version = version.split('+').next().unwrap_or_default();
+        let (core, pre) = match version.split_once('-') {
+            Some((core, pre)) => (core, Some(pre)),
+            None => (version, None),
+        };
+        let mut parts: Vec<u64> = core.split('.').map(|p| p.parse().unwrap_or(0)).collect();
+        parts.resize(parts.len().max(3), 0);
+        (parts, pre)
+    }
+
+    let (a_core, a_pre) = split(a);
+    let (b_core, b_pre) = split(b);
+    a_core.cmp(&b_core).then_with(|| match (a_pre, b_pre) {
+        (None, None) => Ordering::Equal,
+        (None, Some(_)) => Ordering::Greater,
+        (Some(_), None) => Ordering::Less,
+        (Some(a), Some(b)) => {
+            let mut a_ids = a.split('.');
+            let mut b_ids = b.split('.');
+            loop {
+                let ordering = match (a_ids.next(), b_ids.next()) {
+                    (None, None) => return Ordering::Equal,
+                    (None, Some(_)) => Ordering::Less,
+                    (Some(_), None) => Ordering::Greater,
+                    (Some(a), Some(b)) => match (a.parse::<u64>(), b.parse::<u64>()) {
+                        (Ok(a), Ok(b)) => a.cmp(&b),
+                        (Ok(_), Err(_)) => Ordering::Less,
+                        (Err(_), Ok(_)) => Ordering::Greater,
+                        (Err(_), Err(_)) => a.cmp(b),
+                    },
+                };
+                if ordering != Ordering::Equal {
+                    return ordering;
+                }
+            }
+        }
+    })
+}
+
+/// Picks the newest published release from a GitHub-releases-style feed.
+fn latest_release(json: &str) -> Result<Release> {
+    let releases =
+        match serde_json::from_str(json).map_err(|e| Error::InvalidFeed(e.to_string()))? {
+            Feed::List(releases) => releases,
+            Feed::Single(release) => vec![release],
+        };
+    releases
+        .into_iter()
+        .filter(|release| !release.draft)
+        .max_by(|a, b| compare_versions(a.version(), b.version()))
+        .ok_or_else(|| Error::InvalidFeed("it lists no published releases".to_string()))
+}
+
+/// The releases feed the user picked; empty means [`DEFAULT_RELEASES_FEED`].
+pub fn releases_feed() -> Result<String> {
+    Ok(config::load_setting(config::RELEASES_FEED_SETTING)?.unwrap_or_default())
+}
+
+pub fn set_releases_feed(url: &str) -> Result<()> {
+    config::save_setting(config::RELEASES_FEED_SETTING, &url.trim())
+}
+
+/// The newest release in the saved feed.
+fn fetch_latest() -> Result<Release> {
+    let feed_url = releases_feed()?;
+    let feed_url = match feed_url.trim() {
+        "" => DEFAULT_RELEASES_FEED,
+        url => url,
+    };
+    latest_release(&download::get_text(feed_url)?)
+}
+
+fn current_version(source: BinarySource, binary_path: Option<&str>) -> Option<String> {
+    binary::inspect(source, binary_path)
+        .ok()
+        .and_then(|info| info.version)
+}
+
+/// Compares the binary `source` selects with the newest release in the feed.
+pub fn check(source: BinarySource, binary_path: Option<&str>) -> Result<UpdateInfo> {
+    let release = fetch_latest()?;
+    let current = current_version(source, binary_path);
+    let available = current
+        .as_deref()
+        .is_some_and(|current| compare_versions(release.version(), current) == Ordering::Greater);
+    if available {
+        add_app_log(format!(
+            "RustFS {} is available (running {})",
+            release.version(),
+            current.as_deref().unwrap_or("unknown")
+        ));
+    }
+    Ok(UpdateInfo {
+        current,
+        latest: release.version().to_string(),
+        available,
+        release_url: release.html_url.clone(),
+        asset: release.platform_asset().map(|asset| asset.name.clone()),
+    })
+}
+
+/// Stops `instances` gracefully, then launches each again with the config it
+/// was running with, stopping at the first that fails readiness.
+fn restart(instances: &[InstanceInfo]) -> Result<Vec<String>> {
+    for instance in instances {
+        let grace = Duration::from_secs(instance.config.shutdown_grace_secs);
+        state::terminate_rustfs_process_with_grace(&instance.name, grace);
+    }
+    let mut restarted = Vec::new();
+    for instance in instances {
+        process::launch(&instance.name, instance.config.clone())?;
+        supervisor::start(&instance.name, instance.config.clone());
+        state::set_instance_profile(&instance.name, instance.profile.clone());
+        restarted.push(instance.name.clone());
+    }
+    Ok(restarted)
+}
+
+/// The outcome of an upgrade with nothing to do, unless `latest` is newer
+/// than `current`. A binary whose version cannot be read is upgraded.
+fn already_current(latest: &str, current: Option<&str>) -> Option<UpgradeOutcome> {
+    let current = current?;
+    if compare_versions(latest, current) == Ordering::Greater {
+        return None;
+    }
+    Some(UpgradeOutcome {
+        from: Some(current.to_string()),
+        to: current.to_string(),
+        restarted: Vec::new(),
+        rolled_back: false,
+        message: format!("RustFS {} is already up to date", current),
+    })
+}
+
+/// Installs the newest release as the active managed version and restarts
+/// every running instance that uses it. If one of them fails readiness on the
+/// new binary, the previous binary is restored and they are restarted again.
+pub fn upgrade() -> Result<UpgradeOutcome> {
+    let release = fetch_latest()?;
+    let from = current_version(BinarySource::Managed, None);
+    if let Some(outcome) = already_current(release.version(), from.as_deref()) {
+        add_app_log(outcome.message.clone());
+        return Ok(outcome);
+    }
+    let asset = release
+        .platform_asset()
+        .ok_or_else(|| Error::NoReleaseAsset {
+            version: release.version().to_string(),
+            target: format!("{}-{}", std::env::consts::OS, std::env::consts::ARCH),
+        })?;
+
+    // Only instances on the managed binary move to the new version.
+    let affected: Vec<InstanceInfo> = state::list_instances()
+        .into_iter()
+        .filter(|instance| {
+            instance.running && instance.config.binary_source == BinarySource::Managed
+        })
+        .collect();
+    let previous_active = versions::list()?.active;
+
+    let installed = download::install_from_url(&asset.browser_download_url, &asset.name)?;
+    versions::set_active(&installed.version)?;
+    add_app_log(format!(
+        "Upgrading RustFS from {} to {}",
+        from.as_deref().unwrap_or("unknown"),
+        installed.version
+    ));
+
+    let error = match restart(&affected) {
+        Ok(restarted) => {
+            let message = if restarted.is_empty() {
+                format!("RustFS {} installed", installed.version)
+            } else {
+                format!(
+                    "Upgraded to RustFS {} and restarted {}",
+                    installed.version,
+                    restarted.join(", ")
+                )
+            };
+            add_app_log(message.clone());
+            return Ok(UpgradeOutcome {
+                from,
+                to: installed.version,
+                restarted,
+                rolled_back: false,
+                message,
+            });
+        }
+        Err(e) => e,
+    };
+
+    add_app_log(format!(
+        "[ERROR] RustFS {} failed to start, rolling back: {}",
+        installed.version, error
+    ));
+    match &previous_active {
+        Some(version) => versions::set_active(version)?,
+        None => versions::clear_active()?,
+    };
+    let restarted = match restart(&affected) {
+        Ok(restarted) => restarted,
+        Err(e) => {
+            add_app_log(format!("[ERROR] Restarting after rollback failed: {}", e));
+            Vec::new()
+        }
+    };
+    // The full error, stderr included, is in the log above.
+    let reason = match &error {
+        Error::FailedToStart { reason, .. } => reason.clone(),
+        other => other.to_string(),
+    };
+    let message = format!(
+        "RustFS {} failed to start ({}); rolled back to {}",
+        installed.version,
+        reason,
+        from.as_deref().unwrap_or("the previous binary")
+    );
+    add_app_log(message.clone());
+    Ok(UpgradeOutcome {
+        from,
+        to: installed.version,
+        restarted,
+        rolled_back: true,
+        message,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn upgrade_stops_unless_the_release_is_newer() {
+        for current in ["1.2.0", "v1.2.0", "1.3.0-beta"] {
+            let outcome = already_current("1.2.0", Some(current)).expect(current);
+            assert_eq!(outcome.from.as_deref(), Some(current));
+            assert_eq!(outcome.to, current);
+            assert!(outcome.restarted.is_empty());
+            assert!(!outcome.rolled_back);
+        }
+        assert!(already_current("1.2.0", Some("1.1.9")).is_none());
+        assert!(already_current("1.2.0", Some("1.2.0-rc.1")).is_none());
+        assert!(already_current("1.2.0", None).is_none());
+    }
+
+    #[test]
+    fn orders_versions_like_semver() {
+        let ordered = [
+            "0.9.9",
+            "1.0.0-alpha",
+            "1.0.0-alpha.2",
+            "1.0.0-alpha.10",
+            "1.0.0-alpha.beta",
+            "1.0.0-beta",
+            "1.0.0-rc.1",
+            "1.0.0",
+            "1.0.1",
+            "1.10.0",
+        ];
+        for pair in ordered.windows(2) {
+            assert_eq!(
+                compare_versions(pair[0], pair[1]),
+                Ordering::Less,
+                "{pair:?}"
+            );
+            assert_eq!(
+                compare_versions(pair[1], pair[0]),
+                Ordering::Greater,
+                "{pair:?}"
+            );
+        }
+        assert_eq!(compare_versions("v1.2", "1.2.0+build.7"), Ordering::Equal);
+    }
+
+    #[test]
+    fn picks_newest_published_release() {
+        let feed = r#"[
+            {"tag_name": "1.0.0-alpha.9", "html_url": "https://example.com/9", "assets": []},
+            {"tag_name": "1.0.0-alpha.12", "draft": true, "assets": []},
+            {"tag_name": "v1.0.0-alpha.10", "html_url": "https://example.com/10"}
+        ]"#;
+        let release = latest_release(feed).unwrap();
+        assert_eq!(release.version(), "1.0.0-alpha.10");
+        assert_eq!(release.html_url.as_deref(), Some("https://example.com/10"));
+
+        let single = r#"{"tag_name": "v2.0.0", "assets": []}"#;
+        assert_eq!(latest_release(single).unwrap().version(), "2.0.0");
+
+        assert!(matches!(latest_release("[]"), Err(Error::InvalidFeed(_))));
+        assert!(matches!(
+            latest_release("<html>"),
+            Err(Error::InvalidFeed(_))
+        ));
+    }
+
+    #[test]
+    fn finds_platform_asset() {
+        use std::env::consts::{ARCH, OS};
+        let feed = format!(
+            r#"{{"tag_name": "1.0.0", "assets": [
+                {{"name": "rustfs-{os}-{arch}-v1.0.0.zip.sha256", "browser_download_url": "https://example.com/a.sha256"}},
+                {{"name": "rustfs-plan9-mips-v1.0.0.zip", "browser_download_url": "https://example.com/b"}},
+                {{"name": "rustfs-{os}-{arch}-v1.0.0.zip", "browser_download_url": "https://example.com/a"}}
+            ]}}"#,
+            os = OS,
+            arch = ARCH
+        );
+        let release = latest_release(&feed).unwrap();
+        let asset = release.platform_asset().unwrap();
+        assert_eq!(asset.browser_download_url, "https://example.com/a");
+    }
+}
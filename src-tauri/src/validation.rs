@@ -0,0 +1,411 @@
+use crate::config::{BinarySource, RustFsConfig};
+use crate::config_io;
+use crate::process;
+use rustfs_launcher_shared::validation::{
+    ConfigField, ValidationReport, MIN_ACCESS_KEY_LEN, MIN_SECRET_KEY_LEN,
+};
+use std::net::{IpAddr, ToSocketAddrs};
+use std::path::Path;
+
+/// Below this much free space RustFS cannot do anything useful.
+const MIN_FREE_BYTES: u64 = 256 * 1024 * 1024;
+/// Below this much free space RustFS starts but will fill the disk soon.
+const LOW_FREE_BYTES: u64 = 1024 * 1024 * 1024;
+/// Created and removed again to prove the data directory is writable.
+const WRITE_PROBE_FILE: &str = ".rustfs-launcher-write-probe";
+
+/// Checks everything about `config` that can be known before launching,
+/// collecting every problem rather than stopping at the first.
+pub fn validate(config: &RustFsConfig) -> ValidationReport {
+    let mut report = validate_portable(config);
+    check_data_path(&config.data_path, &mut report);
+    check_host_resolves(config.host.as_deref(), &mut report);
+    check_binary(
+        config.binary_source,
+        config.binary_path.as_deref(),
+        &mut report,
+    );
+    report
+}
+
+/// The rules that hold wherever `config` ends up, leaving out the data path,
+/// host lookup and binary, which only mean something on the machine that
+/// launches it. Imported configs must pass these before they are saved.
+pub fn validate_portable(config: &RustFsConfig) -> ValidationReport {
+    let mut report = ValidationReport::default();
+    check_host(config.host.as_deref(), &mut report);
+    check_port(config.port, config.allow_privileged_port, &mut report);
+    check_credentials(config, &mut report);
+
+    if config.startup_timeout_secs == 0 {
+        report.error(
+            ConfigField::StartupTimeoutSecs,
+            "Startup timeout must be at least 1 second",
+        );
+    }
+    if !config.health_check_path.starts_with('/') {
+        report.error(
+            ConfigField::HealthCheckPath,
+            "Health check path must start with '/'",
+        );
+    }
+    if config.supervisor.max_restarts == 0 {
+        report.warning(
+            ConfigField::Supervisor,
+            "Max restarts is 0, so RustFS will never be restarted",
+        );
+    }
+    report
+}
+
+fn check_data_path(data_path: &str, report: &mut ValidationReport) {
+    let field = ConfigField::DataPath;
+    if data_path.trim().is_empty() {
+        report.error(field, "Data path is required");
+        return;
+    }
+    let path = Path::new(data_path);
+    let metadata = match std::fs::metadata(path) {
+        Ok(metadata) => metadata,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+            report.error(field, "Data path does not exist");
+            return;
+        }
+        Err(e) => {
+            report.error(field, format!("Cannot read data path: {}", e));
+            return;
+        }
+    };
+    if !metadata.is_dir() {
+        report.error(field, "Data path is a file, not a directory");
+        return;
+    }
+
+    let probe = path.join(WRITE_PROBE_FILE);
+    match std::fs::write(&probe, b"") {
+        Ok(()) => {
+            let _ = std::fs::remove_file(&probe);
+        }
+        Err(e) => {
+            report.error(field, format!("Data path is not writable: {}", e));
+            return;
+        }
+    }
+
+    match free_space(path) {
+        Ok(free) if free < MIN_FREE_BYTES => report.error(
+            field,
+            format!("Only {} free on this disk", format_bytes(free)),
+        ),
+        Ok(free) if free < LOW_FREE_BYTES => report.warning(
+            field,
+            format!("Only {} free on this disk", format_bytes(free)),
+        ),
+        Ok(_) => {}
+        Err(e) => report.warning(field, format!("Could not check free space: {}", e)),
+    }
+}
+
+fn check_host(host: Option<&str>, report: &mut ValidationReport) {
+    if host.is_some_and(|host| host.trim().is_empty()) {
+        report.error(ConfigField::Host, "Host must not be empty");
+    }
+}
+
+fn check_host_resolves(host: Option<&str>, report: &mut ValidationReport) {
+    let Some(host) = host.map(str::trim).filter(|host| !host.is_empty()) else {
+        return;
+    };
+    if host.parse::<IpAddr>().is_err() && (host, 0).to_socket_addrs().is_err() {
+        report.error(
+            ConfigField::Host,
+            format!("'{}' is not an IP address or a resolvable name", host),
+        );
+    }
+}
+
+fn check_port(port: Option<u16>, allow_privileged: bool, report: &mut ValidationReport) {
+    match port {
+        Some(0) => report.error(ConfigField::Port, "Port must be between 1 and 65535"),
+        Some(port) if port < 1024 && cfg!(unix) => {
+            if allow_privileged {
+                report.warning(
+                    ConfigField::Port,
+                    "Ports below 1024 usually need root privileges",
+                );
+            } else {
+                report.error(
+                    ConfigField::Port,
+                    "Ports below 1024 are privileged; pick a higher port or allow privileged ports",
+                );
+            }
+        }
+        _ => {}
+    }
+}
+
+fn check_credentials(config: &RustFsConfig, report: &mut ValidationReport) {
+    let checks = [
+        (
+            ConfigField::AccessKey,
+            &config.access_key,
+            MIN_ACCESS_KEY_LEN,
+            "Access key",
+        ),
+        (
+            ConfigField::SecretKey,
+            &config.secret_key,
+            MIN_SECRET_KEY_LEN,
+            "Secret key",
+        ),
+    ];
+    for (field, value, min_len, label) in checks {
+        let Some(value) = value.as_deref() else {
+            continue;
+        };
+        // `${VAR}` placeholders are only known at launch time.
+        if value.contains("${") {
+            if !config_io::is_placeholder_well_formed(value) {
+                report.error(
+                    field,
+                    format!("{} has a malformed ${{VAR}} placeholder", label),
+                );
+            }
+            continue;
+        }
+        if value.chars().count() < min_len {
+            report.error(
+                field,
+                format!("{} must be at least {} characters", label, min_len),
+            );
+        } else if value.chars().any(char::is_whitespace) {
+            report.error(field, format!("{} must not contain whitespace", label));
+        }
+    }
+
+    let default = RustFsConfig::default();
+    if config.access_key.is_some()
+        && config.access_key == default.access_key
+        && config.secret_key == default.secret_key
+    {
+        report.warning(
+            ConfigField::SecretKey,
+            "Default credentials are in use; anyone who can reach RustFS can log in",
+        );
+    }
+}
+
+fn check_binary(source: BinarySource, binary_path: Option<&str>, report: &mut ValidationReport) {
+    let field = ConfigField::BinaryPath;
+    let path = match process::find_binary_path(source, binary_path) {
+        Ok(path) => path,
+        Err(e) => {
+            report.error(field, e.to_string());
+            return;
+        }
+    };
+    let metadata = match std::fs::metadata(&path) {
+        Ok(metadata) => metadata,
+        Err(_) => {
+            report.error(
+                field,
+                format!("RustFS binary not found at {}", path.display()),
+            );
+            return;
+        }
+    };
+    if !metadata.is_file() {
+        report.error(field, format!("{} is not a file", path.display()));
+        return;
+    }
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        if metadata.permissions().mode() & 0o111 == 0 {
+            report.error(field, format!("{} is not executable", path.display()));
+        }
+    }
+    #[cfg(windows)]
+    {
+        let is_exe = path
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("exe"));
+        if !is_exe {
+            report.warning(field, format!("{} is not an .exe file", path.display()));
+        }
+    }
+}
+
+fn format_bytes(bytes: u64) -> String {
+    const MIB: u64 = 1024 * 1024;
+    if bytes >= 1024 * MIB {
+        format!("{:.1} GiB", bytes as f64 / (1024 * MIB) as f64)
+    } else {
+        format!("{} MiB", bytes / MIB)
+    }
+}
+
+#[cfg(unix)]
+#[allow(clippy::unnecessary_cast)] // the statvfs field types differ between platforms
+fn free_space(path: &Path) -> std::io::Result<u64> {
+    use std::ffi::CString;
+    use std::os::unix::ffi::OsStrExt;
+
+    let c_path = CString::new(path.as_os_str().as_bytes())
+        .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidInput, e))?;
+    // SAFETY: `c_path` is a valid NUL-terminated string and `stat` is a
+    // properly sized out-parameter that `statvfs` fully initialises on success.
+    let mut stat: libc::statvfs = unsafe { std::mem::zeroed() };
+    if unsafe { libc::statvfs(c_path.as_ptr(), &mut stat) } != 0 {
+        return Err(std::io::Error::last_os_error());
+    }
+    Ok(stat.f_bavail as u64 * stat.f_frsize as u64)
+}
+
+#[cfg(windows)]
+fn free_space(path: &Path) -> std::io::Result<u64> {
+    use std::os::windows::ffi::OsStrExt;
+    use windows_sys::Win32::Storage::FileSystem::GetDiskFreeSpaceExW;
+
+    let wide: Vec<u16> = path
+        .as_os_str()
+        .encode_wide()
+        .chain(std::iter::once(0))
+        .collect();
+    let mut available = 0u64;
+    // SAFETY: `wide` is NUL-terminated and the out-parameters are either valid
+    // pointers or null, which the API allows.
+    let ok = unsafe {
+        GetDiskFreeSpaceExW(
+            wide.as_ptr(),
+            &mut available,
+            std::ptr::null_mut(),
+            std::ptr::null_mut(),
+        )
+    };
+    if ok == 0 {
+        return Err(std::io::Error::last_os_error());
+    }
+    Ok(available)
+}
+
+#[cfg(not(any(unix, windows)))]
+fn free_space(_path: &Path) -> std::io::Result<u64> {
+    Err(std::io::Error::new(
+        std::io::ErrorKind::Unsupported,
+        "Free space checks are not supported on this platform",
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rustfs_launcher_shared::validation::Severity;
+
+    fn severities(report: &ValidationReport, field: ConfigField) -> Vec<Severity> {
+        report.for_field(field).map(|i| i.severity).collect()
+    }
+
+    fn port_report(port: Option<u16>, allow_privileged: bool) -> ValidationReport {
+        let mut report = ValidationReport::default();
+        check_port(port, allow_privileged, &mut report);
+        report
+    }
+
+    #[test]
+    fn port_rules() {
+        assert_eq!(
+            severities(&port_report(Some(0), true), ConfigField::Port),
+            [Severity::Error]
+        );
+        assert!(port_report(Some(9000), false).issues.is_empty());
+        assert!(port_report(None, false).issues.is_empty());
+
+        let privileged = port_report(Some(443), false);
+        let allowed = port_report(Some(443), true);
+        if cfg!(unix) {
+            assert_eq!(
+                severities(&privileged, ConfigField::Port),
+                [Severity::Error]
+            );
+            assert_eq!(severities(&allowed, ConfigField::Port), [Severity::Warning]);
+        } else {
+            assert!(privileged.issues.is_empty());
+            assert!(allowed.issues.is_empty());
+        }
+    }
+
+    #[test]
+    fn host_rules() {
+        let check = |host: Option<&str>| {
+            let mut report = ValidationReport::default();
+            check_host(host, &mut report);
+            check_host_resolves(host, &mut report);
+            severities(&report, ConfigField::Host)
+        };
+        assert!(check(None).is_empty());
+        assert!(check(Some("127.0.0.1")).is_empty());
+        assert!(check(Some("::1")).is_empty());
+        assert!(check(Some(" 0.0.0.0 ")).is_empty());
+        assert_eq!(check(Some("  ")), [Severity::Error]);
+        assert_eq!(check(Some("not a host.invalid")), [Severity::Error]);
+    }
+
+    #[test]
+    fn credential_rules() {
+        let check = |access_key: &str, secret_key: &str| {
+            let config = RustFsConfig {
+                access_key: Some(access_key.to_string()),
+                secret_key: Some(secret_key.to_string()),
+                ..RustFsConfig::default()
+            };
+            let mut report = ValidationReport::default();
+            check_credentials(&config, &mut report);
+            (
+                severities(&report, ConfigField::AccessKey),
+                severities(&report, ConfigField::SecretKey),
+            )
+        };
+        let access = "a".repeat(MIN_ACCESS_KEY_LEN);
+        let secret = "s".repeat(MIN_SECRET_KEY_LEN);
+
+        assert_eq!(check(&access, &secret), (vec![], vec![]));
+        assert_eq!(
+            check(&access[1..], &secret[1..]),
+            (vec![Severity::Error], vec![Severity::Error])
+        );
+        let spaced = format!("{} x", secret);
+        assert_eq!(check(&access, &spaced), (vec![], vec![Severity::Error]));
+        // Placeholders are checked once they are resolved at launch.
+        assert_eq!(check("${KEY}", "${SECRET}"), (vec![], vec![]));
+        assert_eq!(
+            check("${KEY", "prefix-${1SECRET}"),
+            (vec![Severity::Error], vec![Severity::Error])
+        );
+
+        let default = RustFsConfig::default();
+        assert_eq!(
+            check(
+                default.access_key.as_deref().unwrap(),
+                default.secret_key.as_deref().unwrap()
+            )
+            .1,
+            [Severity::Warning]
+        );
+    }
+
+    #[test]
+    fn health_check_path_must_be_absolute() {
+        let check = |path: &str| {
+            let config = RustFsConfig {
+                health_check_path: path.to_string(),
+                ..RustFsConfig::default()
+            };
+            severities(&validate(&config), ConfigField::HealthCheckPath)
+        };
+        assert!(check("/health").is_empty());
+        assert_eq!(check("health"), [Severity::Error]);
+        assert_eq!(check(""), [Severity::Error]);
+    }
+}
@@ -0,0 +1,485 @@
+use crate::binary;
+use crate::config::BinarySource;
+use crate::error::{Error, Result};
+use crate::process;
+use crate::state::{add_app_log, APP_HANDLE};
+use lazy_static::lazy_static;
+use rustfs_launcher_shared::{InstalledVersion, VersionList};
+use std::fs::File;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use tauri::Manager;
+
+const BINARIES_DIR: &str = "binaries";
+const MANIFEST_FILE: &str = "versions.json";
+/// Prefix of the per-install directories archives are unpacked into.
+const STAGING_PREFIX: &str = ".staging-";
+const DOWNLOAD_DIR: &str = ".downloads";
+const MANAGED_BINARY_NAME: &str = if cfg!(windows) {
+    "rustfs.exe"
+} else {
+    "rustfs"
+};
+
+lazy_static! {
+    /// Serialises manifest read-modify-write cycles.
+    static ref MANIFEST_LOCK: Mutex<()> = Mutex::new(());
+}
+
+pub(crate) fn binaries_dir() -> Result<PathBuf> {
+    let handle = APP_HANDLE
+        .lock()
+        .unwrap()
+        .clone()
+        .ok_or_else(|| Error::Io(std::io::Error::other("Application is not initialised")))?;
+    let dir = handle
+        .path()
+        .app_data_dir()
+        .map_err(|e| Error::Io(std::io::Error::other(e.to_string())))?;
+    Ok(dir.join(BINARIES_DIR))
+}
+
+/// Where release archives are kept while they download and install.
+pub(crate) fn download_dir(binaries: &Path) -> PathBuf {
+    binaries.join(DOWNLOAD_DIR)
+}
+
+fn read_manifest(dir: &Path) -> VersionList {
+    std::fs::read_to_string(dir.join(MANIFEST_FILE))
+        .ok()
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default()
+}
+
+/// Writes the manifest next to its final name and renames it into place.
+fn write_manifest(dir: &Path, list: &VersionList) -> Result<()> {
+    let path = dir.join(MANIFEST_FILE);
+    let tmp = path.with_extension("tmp");
+    let json =
+        serde_json::to_string_pretty(list).map_err(|e| std::io::Error::other(e.to_string()))?;
+    std::fs::write(&tmp, json)?;
+    std::fs::rename(&tmp, &path)?;
+    Ok(())
+}
+
+/// The managed binaries, newest install first.
+pub fn list() -> Result<VersionList> {
+    Ok(list_in(&binaries_dir()?))
+}
+
+pub(crate) fn list_in(dir: &Path) -> VersionList {
+    let _guard = MANIFEST_LOCK.lock().unwrap();
+    read_manifest(dir)
+}
+
+/// Path of the active managed binary, if one is set and still on disk.
+pub fn active_binary() -> Option<PathBuf> {
+    let list = list().ok()?;
+    let active = list.active?;
+    list.versions
+        .into_iter()
+        .find(|v| v.version == active)
+        .map(|v| PathBuf::from(v.path))
+        .filter(|path| path.is_file())
+}
+
+fn is_rustfs_binary(name: &str) -> bool {
+    name == "rustfs" || name == "rustfs.exe" || name == process::inferred_binary_name()
+}
+
+fn extract_zip(archive: &Path, dest: &Path) -> Result<()> {
+    let invalid = |e: zip::result::ZipError| Error::InvalidArchive(e.to_string());
+    let mut zip = zip::ZipArchive::new(File::open(archive)?).map_err(invalid)?;
+    for i in 0..zip.len() {
+        let mut entry = zip.by_index(i).map_err(invalid)?;
+        // `enclosed_name` rejects entries that would escape `dest`.
+        let Some(relative) = entry.enclosed_name() else {
+            continue;
+        };
+        let target = dest.join(relative);
+        if entry.is_dir() {
+            std::fs::create_dir_all(&target)?;
+            continue;
+        }
+        if let Some(parent) = target.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::io::copy(&mut entry, &mut File::create(&target)?)?;
+    }
+    Ok(())
+}
+
+fn extract_tar_gz(archive: &Path, dest: &Path) -> Result<()> {
+    let decoder = flate2::read::GzDecoder::new(File::open(archive)?);
+    // `unpack` skips entries that would escape `dest`.
+    tar::Archive::new(decoder)
+        .unpack(dest)
+        .map_err(|e| Error::InvalidArchive(e.to_string()))
+}
+
+fn find_binary(dir: &Path) -> Option<PathBuf> {
+    let mut pending = vec![dir.to_path_buf()];
+    while let Some(dir) = pending.pop() {
+        // A directory that cannot be read is skipped, not fatal.
+        let Ok(entries) = std::fs::read_dir(&dir) else {
+            continue;
+        };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.is_dir() {
+                pending.push(path);
+            } else if path
+                .file_name()
+                .and_then(|n| n.to_str())
+                .is_some_and(is_rustfs_binary)
+            {
+                return Some(path);
+            }
+        }
+    }
+    None
+}
+
+/// Unpacks the RustFS binary from a `.zip` or `.tar.gz` archive into the
+/// managed directory, named after the version it reports. The first version
+/// installed becomes the active one; reinstalling a version replaces it.
+pub fn install(archive: &Path) -> Result<InstalledVersion> {
+    install_in(&binaries_dir()?, archive)
+}
+
+pub(crate) fn install_in(dir: &Path, archive: &Path) -> Result<InstalledVersion> {
+    let name = archive
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let lower = name.to_ascii_lowercase();
+    std::fs::create_dir_all(dir)?;
+    // Each install unpacks on its own, so concurrent installs, downloads and
+    // upgrades cannot wipe each other's files. Removed when dropped.
+    let staging = tempfile::Builder::new()
+        .prefix(STAGING_PREFIX)
+        .tempdir_in(dir)?;
+    let staging = staging.path();
+
+    if lower.ends_with(".zip") {
+        extract_zip(archive, staging)?;
+    } else if lower.ends_with(".tar.gz") || lower.ends_with(".tgz") {
+        extract_tar_gz(archive, staging)?;
+    } else {
+        return Err(Error::InvalidArchive(format!(
+            "{} is not a .zip or .tar.gz file",
+            name
+        )));
+    }
+    let binary = find_binary(staging)
+        .ok_or_else(|| Error::InvalidArchive(format!("no RustFS binary found in {}", name)))?;
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        std::fs::set_permissions(&binary, std::fs::Permissions::from_mode(0o755))?;
+    }
+
+    let info = binary::inspect(BinarySource::Custom, Some(&binary.to_string_lossy()))?;
+    let version = info.version.ok_or_else(|| {
+        Error::InvalidArchive(format!(
+            "cannot tell which version {} contains{}",
+            name,
+            info.warning.map(|w| format!(": {}", w)).unwrap_or_default()
+        ))
+    })?;
+
+    let _guard = MANIFEST_LOCK.lock().unwrap();
+    let version_dir = dir.join(&version);
+    let _ = std::fs::remove_dir_all(&version_dir);
+    std::fs::create_dir_all(&version_dir)?;
+    let path = version_dir.join(MANAGED_BINARY_NAME);
+    std::fs::rename(&binary, &path)?;
+    let installed = InstalledVersion {
+        version,
+        path: path.to_string_lossy().to_string(),
+        source: name,
+        installed_at: chrono::Local::now().to_rfc3339(),
+        os: info.os,
+        arch: info.arch,
+    };
+
+    let mut list = read_manifest(dir);
+    list.versions.retain(|v| v.version != installed.version);
+    list.versions.insert(0, installed.clone());
+    if list.active.is_none() {
+        list.active = Some(installed.version.clone());
+    }
+    write_manifest(dir, &list)?;
+    add_app_log(format!(
+        "Installed RustFS {} from {}",
+        installed.version, installed.source
+    ));
+    Ok(installed)
+}
+
+/// Makes `version` the binary that configs using the managed binary launch.
+pub fn set_active(version: &str) -> Result<VersionList> {
+    set_active_in(&binaries_dir()?, version)
+}
+
+fn set_active_in(dir: &Path, version: &str) -> Result<VersionList> {
+    let _guard = MANIFEST_LOCK.lock().unwrap();
+    let mut list = read_manifest(dir);
+    if !list.versions.iter().any(|v| v.version == version) {
+        return Err(Error::VersionNotInstalled(version.to_string()));
+    }
+    if list.active.as_deref() != Some(version) {
+        list.previous = list.active.replace(version.to_string());
+        write_manifest(dir, &list)?;
+        add_app_log(format!("Active RustFS version is now {}", version));
+    }
+    Ok(list)
+}
+
+/// Sends configs using the managed binary back to the bundled one, keeping
+/// the active version as the rollback target.
+pub fn clear_active() -> Result<VersionList> {
+    clear_active_in(&binaries_dir()?)
+}
+
+fn clear_active_in(dir: &Path) -> Result<VersionList> {
+    let _guard = MANIFEST_LOCK.lock().unwrap();
+    let mut list = read_manifest(dir);
+    if let Some(active) = list.active.take() {
+        list.previous = Some(active);
+        write_manifest(dir, &list)?;
+        add_app_log("Using the bundled RustFS binary again".to_string());
+    }
+    Ok(list)
+}
+
+/// Switches back to the version that was active before the last switch.
+pub fn rollback() -> Result<VersionList> {
+    rollback_in(&binaries_dir()?)
+}
+
+fn rollback_in(dir: &Path) -> Result<VersionList> {
+    let _guard = MANIFEST_LOCK.lock().unwrap();
+    let mut list = read_manifest(dir);
+    let previous = list
+        .previous
+        .clone()
+        .filter(|p| list.versions.iter().any(|v| &v.version == p))
+        .ok_or(Error::NoPreviousVersion)?;
+    list.previous = list.active.replace(previous.clone());
+    write_manifest(dir, &list)?;
+    add_app_log(format!("Rolled back to RustFS {}", previous));
+    Ok(list)
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use std::io::Write;
+
+    /// A stand-in RustFS that only answers `--version`.
+    pub(crate) fn fake_rustfs(version: &str) -> Vec<u8> {
+        format!("#!/bin/sh\necho \"rustfs {}\"\n", version).into_bytes()
+    }
+
+    pub(crate) fn zip_archive(entries: &[(&str, &[u8])]) -> Vec<u8> {
+        let mut zip = zip::ZipWriter::new(std::io::Cursor::new(Vec::new()));
+        let options = zip::write::SimpleFileOptions::default()
+            .compression_method(zip::CompressionMethod::Stored);
+        for (name, contents) in entries {
+            zip.start_file(*name, options).unwrap();
+            zip.write_all(contents).unwrap();
+        }
+        zip.finish().unwrap().into_inner()
+    }
+
+    fn tar_gz_archive(entries: &[(&str, &[u8])]) -> Vec<u8> {
+        let encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+        let mut tar = tar::Builder::new(encoder);
+        for (name, contents) in entries {
+            let mut header = tar::Header::new_gnu();
+            header.set_size(contents.len() as u64);
+            header.set_mode(0o644);
+            header.set_cksum();
+            tar.append_data(&mut header, name, *contents).unwrap();
+        }
+        tar.into_inner().unwrap().finish().unwrap()
+    }
+
+    fn write_archive(dir: &Path, name: &str, contents: Vec<u8>) -> PathBuf {
+        let path = dir.join(name);
+        std::fs::write(&path, contents).unwrap();
+        path
+    }
+
+    fn staging_dirs(dir: &Path) -> usize {
+        std::fs::read_dir(dir)
+            .unwrap()
+            .flatten()
+            .filter(|e| e.file_name().to_string_lossy().starts_with(STAGING_PREFIX))
+            .count()
+    }
+
+    fn installed_version(version: &str) -> InstalledVersion {
+        InstalledVersion {
+            version: version.to_string(),
+            path: format!("/managed/{}/rustfs", version),
+            source: format!("rustfs-{}.zip", version),
+            installed_at: "2025-01-01T00:00:00+00:00".to_string(),
+            os: None,
+            arch: None,
+        }
+    }
+
+    #[test]
+    fn find_binary_searches_subdirectories() {
+        let dir = tempfile::tempdir().unwrap();
+        let nested = dir.path().join("rustfs-linux-x86_64").join("bin");
+        std::fs::create_dir_all(&nested).unwrap();
+        std::fs::write(nested.join("rustfs"), b"").unwrap();
+        std::fs::write(dir.path().join("README.md"), b"").unwrap();
+        // A subdirectory that cannot be read must not end the search.
+        let locked = dir.path().join("locked");
+        std::fs::create_dir(&locked).unwrap();
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            std::fs::set_permissions(&locked, std::fs::Permissions::from_mode(0o000)).unwrap();
+        }
+
+        assert_eq!(find_binary(dir.path()), Some(nested.join("rustfs")));
+        assert_eq!(find_binary(&locked.join("missing")), None);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn installs_zip_and_tar_gz_archives() {
+        let archives = tempfile::tempdir().unwrap();
+        let dir = tempfile::tempdir().unwrap();
+        let zip = write_archive(
+            archives.path(),
+            "rustfs-1.0.0.zip",
+            zip_archive(&[
+                ("rustfs-linux-x86_64/README.md", b"readme"),
+                ("rustfs-linux-x86_64/rustfs", &fake_rustfs("1.0.0")),
+            ]),
+        );
+        let tar_gz = write_archive(
+            archives.path(),
+            "rustfs-1.1.0.tar.gz",
+            tar_gz_archive(&[("bin/rustfs", &fake_rustfs("1.1.0"))]),
+        );
+
+        let first = install_in(dir.path(), &zip).unwrap();
+        assert_eq!(first.version, "1.0.0");
+        assert_eq!(first.source, "rustfs-1.0.0.zip");
+        assert!(dir.path().join("1.0.0").join(MANAGED_BINARY_NAME).is_file());
+        let second = install_in(dir.path(), &tar_gz).unwrap();
+        assert_eq!(second.version, "1.1.0");
+
+        let list = read_manifest(dir.path());
+        let versions: Vec<_> = list.versions.iter().map(|v| v.version.as_str()).collect();
+        assert_eq!(versions, ["1.1.0", "1.0.0"]);
+        // Only the first install becomes active on its own.
+        assert_eq!(list.active.as_deref(), Some("1.0.0"));
+        assert_eq!(staging_dirs(dir.path()), 0);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn concurrent_installs_do_not_disturb_each_other() {
+        let archives = tempfile::tempdir().unwrap();
+        let dir = tempfile::tempdir().unwrap();
+        let paths: Vec<PathBuf> = ["2.0.0", "2.1.0", "2.2.0"]
+            .iter()
+            .map(|version| {
+                write_archive(
+                    archives.path(),
+                    &format!("rustfs-{}.zip", version),
+                    zip_archive(&[("rustfs", &fake_rustfs(version))]),
+                )
+            })
+            .collect();
+
+        std::thread::scope(|scope| {
+            let installs: Vec<_> = paths
+                .iter()
+                .map(|path| scope.spawn(|| install_in(dir.path(), path)))
+                .collect();
+            for install in installs {
+                install.join().unwrap().unwrap();
+            }
+        });
+
+        assert_eq!(read_manifest(dir.path()).versions.len(), 3);
+        assert_eq!(staging_dirs(dir.path()), 0);
+    }
+
+    #[test]
+    fn rejects_archives_without_a_binary() {
+        let archives = tempfile::tempdir().unwrap();
+        let dir = tempfile::tempdir().unwrap();
+        let zip = write_archive(
+            archives.path(),
+            "docs.zip",
+            zip_archive(&[("README.md", b"readme")]),
+        );
+        let unknown = write_archive(archives.path(), "rustfs.rar", Vec::new());
+
+        assert!(matches!(
+            install_in(dir.path(), &zip),
+            Err(Error::InvalidArchive(_))
+        ));
+        assert!(matches!(
+            install_in(dir.path(), &unknown),
+            Err(Error::InvalidArchive(_))
+        ));
+        assert_eq!(read_manifest(dir.path()), VersionList::default());
+        assert_eq!(staging_dirs(dir.path()), 0);
+    }
+
+    #[test]
+    fn switches_rolls_back_and_clears_the_active_version() {
+        let dir = tempfile::tempdir().unwrap();
+        write_manifest(
+            dir.path(),
+            &VersionList {
+                versions: vec![installed_version("1.1.0"), installed_version("1.0.0")],
+                active: Some("1.0.0".to_string()),
+                previous: None,
+            },
+        )
+        .unwrap();
+
+        let list = set_active_in(dir.path(), "1.1.0").unwrap();
+        assert_eq!(list.active.as_deref(), Some("1.1.0"));
+        assert_eq!(list.previous.as_deref(), Some("1.0.0"));
+        assert!(matches!(
+            set_active_in(dir.path(), "9.9.9"),
+            Err(Error::VersionNotInstalled(_))
+        ));
+
+        let list = rollback_in(dir.path()).unwrap();
+        assert_eq!(list.active.as_deref(), Some("1.0.0"));
+        assert_eq!(list.previous.as_deref(), Some("1.1.0"));
+
+        let list = clear_active_in(dir.path()).unwrap();
+        assert_eq!(list.active, None);
+        assert_eq!(list.previous.as_deref(), Some("1.0.0"));
+        assert_eq!(read_manifest(dir.path()), list);
+
+        // Rolling back needs a previous version that is still installed.
+        write_manifest(
+            dir.path(),
+            &VersionList {
+                previous: Some("0.9.0".to_string()),
+                ..list
+            },
+        )
+        .unwrap();
+        assert!(matches!(
+            rollback_in(dir.path()),
+            Err(Error::NoPreviousVersion)
+        ));
+    }
+}
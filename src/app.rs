@@ -1,10 +1,28 @@
 use crate::components::config_form::ConfigForm;
+use crate::components::config_transfer::ConfigTransfer;
+use crate::components::credential_vault::CredentialVault;
+use crate::components::instance_list::InstanceList;
+use crate::components::log_history_settings::LogHistorySettings;
 use crate::components::log_viewer::LogViewer;
+use crate::components::orphan_banner::OrphanBanner;
+use crate::components::profile_picker::ProfilePicker;
+use crate::components::redaction_settings::RedactionSettings;
 use crate::components::toast::{Toast, ToastMessage, ToastType};
-use crate::types::{CommandResponse, LogType, RustFsConfig};
+use crate::components::update_banner::UpdateBanner;
+use crate::components::version_manager::VersionManager;
+use crate::types::{
+    BinaryInfo, BinarySource, CommandError, CommandResponse, CredentialStatus, Credentials,
+    DownloadEvent, ImportSummary, InstalledVersion, InstanceExitEvent, InstanceInfo,
+    InstanceStateEvent, LifecycleEvent, LifecyclePhase, LogCapacity, LogFileInfo, LogLevel,
+    LogRecord, LogStream, LogType, OrphanRecord, PortCheck, ProcessState, ProfileList,
+    RustFsConfig, SecretMode, Severity, SupervisorEvent, SupervisorState, UpdateInfo,
+    UpgradeOutcome, ValidationReport, VersionList,
+};
 use leptos::ev::SubmitEvent;
 use leptos::prelude::*;
-use std::collections::VecDeque;
+use rustfs_launcher_shared::events;
+use std::collections::{HashMap, VecDeque};
+use std::sync::atomic::{AtomicU64, Ordering};
 use wasm_bindgen::closure::Closure;
 use wasm_bindgen::prelude::*;
 use wasm_bindgen_futures::spawn_local;
@@ -16,6 +34,21 @@ const LOGS_CSS: &str = include_str!("logs.css");
 extern "C" {
     #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "core"], js_name = invoke)]
     async fn tauri_invoke(cmd: &str, args: JsValue) -> JsValue;
+
+    // Same as `tauri_invoke`, but surfaces a rejected command as `Err` carrying
+    // the backend's `CommandError` instead of throwing.
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "core"], js_name = invoke, catch)]
+    async fn tauri_try_invoke(cmd: &str, args: JsValue) -> Result<JsValue, JsValue>;
+}
+
+/// The message of a rejected command; older or foreign rejections may be
+/// plain strings, and anything else gets `fallback`.
+fn error_message(err: JsValue, fallback: impl FnOnce() -> String) -> String {
+    serde_wasm_bindgen::from_value::<CommandError>(err.clone())
+        .map(|e| e.message)
+        .ok()
+        .or_else(|| err.as_string())
+        .unwrap_or_else(fallback)
 }
 
 // Helper function to check if we're in Tauri environment
@@ -26,55 +59,458 @@ fn is_tauri() -> bool {
         .unwrap_or(false)
 }
 
-fn load_config() -> RustFsConfig {
+/// Config saved in localStorage by launchers that predate the backend store.
+fn load_legacy_config() -> Option<RustFsConfig> {
+    web_sys::window()
+        .and_then(|w| w.local_storage().ok().flatten())
+        .and_then(|storage| storage.get_item("rustfs_config").ok().flatten())
+        .and_then(|json| serde_json::from_str(&json).ok())
+}
+
+fn remove_legacy_config() {
     if let Some(window) = web_sys::window() {
         if let Ok(Some(storage)) = window.local_storage() {
-            if let Ok(Some(json)) = storage.get_item("rustfs_config") {
-                if let Ok(config) = serde_json::from_str(&json) {
-                    return config;
-                }
-            }
+            let _ = storage.remove_item("rustfs_config");
         }
     }
-    RustFsConfig::default()
 }
 
-fn save_config(config: &RustFsConfig) {
+async fn fetch_config(instance: &str) -> Option<RustFsConfig> {
+    let value = tauri_try_invoke("get_config", instance_args(instance).into())
+        .await
+        .ok()?;
+    serde_wasm_bindgen::from_value::<Option<RustFsConfig>>(value)
+        .ok()
+        .flatten()
+}
+
+async fn save_config(instance: &str, config: &RustFsConfig) -> Result<(), String> {
+    let args = instance_args(instance);
+    let config_js = serde_wasm_bindgen::to_value(config).unwrap();
+    js_sys::Reflect::set(&args, &"config".into(), &config_js).unwrap();
+    tauri_try_invoke("save_config", args.into())
+        .await
+        .map(|_| ())
+        .map_err(|err| error_message(err, || "Failed to save config".to_string()))
+}
+
+async fn fetch_redaction_patterns() -> Vec<String> {
+    let value = tauri_invoke("get_redaction_patterns", js_sys::Object::new().into()).await;
+    serde_wasm_bindgen::from_value(value).unwrap_or_default()
+}
+
+async fn fetch_log_capacity() -> LogCapacity {
+    let value = tauri_invoke("get_log_capacity", js_sys::Object::new().into()).await;
+    serde_wasm_bindgen::from_value(value).unwrap_or_default()
+}
+
+/// The release mirror the user picked; empty means the official site.
+async fn fetch_release_url() -> String {
+    let value = tauri_invoke("get_release_url", js_sys::Object::new().into()).await;
+    serde_wasm_bindgen::from_value(value).unwrap_or_default()
+}
+
+async fn save_release_url(url: String) -> Result<(), String> {
+    let args = js_sys::Object::new();
+    js_sys::Reflect::set(&args, &"url".into(), &url.into()).unwrap();
+    tauri_try_invoke("set_release_url", args.into())
+        .await
+        .map(|_| ())
+        .map_err(|err| error_message(err, || "Failed to save the release mirror".to_string()))
+}
+
+/// The releases feed the user picked; empty means the official one.
+async fn fetch_feed_url() -> String {
+    let value = tauri_invoke("get_releases_feed", js_sys::Object::new().into()).await;
+    serde_wasm_bindgen::from_value(value).unwrap_or_default()
+}
+
+async fn save_feed_url(url: String) -> Result<(), String> {
+    let args = js_sys::Object::new();
+    js_sys::Reflect::set(&args, &"url".into(), &url.into()).unwrap();
+    tauri_try_invoke("set_releases_feed", args.into())
+        .await
+        .map(|_| ())
+        .map_err(|err| error_message(err, || "Failed to save the releases feed".to_string()))
+}
+
+/// Hands the user's redaction patterns to the backend, which applies and
+/// saves them, returning its error message if one of them does not compile.
+async fn push_redaction_patterns(patterns: &[String]) -> Result<(), String> {
+    let args = js_sys::Object::new();
+    let patterns_js = serde_wasm_bindgen::to_value(patterns).unwrap();
+    js_sys::Reflect::set(&args, &"patterns".into(), &patterns_js).unwrap();
+    tauri_try_invoke("set_redaction_patterns", args.into())
+        .await
+        .map(|_| ())
+        .map_err(|err| error_message(err, || "Invalid redaction pattern".to_string()))
+}
+
+/// Tells the backend how much log history to keep, returning the limits it
+/// applied and saved.
+async fn push_log_capacity(capacity: LogCapacity) -> Result<LogCapacity, String> {
+    let args = js_sys::Object::new();
+    let capacity_js = serde_wasm_bindgen::to_value(&capacity).unwrap();
+    js_sys::Reflect::set(&args, &"capacity".into(), &capacity_js).unwrap();
+    let value = tauri_try_invoke("set_log_capacity", args.into())
+        .await
+        .map_err(|err| error_message(err, || "Failed to set the log history".to_string()))?;
+    serde_wasm_bindgen::from_value(value).map_err(|err| err.to_string())
+}
+
+async fn fetch_credential_status() -> Option<CredentialStatus> {
+    let value = tauri_invoke("get_credential_status", js_sys::Object::new().into()).await;
+    serde_wasm_bindgen::from_value(value).ok()
+}
+
+/// Runs a credential store command that answers with the new status, turning
+/// a rejection into its error message.
+async fn invoke_credential_command(
+    cmd: &str,
+    passphrase: Option<String>,
+) -> Result<CredentialStatus, String> {
+    let args = js_sys::Object::new();
+    if let Some(passphrase) = passphrase {
+        js_sys::Reflect::set(&args, &"passphrase".into(), &passphrase.into()).unwrap();
+    }
+    let value = tauri_try_invoke(cmd, args.into())
+        .await
+        .map_err(|err| error_message(err, || "Credential store error".to_string()))?;
+    serde_wasm_bindgen::from_value(value).map_err(|e| e.to_string())
+}
+
+/// Invokes a command answering with a `CommandResponse`, turning a rejection
+/// into its error message.
+async fn invoke_command(cmd: &str, args: js_sys::Object) -> Result<CommandResponse, String> {
+    let value = tauri_try_invoke(cmd, args.into())
+        .await
+        .map_err(|err| error_message(err, || format!("{} failed", cmd)))?;
+    serde_wasm_bindgen::from_value(value).map_err(|e| e.to_string())
+}
+
+/// `binarySource`/`binaryPath` arguments for the commands that look at the
+/// configured binary.
+fn binary_args(source: BinarySource, binary_path: Option<String>) -> js_sys::Object {
+    let args = js_sys::Object::new();
+    let source_js = serde_wasm_bindgen::to_value(&source).unwrap();
+    js_sys::Reflect::set(&args, &"binarySource".into(), &source_js).unwrap();
+    if let Some(path) = binary_path {
+        js_sys::Reflect::set(&args, &"binaryPath".into(), &path.into()).unwrap();
+    }
+    args
+}
+
+async fn inspect_binary(
+    source: BinarySource,
+    binary_path: Option<String>,
+) -> Result<BinaryInfo, String> {
+    let args = binary_args(source, binary_path);
+    let value = tauri_try_invoke("inspect_binary", args.into())
+        .await
+        .map_err(|err| error_message(err, || "Failed to inspect the RustFS binary".to_string()))?;
+    serde_wasm_bindgen::from_value(value).map_err(|e| e.to_string())
+}
+
+async fn fetch_versions() -> VersionList {
+    let value = tauri_invoke("list_versions", js_sys::Object::new().into()).await;
+    serde_wasm_bindgen::from_value(value).unwrap_or_default()
+}
+
+/// Invokes a version manager command answering with the updated `VersionList`.
+async fn invoke_version_command(cmd: &str, args: js_sys::Object) -> Result<VersionList, String> {
+    let value = tauri_try_invoke(cmd, args.into())
+        .await
+        .map_err(|err| error_message(err, || format!("{} failed", cmd)))?;
+    serde_wasm_bindgen::from_value(value).map_err(|e| e.to_string())
+}
+
+async fn check_for_updates(
+    source: BinarySource,
+    binary_path: Option<String>,
+) -> Result<UpdateInfo, String> {
+    let args = binary_args(source, binary_path);
+    let value = tauri_try_invoke("check_for_updates", args.into())
+        .await
+        .map_err(|err| error_message(err, || "Failed to check for updates".to_string()))?;
+    serde_wasm_bindgen::from_value(value).map_err(|e| e.to_string())
+}
+
+async fn fetch_profiles() -> ProfileList {
+    let value = tauri_invoke("list_profiles", js_sys::Object::new().into()).await;
+    serde_wasm_bindgen::from_value(value).unwrap_or_default()
+}
+
+async fn fetch_profile(name: &str) -> Result<RustFsConfig, String> {
+    let args = js_sys::Object::new();
+    js_sys::Reflect::set(&args, &"name".into(), &name.into()).unwrap();
+    let value = tauri_try_invoke("load_profile", args.into())
+        .await
+        .map_err(|err| error_message(err, || format!("Failed to load profile '{}'", name)))?;
+    serde_wasm_bindgen::from_value(value).map_err(|e| e.to_string())
+}
+
+const DEFAULT_INSTANCE: &str = "default";
+
+fn load_instance_name() -> String {
+    web_sys::window()
+        .and_then(|w| w.local_storage().ok().flatten())
+        .and_then(|storage| storage.get_item("rustfs_instance").ok().flatten())
+        .filter(|name| !name.is_empty())
+        .unwrap_or_else(|| DEFAULT_INSTANCE.to_string())
+}
+
+fn save_instance_name(name: &str) {
     if let Some(window) = web_sys::window() {
         if let Ok(Some(storage)) = window.local_storage() {
-            if let Ok(json) = serde_json::to_string(config) {
-                let _ = storage.set_item("rustfs_config", &json);
-            }
+            let _ = storage.set_item("rustfs_instance", name);
         }
     }
 }
 
-const APP_LOG_CAPACITY: usize = 100;
-const RUSTFS_LOG_CAPACITY: usize = 1000;
+fn instance_args(instance: &str) -> js_sys::Object {
+    let args = js_sys::Object::new();
+    js_sys::Reflect::set(&args, &"instance".into(), &instance.into()).unwrap();
+    args
+}
+
+async fn fetch_instances() -> Vec<InstanceInfo> {
+    let value = tauri_invoke("list_instances", js_sys::Object::new().into()).await;
+    serde_wasm_bindgen::from_value(value).unwrap_or_default()
+}
+
+async fn fetch_rustfs_logs(instance: &str) -> Vec<LogRecord> {
+    let value = tauri_invoke("get_rustfs_logs", instance_args(instance).into()).await;
+    serde_wasm_bindgen::from_value(value).unwrap_or_default()
+}
 
-fn push_log(writer: WriteSignal<VecDeque<String>>, msg: String, capacity: usize) {
+/// Numbers records made without a backend, when running in a plain browser.
+static LOCAL_LOG_SEQ: AtomicU64 = AtomicU64::new(1);
+
+/// Inserts `record` in sequence order, skipping it if already there (an event
+/// can race the initial fetch), and drops the oldest beyond `capacity`.
+fn insert_record(logs: &mut VecDeque<LogRecord>, record: LogRecord, capacity: usize) {
+    if let Err(index) = logs.binary_search_by_key(&record.seq, |r| r.seq) {
+        logs.insert(index, record);
+    }
+    trim_records(logs, capacity);
+}
+
+fn trim_records(logs: &mut VecDeque<LogRecord>, capacity: usize) {
+    if logs.len() > capacity {
+        logs.drain(..logs.len() - capacity);
+    }
+}
+
+/// Adds a UI message to the launcher log; a leading `[LEVEL]` tag sets its
+/// level. Under Tauri it goes through the backend, which numbers and persists
+/// it and sends it back as an `app-log` event.
+fn push_log(writer: WriteSignal<VecDeque<LogRecord>>, msg: String) {
+    if is_tauri() {
+        spawn_local(async move {
+            let args = js_sys::Object::new();
+            js_sys::Reflect::set(&args, &"message".into(), &msg.into()).unwrap();
+            let _ = tauri_try_invoke("log_message", args.into()).await;
+        });
+        return;
+    }
+    let (level, message) = LogLevel::split_tag(&msg);
+    let record = LogRecord {
+        seq: LOCAL_LOG_SEQ.fetch_add(1, Ordering::Relaxed),
+        timestamp: js_sys::Date::new_0().to_iso_string().into(),
+        stream: LogStream::Launcher,
+        level: level.unwrap_or(LogLevel::Info),
+        instance: None,
+        message: message.to_string(),
+        target: None,
+        span: None,
+        fields: Vec::new(),
+    };
+    writer.update(|logs| insert_record(logs, record, LogCapacity::default().app));
+}
+
+fn push_instance_log(
+    writer: WriteSignal<HashMap<String, VecDeque<LogRecord>>>,
+    records: Vec<LogRecord>,
+    capacity: usize,
+) {
     writer.update(|logs| {
-        logs.push_back(msg);
-        if logs.len() > capacity {
-            logs.pop_front();
+        for record in records {
+            let instance = record.instance.clone().unwrap_or_default();
+            insert_record(logs.entry(instance).or_default(), record, capacity);
         }
     });
 }
 
 #[component]
 pub fn App() -> impl IntoView {
-    let (config, set_config) = signal(load_config());
+    let (config, set_config) = signal(RustFsConfig::default());
+    // Nothing is saved until the stored config has been loaded, so the
+    // defaults above never overwrite it.
+    let (config_loaded, set_config_loaded) = signal(false);
+
+    let (toasts, set_toasts) = signal(Vec::<ToastMessage>::new());
+    let (app_logs, set_app_logs) = signal(VecDeque::<LogRecord>::new());
+    let (log_capacity, set_log_capacity) = signal(LogCapacity::default());
+    let (rustfs_logs, set_rustfs_logs) = signal(HashMap::<String, VecDeque<LogRecord>>::new());
+    let (instance_name, set_instance_name) = signal(load_instance_name());
+    let (instances, set_instances) = signal(Vec::<InstanceInfo>::new());
+    let (orphans, set_orphans) = signal(Vec::<OrphanRecord>::new());
+    let (port_conflict, set_port_conflict) = signal(Option::<PortCheck>::None);
+    let (validation, set_validation) = signal(ValidationReport::default());
+    let (binary_info, set_binary_info) = signal(Option::<BinaryInfo>::None);
+    let (versions, set_versions) = signal(VersionList::default());
+    let (release_url, set_release_url) = signal(String::new());
+    let (download, set_download) = signal(Option::<DownloadEvent>::None);
+    let (feed_url, set_feed_url) = signal(String::new());
+    let (update, set_update) = signal(Option::<UpdateInfo>::None);
+    let (upgrading, set_upgrading) = signal(false);
+    let (current_log_type, set_current_log_type) = signal(LogType::App);
+    let (log_files, set_log_files) = signal(Vec::<LogFileInfo>::new());
+    let (selected_file, set_selected_file) = signal(Option::<String>::None);
+    let (file_logs, set_file_logs) = signal(Vec::<String>::new());
+    let (process_state, set_process_state) = signal(ProcessState::Stopped);
+    let (supervisor_state, set_supervisor_state) = signal(SupervisorState::Idle);
+    let (redaction_patterns, set_redaction_patterns) = signal(Vec::<String>::new());
+    let (redaction_error, set_redaction_error) = signal(Option::<String>::None);
+    let (credential_status, set_credential_status) = signal(Option::<CredentialStatus>::None);
+    let (profiles, set_profiles) = signal(ProfileList::default());
+    let (active_profile, set_active_profile) = signal(Option::<String>::None);
 
     Effect::new(move |_| {
-        save_config(&config.get());
+        save_instance_name(&instance_name.get());
     });
 
-    let (toasts, set_toasts) = signal(Vec::<ToastMessage>::new());
-    let (is_running, set_is_running) = signal(false);
-    let (app_logs, set_app_logs) = signal(VecDeque::<String>::new());
-    let (rustfs_logs, set_rustfs_logs) = signal(VecDeque::<String>::new());
-    let (current_log_type, set_current_log_type) = signal(LogType::App);
-    let (service_status, set_service_status) = signal(false);
+    Effect::new(move |_| {
+        let url = release_url.get();
+        // Only changes made after the saved mirror was loaded
+        if !config_loaded.get_untracked() || !is_tauri() {
+            return;
+        }
+        spawn_local(async move {
+            if let Err(message) = save_release_url(url).await {
+                leptos::logging::warn!("{}", message);
+            }
+        });
+    });
+
+    Effect::new(move |_| {
+        let url = feed_url.get();
+        // Only changes made after the saved feed was loaded
+        if !config_loaded.get_untracked() || !is_tauri() {
+            return;
+        }
+        spawn_local(async move {
+            if let Err(message) = save_feed_url(url).await {
+                leptos::logging::warn!("{}", message);
+            }
+        });
+    });
+
+    Effect::new(move |_| {
+        let current = config.get();
+        if !config_loaded.get() || !is_tauri() {
+            return;
+        }
+        let name = instance_name.get_untracked();
+        spawn_local(async move {
+            if let Err(message) = save_config(&name, &current).await {
+                leptos::logging::warn!("{}", message);
+            }
+        });
+    });
+
+    // Re-inspect the binary whenever a different one is configured, whether
+    // picked in the form, loaded from a profile or imported, and whenever the
+    // managed versions change, since the active one is what a config on the
+    // managed binary launches.
+    let binary = Memo::new(move |_| {
+        let config = config.get();
+        (config.binary_source, config.binary_path)
+    });
+    Effect::new(move |_| {
+        let selected = binary.get();
+        versions.track();
+        if !is_tauri() {
+            return;
+        }
+        spawn_local(async move {
+            let (source, path) = selected.clone();
+            let info = inspect_binary(source, path).await;
+            // Drop the answer if another binary was picked in the meantime.
+            if binary.get_untracked() != selected {
+                return;
+            }
+            match info {
+                Ok(info) => set_binary_info.set(Some(info)),
+                Err(message) => {
+                    set_binary_info.set(None);
+                    push_log(set_app_logs, format!("[ERROR] {}", message));
+                }
+            }
+        });
+    });
+
+    let refresh_instances = move || {
+        spawn_local(async move {
+            if is_tauri() {
+                set_instances.set(fetch_instances().await);
+            }
+        });
+    };
+
+    let is_running = Signal::derive(move || process_state.get().is_active());
+
+    let refresh_status = move || {
+        spawn_local(async move {
+            if !is_tauri() {
+                return;
+            }
+            let args = instance_args(&instance_name.get_untracked());
+            let value = tauri_invoke("get_status", args.into()).await;
+            if let Ok(state) = serde_wasm_bindgen::from_value::<ProcessState>(value) {
+                set_process_state.set(state);
+            }
+        });
+    };
+
+    // Fills the form from the credential store, or seeds the store with the
+    // credentials still held in memory (e.g. from an older saved config).
+    let sync_credentials = move || {
+        spawn_local(async move {
+            if !credential_status
+                .get_untracked()
+                .is_some_and(|status| status.unlocked)
+            {
+                return;
+            }
+            let args = instance_args(&instance_name.get_untracked());
+            let stored = tauri_try_invoke("get_credentials", args.into())
+                .await
+                .ok()
+                .and_then(|value| serde_wasm_bindgen::from_value::<Option<Credentials>>(value).ok())
+                .flatten();
+            match stored {
+                Some(credentials) => set_config.update(|c| {
+                    c.access_key = credentials.access_key;
+                    c.secret_key = credentials.secret_key;
+                }),
+                None => {
+                    let current = config.get_untracked();
+                    if current.access_key.is_none() && current.secret_key.is_none() {
+                        return;
+                    }
+                    let args = instance_args(&instance_name.get_untracked());
+                    let credentials = Credentials {
+                        access_key: current.access_key,
+                        secret_key: current.secret_key,
+                    };
+                    let credentials_js = serde_wasm_bindgen::to_value(&credentials).unwrap();
+                    js_sys::Reflect::set(&args, &"credentials".into(), &credentials_js).unwrap();
+                    let _ = tauri_try_invoke("set_credentials", args.into()).await;
+                }
+            }
+        });
+    };
 
     let remove_toast = Callback::new(move |id: u64| {
         set_toasts.update(|current| {
@@ -104,44 +540,36 @@ pub fn App() -> impl IntoView {
             );
     };
 
-    // Health Check Polling
-    Effect::new(move |_| {
+    // Compares the binary the selected instance launches with the releases
+    // feed. `announce` also reports an up-to-date binary and failures as toasts.
+    let check_updates = move |announce: bool| {
         spawn_local(async move {
             if !is_tauri() {
                 return;
             }
-
-            let closure = Closure::wrap(Box::new(move || {
-                spawn_local(async move {
-                    let current = config.get_untracked();
-                    if let Some(port) = current.port {
-                        let host = current.host.unwrap_or_else(|| "127.0.0.1".to_string());
-
-                        let args = js_sys::Object::new();
-                        js_sys::Reflect::set(&args, &"host".into(), &host.into()).unwrap();
-                        js_sys::Reflect::set(&args, &"port".into(), &port.into()).unwrap();
-
-                        match tauri_invoke("check_tcp_connection", args.into())
-                            .await
-                            .as_bool()
-                        {
-                            Some(is_active) => set_service_status.set(is_active),
-                            None => set_service_status.set(false),
-                        }
+            let current = config.get_untracked();
+            match check_for_updates(current.binary_source, current.binary_path).await {
+                Ok(info) => {
+                    if announce && !info.available {
+                        show_toast(
+                            format!(
+                                "RustFS {} is the latest release",
+                                info.current.as_deref().unwrap_or(&info.latest)
+                            ),
+                            ToastType::Info,
+                        );
                     }
-                });
-            }) as Box<dyn FnMut()>);
-
-            let _ = web_sys::window()
-                .unwrap()
-                .set_interval_with_callback_and_timeout_and_arguments_0(
-                    closure.as_ref().unchecked_ref(),
-                    3000, // 3 seconds
-                );
-
-            closure.forget(); // Leak the closure to keep it alive
+                    set_update.set(Some(info));
+                }
+                Err(message) => {
+                    push_log(set_app_logs, format!("[ERROR] {}", message));
+                    if announce {
+                        show_toast(message, ToastType::Error);
+                    }
+                }
+            }
         });
-    });
+    };
 
     let app_log_writer = set_app_logs;
     let rustfs_log_writer = set_rustfs_logs;
@@ -151,7 +579,6 @@ pub fn App() -> impl IntoView {
             push_log(
                 app_log_writer,
                 "[WARN] Not running in Tauri environment - logs disabled".to_string(),
-                APP_LOG_CAPACITY,
             );
             return;
         }
@@ -159,41 +586,65 @@ pub fn App() -> impl IntoView {
         push_log(
             app_log_writer,
             "[DEBUG] Setting up real-time log listeners...".to_string(),
-            APP_LOG_CAPACITY,
         );
 
-        const APP_LOG_EVENT: &str = "app-log";
-        const RUSTFS_LOG_EVENT: &str = "rustfs-log";
-        const RUSTFS_EXIT_EVENT: &str = "rustfs-exit";
-
         fn create_log_listener(
-            logs_signal: WriteSignal<VecDeque<String>>,
-            max_logs: usize,
+            logs_signal: WriteSignal<VecDeque<LogRecord>>,
+            capacity: ReadSignal<LogCapacity>,
         ) -> Closure<dyn FnMut(JsValue)> {
             Closure::wrap(Box::new(move |event: JsValue| {
                 if let Ok(payload) = js_sys::Reflect::get(&event, &"payload".into()) {
-                    if let Some(log) = payload.as_string() {
-                        push_log(logs_signal, log, max_logs);
+                    if let Ok(record) = serde_wasm_bindgen::from_value::<LogRecord>(payload) {
+                        let max_logs = capacity.get_untracked().app;
+                        logs_signal.update(|logs| insert_record(logs, record, max_logs));
                     }
                 }
             }) as Box<dyn FnMut(JsValue)>)
         }
 
-        let exit_listener = Closure::wrap(Box::new(move |event: JsValue| {
+        let rustfs_listener = Closure::wrap(Box::new(move |event: JsValue| {
             if let Ok(payload) = js_sys::Reflect::get(&event, &"payload".into()) {
-                if let Some(exit_code) = payload.as_string() {
-                    set_is_running.set(false);
-                    set_service_status.set(false);
-                    show_toast(
-                        format!("RustFS exited with code: {}", exit_code),
-                        ToastType::Error,
+                if let Ok(record) = serde_wasm_bindgen::from_value::<LogRecord>(payload) {
+                    push_instance_log(
+                        rustfs_log_writer,
+                        vec![record],
+                        log_capacity.get_untracked().rustfs,
                     );
+                }
+            }
+        }) as Box<dyn FnMut(JsValue)>);
+
+        let exit_listener = Closure::wrap(Box::new(move |event: JsValue| {
+            if let Ok(payload) = js_sys::Reflect::get(&event, &"payload".into()) {
+                if let Ok(exit) = serde_wasm_bindgen::from_value::<InstanceExitEvent>(payload) {
+                    refresh_instances();
+
+                    if !exit.unexpected {
+                        push_log(
+                            app_log_writer,
+                            format!(
+                                "[INFO] RustFS instance '{}' exited: {}",
+                                exit.instance, exit.status
+                            ),
+                        );
+                        return;
+                    }
 
-                    // Log it
                     push_log(
                         app_log_writer,
-                        format!("[ERROR] RustFS process exited unexpectedly: {}", exit_code),
-                        APP_LOG_CAPACITY,
+                        format!(
+                            "[ERROR] RustFS instance '{}' exited unexpectedly: {}",
+                            exit.instance, exit.status
+                        ),
+                    );
+
+                    if exit.instance != instance_name.get_untracked() {
+                        return;
+                    }
+
+                    show_toast(
+                        format!("RustFS exited with code: {}", exit.status),
+                        ToastType::Error,
                     );
 
                     // Switch to RustFS logs so user sees why
@@ -202,9 +653,69 @@ pub fn App() -> impl IntoView {
             }
         }) as Box<dyn FnMut(JsValue)>);
 
+        let supervisor_listener = Closure::wrap(Box::new(move |event: JsValue| {
+            if let Ok(payload) = js_sys::Reflect::get(&event, &"payload".into()) {
+                if let Ok(update) = serde_wasm_bindgen::from_value::<SupervisorEvent>(payload) {
+                    push_log(app_log_writer, format!("[SUPERVISOR] {}", update.message));
+                    if update.instance != instance_name.get_untracked() {
+                        return;
+                    }
+
+                    set_supervisor_state.set(update.state);
+                    match update.state {
+                        SupervisorState::Restarting => {
+                            show_toast(update.message.clone(), ToastType::Info);
+                        }
+                        SupervisorState::CrashLoop => {
+                            show_toast(update.message.clone(), ToastType::Error);
+                        }
+                        SupervisorState::Idle => {}
+                    }
+                }
+            }
+        }) as Box<dyn FnMut(JsValue)>);
+
+        let lifecycle_listener = Closure::wrap(Box::new(move |event: JsValue| {
+            if let Ok(payload) = js_sys::Reflect::get(&event, &"payload".into()) {
+                if let Ok(update) = serde_wasm_bindgen::from_value::<LifecycleEvent>(payload) {
+                    push_log(app_log_writer, format!("[LIFECYCLE] {}", update.message));
+                    if update.instance != instance_name.get_untracked() {
+                        return;
+                    }
+                    if update.phase == LifecyclePhase::FailedToStart {
+                        set_current_log_type.set(LogType::RustFS);
+                    }
+                }
+            }
+        }) as Box<dyn FnMut(JsValue)>);
+
+        let download_listener = Closure::wrap(Box::new(move |event: JsValue| {
+            if let Ok(payload) = js_sys::Reflect::get(&event, &"payload".into()) {
+                if let Ok(update) = serde_wasm_bindgen::from_value::<DownloadEvent>(payload) {
+                    if !update.message.is_empty() {
+                        push_log(
+                            app_log_writer,
+                            format!("[DOWNLOAD] {}: {}", update.asset, update.message),
+                        );
+                    }
+                    set_download.set(Some(update));
+                }
+            }
+        }) as Box<dyn FnMut(JsValue)>);
+
+        let state_listener = Closure::wrap(Box::new(move |event: JsValue| {
+            if let Ok(payload) = js_sys::Reflect::get(&event, &"payload".into()) {
+                if let Ok(update) = serde_wasm_bindgen::from_value::<InstanceStateEvent>(payload) {
+                    refresh_instances();
+                    if update.instance == instance_name.get_untracked() {
+                        set_process_state.set(update.state);
+                    }
+                }
+            }
+        }) as Box<dyn FnMut(JsValue)>);
+
         if let Some(window) = web_sys::window() {
-            let app_listener = create_log_listener(app_log_writer, APP_LOG_CAPACITY);
-            let rustfs_listener = create_log_listener(rustfs_log_writer, RUSTFS_LOG_CAPACITY);
+            let app_listener = create_log_listener(app_log_writer, log_capacity);
 
             if let Ok(tauri) = js_sys::Reflect::get(&window, &"__TAURI__".into()) {
                 if let Ok(event) = js_sys::Reflect::get(&tauri, &"event".into()) {
@@ -213,19 +724,39 @@ pub fn App() -> impl IntoView {
 
                         let _ = listen_fn.call2(
                             &event,
-                            &APP_LOG_EVENT.into(),
+                            &events::APP_LOG.into(),
                             app_listener.as_ref().unchecked_ref(),
                         );
                         let _ = listen_fn.call2(
                             &event,
-                            &RUSTFS_LOG_EVENT.into(),
+                            &events::RUSTFS_LOG.into(),
                             rustfs_listener.as_ref().unchecked_ref(),
                         );
                         let _ = listen_fn.call2(
                             &event,
-                            &RUSTFS_EXIT_EVENT.into(),
+                            &events::RUSTFS_EXIT.into(),
                             exit_listener.as_ref().unchecked_ref(),
                         );
+                        let _ = listen_fn.call2(
+                            &event,
+                            &events::RUSTFS_SUPERVISOR.into(),
+                            supervisor_listener.as_ref().unchecked_ref(),
+                        );
+                        let _ = listen_fn.call2(
+                            &event,
+                            &events::RUSTFS_LIFECYCLE.into(),
+                            lifecycle_listener.as_ref().unchecked_ref(),
+                        );
+                        let _ = listen_fn.call2(
+                            &event,
+                            &events::RUSTFS_DOWNLOAD.into(),
+                            download_listener.as_ref().unchecked_ref(),
+                        );
+                        let _ = listen_fn.call2(
+                            &event,
+                            &events::RUSTFS_STATE.into(),
+                            state_listener.as_ref().unchecked_ref(),
+                        );
                     }
                 }
             }
@@ -233,35 +764,559 @@ pub fn App() -> impl IntoView {
             app_listener.forget();
             rustfs_listener.forget();
             exit_listener.forget();
+            supervisor_listener.forget();
+            lifecycle_listener.forget();
+            download_listener.forget();
+            state_listener.forget();
         }
 
-        // Fetch initial logs
+        set_redaction_patterns.set(fetch_redaction_patterns().await);
+        set_log_capacity.set(fetch_log_capacity().await);
+
+        set_credential_status.set(fetch_credential_status().await);
+        set_versions.set(fetch_versions().await);
+        set_release_url.set(fetch_release_url().await);
+        set_feed_url.set(fetch_feed_url().await);
+        let profile_list = fetch_profiles().await;
+        let default_profile = profile_list.default_profile.clone();
+        set_profiles.set(profile_list);
+
+        let name = instance_name.get_untracked();
+        match fetch_config(&name).await {
+            Some(stored) => set_config.set(stored),
+            None if load_legacy_config().is_none() => {
+                // A fresh instance starts from the default profile, if any
+                if let Some(profile) = default_profile {
+                    if let Ok(profile_config) = fetch_profile(&profile).await {
+                        set_config.set(profile_config);
+                        set_active_profile.set(Some(profile));
+                    }
+                }
+            }
+            None => {
+                // One-time import of the config the frontend used to keep itself
+                if let Some(legacy) = load_legacy_config() {
+                    match save_config(&name, &legacy).await {
+                        Ok(()) => {
+                            remove_legacy_config();
+                            push_log(
+                                app_log_writer,
+                                "Imported saved config from an older launcher".to_string(),
+                            );
+                        }
+                        Err(message) => leptos::logging::warn!("{}", message),
+                    }
+                    set_config.set(legacy);
+                }
+            }
+        }
+        set_config_loaded.set(true);
+        sync_credentials();
+        check_updates(false);
+
+        // Fetch initial logs, merged with any that arrived as events meanwhile
         let app_logs_value = tauri_invoke("get_app_logs", js_sys::Object::new().into()).await;
-        if let Ok(logs_vec) = serde_wasm_bindgen::from_value::<Vec<String>>(app_logs_value) {
-            app_log_writer.set(logs_vec.into_iter().collect());
+        if let Ok(records) = serde_wasm_bindgen::from_value::<Vec<LogRecord>>(app_logs_value) {
+            app_log_writer.update(|logs| {
+                for record in records {
+                    insert_record(logs, record, log_capacity.get_untracked().app);
+                }
+            });
+        }
+
+        let instance_list = fetch_instances().await;
+        for instance in &instance_list {
+            let records = fetch_rustfs_logs(&instance.name).await;
+            push_instance_log(
+                rustfs_log_writer,
+                records,
+                log_capacity.get_untracked().rustfs,
+            );
+        }
+        set_instances.set(instance_list);
+        refresh_status();
+
+        let orphans_value = tauri_invoke("list_orphans", js_sys::Object::new().into()).await;
+        if let Ok(found) = serde_wasm_bindgen::from_value::<Vec<OrphanRecord>>(orphans_value) {
+            set_orphans.set(found);
+        }
+    });
+
+    let data_path_args = |data_path: &str| {
+        let args = js_sys::Object::new();
+        js_sys::Reflect::set(&args, &"dataPath".into(), &data_path.into()).unwrap();
+        args
+    };
+
+    let adopt_orphan = Callback::new(move |data_path: String| {
+        spawn_local(async move {
+            let result_value =
+                tauri_invoke("adopt_orphan", data_path_args(&data_path).into()).await;
+            match serde_wasm_bindgen::from_value::<CommandResponse>(result_value) {
+                Ok(res) => show_toast(res.message, ToastType::Success),
+                Err(_) => show_toast("Failed to reattach to RustFS".to_string(), ToastType::Error),
+            }
+            set_orphans.update(|list| list.retain(|o| o.config.data_path != data_path));
+            refresh_instances();
+        });
+    });
+
+    let terminate_orphan = Callback::new(move |data_path: String| {
+        spawn_local(async move {
+            let result_value =
+                tauri_invoke("terminate_orphan", data_path_args(&data_path).into()).await;
+            match serde_wasm_bindgen::from_value::<CommandResponse>(result_value) {
+                Ok(res) => show_toast(res.message, ToastType::Success),
+                Err(_) => show_toast("Failed to terminate RustFS".to_string(), ToastType::Error),
+            }
+            set_orphans.update(|list| list.retain(|o| o.config.data_path != data_path));
+        });
+    });
+
+    let select_instance = Callback::new(move |name: String| {
+        set_supervisor_state.set(SupervisorState::Idle);
+        set_instance_name.set(name.clone());
+        refresh_status();
+
+        spawn_local(async move {
+            if !is_tauri() {
+                return;
+            }
+            let running_config =
+                instances.with_untracked(|list| list.iter().find(|i| i.name == name).cloned());
+            set_active_profile.set(running_config.as_ref().and_then(|i| i.profile.clone()));
+            match fetch_config(&name).await {
+                Some(stored) => set_config.set(stored),
+                None => {
+                    if let Some(info) = running_config {
+                        set_config.set(info.config);
+                    }
+                }
+            }
+            sync_credentials();
+
+            let records = fetch_rustfs_logs(&name).await;
+            push_instance_log(
+                set_rustfs_logs,
+                records,
+                log_capacity.get_untracked().rustfs,
+            );
+        });
+    });
+
+    let remove_instance = Callback::new(move |name: String| {
+        spawn_local(async move {
+            let args = instance_args(&name);
+            let result_value = tauri_invoke("remove_instance", args.into()).await;
+            if let Ok(res) = serde_wasm_bindgen::from_value::<CommandResponse>(result_value) {
+                show_toast(res.message, ToastType::Info);
+            }
+            set_rustfs_logs.update(|all| {
+                all.remove(&name);
+            });
+            refresh_instances();
+        });
+    });
+
+    let apply_log_capacity = Callback::new(move |capacity: LogCapacity| {
+        spawn_local(async move {
+            let applied = if is_tauri() {
+                match push_log_capacity(capacity).await {
+                    Ok(applied) => applied,
+                    Err(message) => {
+                        show_toast(message, ToastType::Error);
+                        return;
+                    }
+                }
+            } else {
+                capacity.clamped()
+            };
+            set_log_capacity.set(applied);
+            set_app_logs.update(|logs| trim_records(logs, applied.app));
+            set_rustfs_logs.update(|all| {
+                for logs in all.values_mut() {
+                    trim_records(logs, applied.rustfs);
+                }
+            });
+            show_toast(
+                format!(
+                    "Keeping {} launcher and {} RustFS log records",
+                    applied.app, applied.rustfs
+                ),
+                ToastType::Success,
+            );
+        });
+    });
+
+    let apply_redaction = Callback::new(move |patterns: Vec<String>| {
+        spawn_local(async move {
+            match push_redaction_patterns(&patterns).await {
+                Ok(()) => {
+                    set_redaction_patterns.set(patterns);
+                    set_redaction_error.set(None);
+                    show_toast("Redaction patterns applied".to_string(), ToastType::Success);
+                }
+                Err(message) => set_redaction_error.set(Some(message)),
+            }
+        });
+    });
+
+    let run_credential_command = move |cmd: &'static str, passphrase: Option<String>| {
+        spawn_local(async move {
+            match invoke_credential_command(cmd, passphrase).await {
+                Ok(status) => {
+                    set_credential_status.set(Some(status));
+                    sync_credentials();
+                }
+                Err(message) => show_toast(message, ToastType::Error),
+            }
+        });
+    };
+
+    let unlock_credentials = Callback::new(move |passphrase: Option<String>| {
+        run_credential_command("unlock_credentials", passphrase);
+    });
+
+    let lock_credentials = Callback::new(move |_: ()| {
+        run_credential_command("lock_credentials", None);
+    });
+
+    let rotate_credentials_key = Callback::new(move |passphrase: Option<String>| {
+        run_credential_command("rotate_credentials_key", passphrase);
+    });
+
+    let wipe_credentials = Callback::new(move |_: ()| {
+        let confirmed = web_sys::window()
+            .and_then(|w| {
+                w.confirm_with_message("Delete all stored credentials? This cannot be undone.")
+                    .ok()
+            })
+            .unwrap_or(false);
+        if !confirmed {
+            return;
+        }
+        spawn_local(async move {
+            match tauri_try_invoke("wipe_credentials", js_sys::Object::new().into()).await {
+                Ok(_) => show_toast("Stored credentials wiped".to_string(), ToastType::Info),
+                Err(err) => show_toast(
+                    error_message(err, || "Failed to wipe credentials".to_string()),
+                    ToastType::Error,
+                ),
+            }
+            set_credential_status.set(fetch_credential_status().await);
+        });
+    });
+
+    let refresh_profiles = move || {
+        spawn_local(async move {
+            set_profiles.set(fetch_profiles().await);
+        });
+    };
+
+    // Runs a profile command, reports the result and reloads the list. On
+    // success the active profile becomes `activate`, if given.
+    let run_profile_command =
+        move |cmd: &'static str, args: js_sys::Object, activate: Option<Option<String>>| {
+            spawn_local(async move {
+                match invoke_command(cmd, args).await {
+                    Ok(res) => {
+                        show_toast(res.message, ToastType::Success);
+                        if let Some(profile) = activate {
+                            set_active_profile.set(profile);
+                        }
+                    }
+                    Err(message) => show_toast(message, ToastType::Error),
+                }
+                refresh_profiles();
+            });
+        };
+
+    let profile_args = move |config_too: bool| {
+        let args = js_sys::Object::new();
+        if let Some(name) = active_profile.get_untracked() {
+            js_sys::Reflect::set(&args, &"name".into(), &name.into()).unwrap();
         }
+        if config_too {
+            let config_js = serde_wasm_bindgen::to_value(&config.get_untracked()).unwrap();
+            js_sys::Reflect::set(&args, &"config".into(), &config_js).unwrap();
+        }
+        args
+    };
 
-        let rustfs_logs_value = tauri_invoke("get_rustfs_logs", js_sys::Object::new().into()).await;
-        if let Ok(logs_vec) = serde_wasm_bindgen::from_value::<Vec<String>>(rustfs_logs_value) {
-            rustfs_log_writer.set(logs_vec.into_iter().collect());
+    let select_profile = Callback::new(move |name: String| {
+        if name.is_empty() {
+            set_active_profile.set(None);
+            return;
         }
+        spawn_local(async move {
+            match fetch_profile(&name).await {
+                Ok(profile_config) => {
+                    set_config.set(profile_config);
+                    set_active_profile.set(Some(name));
+                }
+                Err(message) => show_toast(message, ToastType::Error),
+            }
+        });
+    });
+
+    let create_profile = Callback::new(move |name: String| {
+        let args = profile_args(true);
+        js_sys::Reflect::set(&args, &"name".into(), &name.clone().into()).unwrap();
+        run_profile_command("create_profile", args, Some(Some(name)));
+    });
+
+    let save_profile = Callback::new(move |_: ()| {
+        run_profile_command("update_profile", profile_args(true), None);
+    });
+
+    let clone_profile = Callback::new(move |name: String| {
+        let args = js_sys::Object::new();
+        let source = active_profile.get_untracked().unwrap_or_default();
+        js_sys::Reflect::set(&args, &"source".into(), &source.into()).unwrap();
+        js_sys::Reflect::set(&args, &"name".into(), &name.clone().into()).unwrap();
+        run_profile_command("clone_profile", args, Some(Some(name)));
+    });
+
+    let rename_profile = Callback::new(move |new_name: String| {
+        let args = profile_args(false);
+        js_sys::Reflect::set(&args, &"newName".into(), &new_name.clone().into()).unwrap();
+        run_profile_command("rename_profile", args, Some(Some(new_name)));
+    });
+
+    let delete_profile = Callback::new(move |_: ()| {
+        let name = active_profile.get_untracked().unwrap_or_default();
+        let confirmed = web_sys::window()
+            .and_then(|w| {
+                w.confirm_with_message(&format!("Delete profile '{}'?", name))
+                    .ok()
+            })
+            .unwrap_or(false);
+        if confirmed {
+            run_profile_command("delete_profile", profile_args(false), Some(None));
+        }
+    });
+
+    let set_default_profile = Callback::new(move |_: ()| {
+        run_profile_command("set_default_profile", profile_args(false), None);
+    });
+
+    let export_config = Callback::new(
+        move |(path, include_profiles, secrets): (String, bool, SecretMode)| {
+            let args = js_sys::Object::new();
+            let config_js = serde_wasm_bindgen::to_value(&config.get_untracked()).unwrap();
+            let secrets_js = serde_wasm_bindgen::to_value(&secrets).unwrap();
+            js_sys::Reflect::set(&args, &"path".into(), &path.into()).unwrap();
+            js_sys::Reflect::set(&args, &"config".into(), &config_js).unwrap();
+            js_sys::Reflect::set(&args, &"includeProfiles".into(), &include_profiles.into())
+                .unwrap();
+            js_sys::Reflect::set(&args, &"secrets".into(), &secrets_js).unwrap();
+            spawn_local(async move {
+                match invoke_command("export_config", args).await {
+                    Ok(res) => show_toast(res.message, ToastType::Success),
+                    Err(message) => show_toast(message, ToastType::Error),
+                }
+            });
+        },
+    );
+
+    let import_config = Callback::new(move |path: String| {
+        let args = js_sys::Object::new();
+        js_sys::Reflect::set(&args, &"path".into(), &path.into()).unwrap();
+        spawn_local(async move {
+            let summary = tauri_try_invoke("import_config", args.into())
+                .await
+                .map_err(|err| error_message(err, || "Failed to import config".to_string()))
+                .and_then(|value| {
+                    serde_wasm_bindgen::from_value::<ImportSummary>(value)
+                        .map_err(|e| e.to_string())
+                });
+            match summary {
+                Ok(summary) => {
+                    if let Some(mut imported) = summary.config {
+                        // A file exported without secrets keeps the current ones
+                        let current = config.get_untracked();
+                        if imported.access_key.is_none() && imported.secret_key.is_none() {
+                            imported.access_key = current.access_key;
+                            imported.secret_key = current.secret_key;
+                        }
+                        set_config.set(imported);
+                    }
+                    show_toast(
+                        format!("Imported {} profile(s)", summary.profiles.len()),
+                        ToastType::Success,
+                    );
+                    refresh_profiles();
+                }
+                Err(message) => show_toast(message, ToastType::Error),
+            }
+        });
+    });
+
+    let install_version = Callback::new(move |path: String| {
+        let args = js_sys::Object::new();
+        js_sys::Reflect::set(&args, &"archivePath".into(), &path.into()).unwrap();
+        spawn_local(async move {
+            show_toast("Installing RustFS release...".to_string(), ToastType::Info);
+            let installed = tauri_try_invoke("install_version", args.into())
+                .await
+                .map_err(|err| error_message(err, || "Failed to install release".to_string()))
+                .and_then(|value| {
+                    serde_wasm_bindgen::from_value::<InstalledVersion>(value)
+                        .map_err(|e| e.to_string())
+                });
+            match installed {
+                Ok(installed) => {
+                    show_toast(
+                        format!("Installed RustFS {}", installed.version),
+                        ToastType::Success,
+                    );
+                    set_versions.set(fetch_versions().await);
+                }
+                Err(message) => show_toast(message, ToastType::Error),
+            }
+        });
+    });
+
+    let download_version = Callback::new(move |version: String| {
+        let args = js_sys::Object::new();
+        if !version.trim().is_empty() {
+            js_sys::Reflect::set(&args, &"version".into(), &version.into()).unwrap();
+        }
+        set_download.set(None);
+        spawn_local(async move {
+            let installed = tauri_try_invoke("download_version", args.into())
+                .await
+                .map_err(|err| error_message(err, || "Failed to download release".to_string()))
+                .and_then(|value| {
+                    serde_wasm_bindgen::from_value::<InstalledVersion>(value)
+                        .map_err(|e| e.to_string())
+                });
+            match installed {
+                Ok(installed) => {
+                    show_toast(
+                        format!("Downloaded RustFS {}", installed.version),
+                        ToastType::Success,
+                    );
+                    set_versions.set(fetch_versions().await);
+                }
+                Err(message) => show_toast(message, ToastType::Error),
+            }
+        });
+    });
+
+    let upgrade_rustfs = Callback::new(move |_: ()| {
+        set_upgrading.set(true);
+        spawn_local(async move {
+            let outcome = tauri_try_invoke("upgrade_rustfs", js_sys::Object::new().into())
+                .await
+                .map_err(|err| error_message(err, || "Failed to upgrade RustFS".to_string()))
+                .and_then(|value| {
+                    serde_wasm_bindgen::from_value::<UpgradeOutcome>(value)
+                        .map_err(|e| e.to_string())
+                });
+            set_upgrading.set(false);
+            match outcome {
+                Ok(outcome) if outcome.rolled_back => {
+                    show_toast(outcome.message, ToastType::Error);
+                }
+                Ok(outcome) => show_toast(outcome.message, ToastType::Success),
+                Err(message) => show_toast(message, ToastType::Error),
+            }
+            set_versions.set(fetch_versions().await);
+            refresh_instances();
+            check_updates(false);
+        });
+    });
+
+    let refresh_log_files = Callback::new(move |_: ()| {
+        spawn_local(async move {
+            let value = tauri_invoke("list_log_files", js_sys::Object::new().into()).await;
+            set_log_files.set(serde_wasm_bindgen::from_value(value).unwrap_or_default());
+        });
+    });
+
+    let open_log_file = Callback::new(move |name: String| {
+        let args = js_sys::Object::new();
+        js_sys::Reflect::set(&args, &"name".into(), &name.clone().into()).unwrap();
+        set_selected_file.set(Some(name));
+        spawn_local(async move {
+            let lines = tauri_try_invoke("read_log_file", args.into())
+                .await
+                .map_err(|err| error_message(err, || "Failed to read log file".to_string()))
+                .and_then(|value| {
+                    serde_wasm_bindgen::from_value::<Vec<String>>(value).map_err(|e| e.to_string())
+                });
+            match lines {
+                Ok(lines) => set_file_logs.set(lines),
+                Err(message) => show_toast(message, ToastType::Error),
+            }
+        });
+    });
+
+    let activate_version = Callback::new(move |version: String| {
+        let args = js_sys::Object::new();
+        js_sys::Reflect::set(&args, &"version".into(), &version.clone().into()).unwrap();
+        spawn_local(async move {
+            match invoke_version_command("set_active_version", args).await {
+                Ok(list) => {
+                    set_versions.set(list);
+                    show_toast(
+                        format!("RustFS {} is now active", version),
+                        ToastType::Success,
+                    );
+                }
+                Err(message) => show_toast(message, ToastType::Error),
+            }
+        });
+    });
+
+    let rollback_version = Callback::new(move |_: ()| {
+        spawn_local(async move {
+            match invoke_version_command("rollback_version", js_sys::Object::new()).await {
+                Ok(list) => {
+                    let active = list.active.clone().unwrap_or_default();
+                    set_versions.set(list);
+                    show_toast(
+                        format!("Rolled back to RustFS {}", active),
+                        ToastType::Success,
+                    );
+                }
+                Err(message) => show_toast(message, ToastType::Error),
+            }
+        });
+    });
+
+    let check_credential_env = Callback::new(move |_: ()| {
+        spawn_local(async move {
+            let current = config.get_untracked();
+            let args = binary_args(current.binary_source, current.binary_path);
+            match tauri_try_invoke("check_credential_env_support", args.into())
+                .await
+                .map(serde_wasm_bindgen::from_value::<CommandResponse>)
+            {
+                Ok(Ok(res)) if res.success => show_toast(res.message, ToastType::Success),
+                Ok(Ok(res)) => show_toast(res.message, ToastType::Error),
+                Ok(Err(_)) => show_toast(
+                    "Failed to parse diagnostic response".to_string(),
+                    ToastType::Error,
+                ),
+                Err(err) => show_toast(
+                    error_message(err, || "Failed to run the RustFS binary".to_string()),
+                    ToastType::Error,
+                ),
+            }
+        });
     });
 
     let launch_rustfs = move |ev: SubmitEvent| {
         ev.prevent_default();
-        set_is_running.set(true);
         show_toast("Launching RustFS...".to_string(), ToastType::Info);
 
-        let now = js_sys::Date::new_0().to_locale_time_string("en-US".into());
-        push_log(
-            set_app_logs,
-            format!("[{}] Launch button clicked", now),
-            APP_LOG_CAPACITY,
-        );
+        let now = js_sys::Date::new_0().to_locale_time_string("en-US");
+        push_log(set_app_logs, format!("[{}] Launch button clicked", now));
         push_log(
             set_app_logs,
-            format!("[{}] Config: {:?}", now, config.get()),
-            APP_LOG_CAPACITY,
+            format!("[{}] Config: {:?}", now, config.get().redacted()),
         );
 
         spawn_local(async move {
@@ -274,14 +1329,83 @@ pub fn App() -> impl IntoView {
                 push_log(
                     set_app_logs,
                     "[ERROR] Not running in Tauri environment".to_string(),
-                    APP_LOG_CAPACITY,
                 );
-                set_is_running.set(false);
                 return;
             }
 
             let current_config = config.get_untracked();
 
+            // Validate every field first so all problems show up inline at once
+            let args = js_sys::Object::new();
+            let config_js = serde_wasm_bindgen::to_value(&current_config).unwrap();
+            js_sys::Reflect::set(&args, &"config".into(), &config_js).unwrap();
+            match tauri_try_invoke("validate_config", args.into()).await {
+                Ok(value) => {
+                    let report: ValidationReport =
+                        serde_wasm_bindgen::from_value(value).unwrap_or_default();
+                    for issue in &report.issues {
+                        let level = match issue.severity {
+                            Severity::Error => "ERROR",
+                            Severity::Warning => "WARN",
+                        };
+                        push_log(
+                            set_app_logs,
+                            format!("[{}] {:?}: {}", level, issue.field, issue.message),
+                        );
+                    }
+                    let has_errors = report.has_errors();
+                    set_validation.set(report);
+                    if has_errors {
+                        show_toast(
+                            "Fix the highlighted settings before launching".to_string(),
+                            ToastType::Error,
+                        );
+                        return;
+                    }
+                }
+                Err(err) => push_log(
+                    set_app_logs,
+                    format!(
+                        "[ERROR] {}",
+                        error_message(err, || "Config validation failed".to_string())
+                    ),
+                ),
+            }
+
+            // Check the port up front so a conflict can offer a free one
+            let host = current_config
+                .host
+                .clone()
+                .unwrap_or_else(|| "127.0.0.1".to_string());
+            let port = current_config.port.unwrap_or(9000);
+            let args = js_sys::Object::new();
+            js_sys::Reflect::set(&args, &"host".into(), &host.into()).unwrap();
+            js_sys::Reflect::set(&args, &"port".into(), &port.into()).unwrap();
+            let check_value = match tauri_try_invoke("check_port", args.into()).await {
+                Ok(value) => value,
+                Err(err) => {
+                    let message = error_message(err, || format!("Cannot check port {}", port));
+                    show_toast(message.clone(), ToastType::Error);
+                    push_log(set_app_logs, format!("[ERROR] {}", message));
+                    return;
+                }
+            };
+            if let Ok(check) = serde_wasm_bindgen::from_value::<PortCheck>(check_value) {
+                if !check.available {
+                    let owner = check
+                        .owner
+                        .as_ref()
+                        .map(|o| format!(" by {}", o))
+                        .unwrap_or_default();
+                    let message = format!("Port {} is already in use{}", port, owner);
+                    show_toast(message.clone(), ToastType::Error);
+                    push_log(set_app_logs, format!("[ERROR] {}", message));
+                    set_port_conflict.set(Some(check));
+                    return;
+                }
+            }
+            set_port_conflict.set(None);
+
             // 添加详细日志
             leptos::logging::log!(
                 "Starting RustFS with config: data_path={}, port={:?}, host={:?}",
@@ -290,66 +1414,73 @@ pub fn App() -> impl IntoView {
                 current_config.host
             );
 
-            let now = js_sys::Date::new_0().to_locale_time_string("en-US".into());
+            let now = js_sys::Date::new_0().to_locale_time_string("en-US");
             push_log(
                 set_app_logs,
                 format!("[{}] Calling tauri_invoke with command: launch_rustfs", now),
-                APP_LOG_CAPACITY,
             );
 
-            // Create args object with config parameter
-            let args = js_sys::Object::new();
+            // Create args object with instance and config parameters
+            let args = instance_args(&instance_name.get_untracked());
             let config_js = serde_wasm_bindgen::to_value(&current_config).unwrap();
             js_sys::Reflect::set(&args, &"config".into(), &config_js).unwrap();
+            if let Some(profile) = active_profile.get_untracked() {
+                js_sys::Reflect::set(&args, &"profile".into(), &profile.into()).unwrap();
+            }
 
-            let result_value = tauri_invoke("launch_rustfs", args.into()).await;
-            let now = js_sys::Date::new_0().to_locale_time_string("en-US".into());
+            let result_value = match tauri_try_invoke("launch_rustfs", args.into()).await {
+                Ok(value) => value,
+                Err(err) => {
+                    let message = error_message(err, || "RustFS failed to start".to_string());
+                    show_toast(
+                        message.lines().next().unwrap_or_default().to_string(),
+                        ToastType::Error,
+                    );
+                    let now = js_sys::Date::new_0().to_locale_time_string("en-US");
+                    push_log(
+                        set_app_logs,
+                        format!("[{}] Launch failed: {}", now, message),
+                    );
+                    set_current_log_type.set(LogType::RustFS);
+                    refresh_instances();
+                    return;
+                }
+            };
+            let now = js_sys::Date::new_0().to_locale_time_string("en-US");
             push_log(
                 set_app_logs,
                 format!("[{}] Invoke result: {:?}", now, result_value),
-                APP_LOG_CAPACITY,
             );
 
             match serde_wasm_bindgen::from_value::<CommandResponse>(result_value) {
                 Ok(CommandResponse { success, message }) => {
-                    let now = js_sys::Date::new_0().to_locale_time_string("en-US".into());
+                    let now = js_sys::Date::new_0().to_locale_time_string("en-US");
                     push_log(
                         set_app_logs,
                         format!("[{}] Result message: {}", now, message),
-                        APP_LOG_CAPACITY,
                     );
 
                     if success {
-                        show_toast(
-                            "RustFS launched successfully!".to_string(),
-                            ToastType::Success,
-                        );
-                        let now = js_sys::Date::new_0().to_locale_time_string("en-US".into());
-                        push_log(
-                            set_app_logs,
-                            format!("[{}] Launch successful!", now),
-                            APP_LOG_CAPACITY,
-                        );
+                        show_toast("RustFS is ready".to_string(), ToastType::Success);
+                        refresh_instances();
+                        let now = js_sys::Date::new_0().to_locale_time_string("en-US");
+                        push_log(set_app_logs, format!("[{}] Launch successful!", now));
                     } else {
                         show_toast(format!("Launch failed: {}", message), ToastType::Error);
-                        let now = js_sys::Date::new_0().to_locale_time_string("en-US".into());
+                        let now = js_sys::Date::new_0().to_locale_time_string("en-US");
                         push_log(
                             set_app_logs,
                             format!("[{}] Launch result: {}", now, message),
-                            APP_LOG_CAPACITY,
                         );
-                        set_is_running.set(false);
                     }
                 }
                 Err(_) => {
                     show_toast("RustFS launch command failed".to_string(), ToastType::Error);
-                    let now = js_sys::Date::new_0().to_locale_time_string("en-US".into());
+                    let now = js_sys::Date::new_0().to_locale_time_string("en-US");
                     push_log(
                         set_app_logs,
                         format!("[{}] Launch completed but response parsing failed", now),
-                        APP_LOG_CAPACITY,
                     );
-                    set_is_running.set(false);
                 }
             }
         });
@@ -357,33 +1488,21 @@ pub fn App() -> impl IntoView {
 
     let stop_rustfs = move |_| {
         show_toast("Stopping RustFS...".to_string(), ToastType::Info);
-        push_log(
-            set_app_logs,
-            "Stopping RustFS...".to_string(),
-            APP_LOG_CAPACITY,
-        );
+        push_log(set_app_logs, "Stopping RustFS...".to_string());
 
         spawn_local(async move {
-            let result_value = tauri_invoke("stop_rustfs", js_sys::Object::new().into()).await;
+            let args = instance_args(&instance_name.get_untracked());
+            let result_value = tauri_invoke("stop_rustfs", args.into()).await;
 
             match serde_wasm_bindgen::from_value::<CommandResponse>(result_value) {
                 Ok(res) => {
                     if res.success {
-                        set_is_running.set(false);
-                        set_service_status.set(false);
-                        show_toast("RustFS stopped".to_string(), ToastType::Success);
-                        push_log(
-                            set_app_logs,
-                            "RustFS stopped successfully".to_string(),
-                            APP_LOG_CAPACITY,
-                        );
+                        show_toast(res.message.clone(), ToastType::Success);
+                        refresh_instances();
+                        push_log(set_app_logs, format!("RustFS stopped: {}", res.message));
                     } else {
                         show_toast(format!("Failed to stop: {}", res.message), ToastType::Error);
-                        push_log(
-                            set_app_logs,
-                            format!("Failed to stop: {}", res.message),
-                            APP_LOG_CAPACITY,
-                        );
+                        push_log(set_app_logs, format!("Failed to stop: {}", res.message));
                     }
                 }
                 Err(_) => {
@@ -405,21 +1524,104 @@ pub fn App() -> impl IntoView {
                 <div class="header">
                     <h1>"RustFS Launcher"</h1>
                     <p class="subtitle">"Simple launcher for RustFS project"</p>
-                    <div class="service-indicator" class:online=move || service_status.get()>
+                    <div
+                        class="service-indicator"
+                        class:online=move || {
+                            matches!(process_state.get(), ProcessState::Running { .. })
+                        }
+                        class:crash-loop=move || supervisor_state.get() == SupervisorState::CrashLoop
+                    >
                         <span class="status-dot"></span>
                         <span class="status-text">
-                            {move || if service_status.get() { "Service Online" } else { "Service Offline" }}
+                            {move || match supervisor_state.get() {
+                                SupervisorState::CrashLoop => "Crash Loop",
+                                _ => process_state.get().label(),
+                            }}
                         </span>
                     </div>
                 </div>
 
+                <UpdateBanner update=update upgrading=upgrading on_upgrade=upgrade_rustfs />
+
+                <OrphanBanner
+                    orphans=orphans
+                    on_adopt=adopt_orphan
+                    on_terminate=terminate_orphan
+                />
+
+                <InstanceList
+                    instances=instances
+                    selected=instance_name
+                    on_select=select_instance
+                    on_remove=remove_instance
+                />
+
+                <ProfilePicker
+                    profiles=profiles
+                    active=active_profile
+                    on_select=select_profile
+                    on_create=create_profile
+                    on_save=save_profile
+                    on_clone=clone_profile
+                    on_rename=rename_profile
+                    on_delete=delete_profile
+                    on_set_default=set_default_profile
+                />
+
                 <ConfigForm
                     config=config
                     set_config=set_config
                     is_running=is_running
+                    port_conflict=port_conflict
+                    set_port_conflict=set_port_conflict
+                    validation=validation
+                    set_validation=set_validation
                     on_launch=Callback::new(launch_rustfs)
                     on_stop=Callback::new(stop_rustfs)
+                    on_check_credential_env=check_credential_env
+                    binary_info=binary_info
+                    on_select_binary=Callback::new(
+                        move |(source, path): (BinarySource, Option<String>)| {
+                            set_config.update(|c| {
+                                c.binary_source = source;
+                                c.binary_path = path;
+                            });
+                        },
+                    )
+                />
+
+                <VersionManager
+                    versions=versions
+                    on_install=install_version
+                    on_activate=activate_version
+                    on_rollback=rollback_version
+                    release_url=release_url
+                    set_release_url=set_release_url
+                    download=download
+                    on_download=download_version
+                    feed_url=feed_url
+                    set_feed_url=set_feed_url
+                    update=update
+                    on_check_updates=Callback::new(move |_| check_updates(true))
+                />
+
+                <ConfigTransfer on_export=export_config on_import=import_config />
+
+                <CredentialVault
+                    status=credential_status
+                    on_unlock=unlock_credentials
+                    on_lock=lock_credentials
+                    on_rotate=rotate_credentials_key
+                    on_wipe=wipe_credentials
                 />
+
+                <RedactionSettings
+                    patterns=redaction_patterns
+                    error=redaction_error
+                    on_apply=apply_redaction
+                />
+
+                <LogHistorySettings capacity=log_capacity on_apply=apply_log_capacity />
             </div>
 
             <div class="logs-section">
@@ -427,9 +1629,20 @@ pub fn App() -> impl IntoView {
                     app_logs=app_logs
                     set_app_logs=set_app_logs
                     rustfs_logs=rustfs_logs
-                    set_rustfs_logs=set_rustfs_logs
+                    instance=instance_name
+                    on_clear_rustfs_logs=Callback::new(move |_| {
+                        let name = instance_name.get_untracked();
+                        set_rustfs_logs.update(|all| {
+                            all.remove(&name);
+                        });
+                    })
                     current_log_type=current_log_type
                     set_current_log_type=set_current_log_type
+                    log_files=log_files
+                    selected_file=selected_file
+                    file_logs=file_logs
+                    on_open_file=open_log_file
+                    on_refresh_files=refresh_log_files
                 />
             </div>
         </main>
@@ -0,0 +1,128 @@
+use crate::types::{BinaryInfo, BinarySource};
+use leptos::prelude::*;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::spawn_local;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "dialog"])]
+    async fn open(options: JsValue) -> JsValue;
+}
+
+#[component]
+pub fn BinaryPicker(
+    #[prop(into)] binary_source: Signal<BinarySource>,
+    /// Remembered while another source is selected, so that switching back
+    /// to `Custom` restores it.
+    #[prop(into)]
+    binary_path: Signal<Option<String>>,
+    #[prop(into)] info: Signal<Option<BinaryInfo>>,
+    #[prop(into)] on_select: Callback<(BinarySource, Option<String>)>,
+) -> impl IntoView {
+    let is_custom = move || binary_source.get() == BinarySource::Custom;
+
+    let select_file = move |_| {
+        spawn_local(async move {
+            let options = serde_wasm_bindgen::to_value(&serde_json::json!({
+                "directory": false,
+                "title": "Select RustFS Binary"
+            }))
+            .unwrap();
+            if let Some(path) = open(options).await.as_string() {
+                if !path.is_empty() {
+                    on_select.run((BinarySource::Custom, Some(path)));
+                }
+            }
+        });
+    };
+
+    let summary = move || match info.get() {
+        None => "Not inspected yet".to_string(),
+        Some(info) => {
+            let version = info.version.as_deref().unwrap_or("unknown version");
+            let target = match (info.os.as_deref(), info.arch.as_deref()) {
+                (Some(os), Some(arch)) => format!(" ({}-{})", os, arch),
+                (Some(os), None) => format!(" ({})", os),
+                _ => String::new(),
+            };
+            let source = if info.bundled {
+                "Bundled"
+            } else if binary_source.get() == BinarySource::Managed {
+                "Managed"
+            } else {
+                "Custom"
+            };
+            format!("{} binary, {}{}", source, version, target)
+        }
+    };
+
+    view! {
+        <div class="form-group binary-picker">
+            <label for="binary-source">"RustFS Binary"</label>
+            <select
+                id="binary-source"
+                prop:value=move || match binary_source.get() {
+                    BinarySource::Managed => "managed",
+                    BinarySource::Bundled => "bundled",
+                    BinarySource::Custom => "custom",
+                }
+                on:change=move |ev| {
+                    let source = match event_target_value(&ev).as_str() {
+                        "bundled" => BinarySource::Bundled,
+                        "custom" => BinarySource::Custom,
+                        _ => BinarySource::Managed,
+                    };
+                    on_select.run((source, binary_path.get_untracked()));
+                }
+            >
+                <option value="managed">"Active managed version"</option>
+                <option value="bundled">"Bundled with the launcher"</option>
+                <option value="custom">"Custom binary"</option>
+            </select>
+            <div class="path-input-group">
+                <input
+                    id="binary-path"
+                    type="text"
+                    placeholder=move || {
+                        info.get()
+                            .filter(|_| !is_custom())
+                            .map(|i| i.path)
+                            .unwrap_or_else(|| "Path to a RustFS binary".to_string())
+                    }
+                    prop:value=move || {
+                        if is_custom() { binary_path.get().unwrap_or_default() } else { String::new() }
+                    }
+                    on:change=move |ev| {
+                        let value = event_target_value(&ev).trim().to_string();
+                        if value.is_empty() {
+                            on_select.run((binary_source.get_untracked(), None));
+                        } else {
+                            on_select.run((BinarySource::Custom, Some(value)));
+                        }
+                    }
+                />
+                <button type="button" class="browse-btn" on:click=select_file>
+                    "Browse"
+                </button>
+                <Show when=move || binary_source.get() != BinarySource::Bundled>
+                    <button
+                        type="button"
+                        class="browse-btn"
+                        title="Use the binary bundled with the launcher"
+                        on:click=move |_| {
+                            on_select.run((BinarySource::Bundled, binary_path.get_untracked()))
+                        }
+                    >
+                        "Reset"
+                    </button>
+                </Show>
+            </div>
+            <div class="binary-info">{summary}</div>
+            {move || {
+                info.get()
+                    .and_then(|i| i.warning)
+                    .map(|warning| view! { <div class="field-warning">{warning}</div> })
+            }}
+        </div>
+    }
+}
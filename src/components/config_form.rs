@@ -1,4 +1,8 @@
-use crate::types::RustFsConfig;
+use crate::components::binary_picker::BinaryPicker;
+use crate::types::{
+    BinaryInfo, BinarySource, ConfigField, PortCheck, RestartPolicy, RustFsConfig, Severity,
+    ValidationReport,
+};
 use leptos::ev::SubmitEvent;
 use leptos::prelude::*;
 use serde_json;
@@ -12,13 +16,42 @@ extern "C" {
     async fn open(options: JsValue) -> JsValue;
 }
 
+/// The backend's validation issues for `fields`, shown under an input.
+#[component]
+fn FieldIssues(
+    #[prop(into)] validation: Signal<ValidationReport>,
+    fields: &'static [ConfigField],
+) -> impl IntoView {
+    move || {
+        let report = validation.get();
+        fields
+            .iter()
+            .flat_map(|field| report.for_field(*field))
+            .map(|issue| {
+                let class = match issue.severity {
+                    Severity::Error => "field-error",
+                    Severity::Warning => "field-warning",
+                };
+                view! { <div class=class>{issue.message.clone()}</div> }
+            })
+            .collect_view()
+    }
+}
+
 #[component]
 pub fn ConfigForm(
     #[prop(into)] config: Signal<RustFsConfig>,
     #[prop(into)] set_config: WriteSignal<RustFsConfig>,
     #[prop(into)] is_running: Signal<bool>,
+    #[prop(into)] port_conflict: Signal<Option<PortCheck>>,
+    #[prop(into)] set_port_conflict: WriteSignal<Option<PortCheck>>,
+    #[prop(into)] validation: Signal<ValidationReport>,
+    #[prop(into)] set_validation: WriteSignal<ValidationReport>,
+    #[prop(into)] binary_info: Signal<Option<BinaryInfo>>,
+    #[prop(into)] on_select_binary: Callback<(BinarySource, Option<String>)>,
     #[prop(into)] on_launch: Callback<SubmitEvent>,
     #[prop(into)] on_stop: Callback<()>,
+    #[prop(into)] on_check_credential_env: Callback<()>,
 ) -> impl IntoView {
     let (show_secret, set_show_secret) = signal(false);
     let (is_drag_over, set_is_drag_over) = signal(false);
@@ -102,7 +135,8 @@ pub fn ConfigForm(
         });
     };
 
-    let (error_message, set_error_message) = signal(Option::<String>::None);
+    // Editing a field makes its earlier validation issues stale.
+    let clear_issues = move |field: ConfigField| set_validation.update(|r| r.clear(field));
 
     let handle_submit = move |ev: SubmitEvent| {
         ev.prevent_default();
@@ -111,29 +145,24 @@ pub fn ConfigForm(
             // Stop mode
             on_stop.run(());
         } else {
-            // Launch mode
-            set_error_message.set(None);
-
-            let current_config = config.get();
-
-            if current_config.data_path.trim().is_empty() {
-                set_error_message.set(Some("Data path is required".to_string()));
-                return;
-            }
-
-            if let Some(port) = current_config.port {
-                if port == 0 {
-                    set_error_message.set(Some("Port must be greater than 0".to_string()));
-                    return;
-                }
-            }
-
+            // Launch mode; the backend validates the config first
             on_launch.run(ev);
         }
     };
 
     view! {
         <form class="config-form" on:submit=handle_submit>
+            <BinaryPicker
+                binary_source=Signal::derive(move || config.get().binary_source)
+                binary_path=Signal::derive(move || config.get().binary_path)
+                info=binary_info
+                on_select=Callback::new(move |selection| {
+                    clear_issues(ConfigField::BinaryPath);
+                    on_select_binary.run(selection);
+                })
+            />
+            <FieldIssues validation=validation fields=&[ConfigField::BinaryPath] />
+
             <div class="form-group">
                 <label for="data-path">"Data Path" <span class="required">"*"</span></label>
                 <div
@@ -147,16 +176,15 @@ pub fn ConfigForm(
                         prop:value=move || config.get().data_path
                         on:input=move |ev| {
                             let value = event_target_value(&ev);
-                            set_config.update(|c| c.data_path = value.clone());
-                            if !value.is_empty() {
-                                set_error_message.set(None);
-                            }
+                            set_config.update(|c| c.data_path = value);
+                            clear_issues(ConfigField::DataPath);
                         }
                     />
                     <button type="button" class="browse-btn" on:click=select_folder>
                         "Browse"
                     </button>
                 </div>
+                <FieldIssues validation=validation fields=&[ConfigField::DataPath] />
             </div>
 
             <div class="form-row">
@@ -173,8 +201,49 @@ pub fn ConfigForm(
                             let value = event_target_value(&ev);
                             let port = if value.is_empty() { None } else { value.parse().ok() };
                             set_config.update(|c| c.port = port);
+                            set_port_conflict.set(None);
+                            clear_issues(ConfigField::Port);
                         }
                     />
+                    <FieldIssues validation=validation fields=&[ConfigField::Port] />
+                    <Show when=move || config.get().port.is_some_and(|p| p > 0 && p < 1024)>
+                        <div class="checkbox-group">
+                            <input
+                                id="allow-privileged-port"
+                                type="checkbox"
+                                prop:checked=move || config.get().allow_privileged_port
+                                on:change=move |ev| {
+                                    let checked = event_target_checked(&ev);
+                                    set_config.update(|c| c.allow_privileged_port = checked);
+                                    clear_issues(ConfigField::Port);
+                                }
+                            />
+                            <label for="allow-privileged-port">"Allow Privileged Port"</label>
+                        </div>
+                    </Show>
+                    {move || port_conflict.get().map(|check| {
+                        let owner = check
+                            .owner
+                            .map(|o| format!(" by {}", o))
+                            .unwrap_or_default();
+                        view! {
+                            <div class="field-error">
+                                {format!("Port in use{}", owner)}
+                                {check.suggested_port.map(|free| view! {
+                                    <button
+                                        type="button"
+                                        class="link-btn"
+                                        on:click=move |_| {
+                                            set_config.update(|c| c.port = Some(free));
+                                            set_port_conflict.set(None);
+                                        }
+                                    >
+                                        {format!("Use {}", free)}
+                                    </button>
+                                })}
+                            </div>
+                        }
+                    })}
                 </div>
                 <div class="form-group">
                     <label for="host">"Host"</label>
@@ -187,8 +256,10 @@ pub fn ConfigForm(
                             let value = event_target_value(&ev);
                             let host = if value.is_empty() { None } else { Some(value) };
                             set_config.update(|c| c.host = host);
+                            clear_issues(ConfigField::Host);
                         }
                     />
+                    <FieldIssues validation=validation fields=&[ConfigField::Host] />
                 </div>
             </div>
 
@@ -204,8 +275,10 @@ pub fn ConfigForm(
                             let value = event_target_value(&ev);
                             let access_key = if value.is_empty() { None } else { Some(value) };
                             set_config.update(|c| c.access_key = access_key);
+                            clear_issues(ConfigField::AccessKey);
                         }
                     />
+                    <FieldIssues validation=validation fields=&[ConfigField::AccessKey] />
                 </div>
                 <div class="form-group">
                     <label for="secret-key">"Secret Key"</label>
@@ -219,6 +292,7 @@ pub fn ConfigForm(
                                 let value = event_target_value(&ev);
                                 let secret_key = if value.is_empty() { None } else { Some(value) };
                                 set_config.update(|c| c.secret_key = secret_key);
+                                clear_issues(ConfigField::SecretKey);
                             }
                         />
                         <button
@@ -244,6 +318,45 @@ pub fn ConfigForm(
                             }}
                         </button>
                     </div>
+                    <FieldIssues validation=validation fields=&[ConfigField::SecretKey] />
+                </div>
+            </div>
+
+            <div class="form-row">
+                <div class="form-group">
+                    <label for="shutdown-grace">"Shutdown Grace (s)"</label>
+                    <input
+                        id="shutdown-grace"
+                        type="number"
+                        placeholder="10"
+                        min="0"
+                        prop:value=move || config.get().shutdown_grace_secs.to_string()
+                        on:input=move |ev| {
+                            let value = event_target_value(&ev);
+                            if let Ok(secs) = value.parse() {
+                                set_config.update(|c| c.shutdown_grace_secs = secs);
+                            }
+                            clear_issues(ConfigField::ShutdownGraceSecs);
+                        }
+                    />
+                    <FieldIssues validation=validation fields=&[ConfigField::ShutdownGraceSecs] />
+                </div>
+                <div class="form-group">
+                    <label for="startup-timeout">"Startup Timeout (s)"</label>
+                    <input
+                        id="startup-timeout"
+                        type="number"
+                        placeholder="30"
+                        min="1"
+                        prop:value=move || config.get().startup_timeout_secs.to_string()
+                        on:input=move |ev| {
+                            if let Ok(secs) = event_target_value(&ev).parse() {
+                                set_config.update(|c| c.startup_timeout_secs = secs);
+                            }
+                            clear_issues(ConfigField::StartupTimeoutSecs);
+                        }
+                    />
+                    <FieldIssues validation=validation fields=&[ConfigField::StartupTimeoutSecs] />
                 </div>
             </div>
 
@@ -264,6 +377,73 @@ pub fn ConfigForm(
                 </div>
             </div>
 
+            <div class="form-row">
+                <div class="form-group">
+                    <div class="checkbox-group">
+                        <input
+                            id="credentials-via-env"
+                            type="checkbox"
+                            prop:checked=move || config.get().credentials_via_env
+                            on:change=move |ev| {
+                                let checked = event_target_checked(&ev);
+                                set_config.update(|c| c.credentials_via_env = checked);
+                            }
+                        />
+                        <label for="credentials-via-env">"Pass Credentials via Environment"</label>
+                        <button
+                            type="button"
+                            class="link-btn"
+                            on:click=move |_| on_check_credential_env.run(())
+                        >
+                            "Check binary"
+                        </button>
+                    </div>
+                </div>
+            </div>
+
+            <div class="form-row">
+                <div class="form-group">
+                    <label for="restart-policy">"Auto Restart"</label>
+                    <select
+                        id="restart-policy"
+                        prop:value=move || match config.get().supervisor.restart_policy {
+                            RestartPolicy::Never => "never",
+                            RestartPolicy::OnFailure => "on-failure",
+                            RestartPolicy::Always => "always",
+                        }
+                        on:change=move |ev| {
+                            let policy = match event_target_value(&ev).as_str() {
+                                "on-failure" => RestartPolicy::OnFailure,
+                                "always" => RestartPolicy::Always,
+                                _ => RestartPolicy::Never,
+                            };
+                            set_config.update(|c| c.supervisor.restart_policy = policy);
+                        }
+                    >
+                        <option value="never">"Never"</option>
+                        <option value="on-failure">"On Failure"</option>
+                        <option value="always">"Always"</option>
+                    </select>
+                </div>
+                <div class="form-group">
+                    <label for="max-restarts">"Max Restarts"</label>
+                    <input
+                        id="max-restarts"
+                        type="number"
+                        placeholder="5"
+                        min="1"
+                        prop:value=move || config.get().supervisor.max_restarts.to_string()
+                        on:input=move |ev| {
+                            if let Ok(max) = event_target_value(&ev).parse() {
+                                set_config.update(|c| c.supervisor.max_restarts = max);
+                            }
+                            clear_issues(ConfigField::Supervisor);
+                        }
+                    />
+                    <FieldIssues validation=validation fields=&[ConfigField::Supervisor] />
+                </div>
+            </div>
+
             <div class="form-actions">
                 <button
                     type="submit"
@@ -273,11 +453,8 @@ pub fn ConfigForm(
                 >
                     { move || if is_running.get() { "Stop RustFS" } else { "Launch RustFS" } }
                 </button>
-                <Show when=move || error_message.get().is_some()>
-                    <div class="error-message">
-                        { move || error_message.get() }
-                    </div>
-                </Show>
+                // Settings without an input of their own
+                <FieldIssues validation=validation fields=&[ConfigField::HealthCheckPath] />
             </div>
         </form>
     }
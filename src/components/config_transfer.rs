@@ -0,0 +1,107 @@
+use crate::types::SecretMode;
+use leptos::prelude::*;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::spawn_local;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "dialog"])]
+    async fn open(options: JsValue) -> JsValue;
+
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "dialog"])]
+    async fn save(options: JsValue) -> JsValue;
+}
+
+fn file_filters() -> serde_json::Value {
+    serde_json::json!([{ "name": "Launcher Config", "extensions": ["toml", "json"] }])
+}
+
+#[component]
+pub fn ConfigTransfer(
+    #[prop(into)] on_export: Callback<(String, bool, SecretMode)>,
+    #[prop(into)] on_import: Callback<String>,
+) -> impl IntoView {
+    let (include_profiles, set_include_profiles) = signal(true);
+    let (secret_mode, set_secret_mode) = signal(SecretMode::Placeholder);
+
+    let export = move |_| {
+        spawn_local(async move {
+            let options = serde_wasm_bindgen::to_value(&serde_json::json!({
+                "title": "Export Launcher Config",
+                "defaultPath": "rustfs-launcher.toml",
+                "filters": file_filters()
+            }))
+            .unwrap();
+            if let Some(path) = save(options).await.as_string() {
+                if !path.is_empty() {
+                    on_export.run((
+                        path,
+                        include_profiles.get_untracked(),
+                        secret_mode.get_untracked(),
+                    ));
+                }
+            }
+        });
+    };
+
+    let import = move |_| {
+        spawn_local(async move {
+            let options = serde_wasm_bindgen::to_value(&serde_json::json!({
+                "title": "Import Launcher Config",
+                "filters": file_filters()
+            }))
+            .unwrap();
+            if let Some(path) = open(options).await.as_string() {
+                if !path.is_empty() {
+                    on_import.run(path);
+                }
+            }
+        });
+    };
+
+    view! {
+        <details class="config-transfer">
+            <summary>"Import / Export"</summary>
+            <div class="form-group">
+                <label for="secret-mode">"Secrets in Exported File"</label>
+                <select
+                    id="secret-mode"
+                    prop:value=move || match secret_mode.get() {
+                        SecretMode::Placeholder => "placeholder",
+                        SecretMode::Exclude => "exclude",
+                        SecretMode::Include => "include",
+                    }
+                    on:change=move |ev| {
+                        let mode = match event_target_value(&ev).as_str() {
+                            "exclude" => SecretMode::Exclude,
+                            "include" => SecretMode::Include,
+                            _ => SecretMode::Placeholder,
+                        };
+                        set_secret_mode.set(mode);
+                    }
+                >
+                    <option value="placeholder">"${ENV_VAR} placeholders"</option>
+                    <option value="exclude">"Leave out"</option>
+                    <option value="include">"Include in clear text"</option>
+                </select>
+            </div>
+            <div class="checkbox-group">
+                <input
+                    id="export-profiles"
+                    type="checkbox"
+                    prop:checked=move || include_profiles.get()
+                    on:change=move |ev| set_include_profiles.set(event_target_checked(&ev))
+                />
+                <label for="export-profiles">"Include Profiles"</label>
+            </div>
+            <div class="transfer-actions">
+                <button type="button" class="browse-btn" on:click=export>
+                    "Export..."
+                </button>
+                <button type="button" class="browse-btn" on:click=import>
+                    "Import..."
+                </button>
+            </div>
+        </details>
+    }
+}
@@ -0,0 +1,85 @@
+use crate::types::{CredentialStatus, KeySource};
+use leptos::prelude::*;
+
+#[component]
+pub fn CredentialVault(
+    #[prop(into)] status: Signal<Option<CredentialStatus>>,
+    #[prop(into)] on_unlock: Callback<Option<String>>,
+    #[prop(into)] on_lock: Callback<()>,
+    #[prop(into)] on_rotate: Callback<Option<String>>,
+    #[prop(into)] on_wipe: Callback<()>,
+) -> impl IntoView {
+    let (passphrase, set_passphrase) = signal(String::new());
+
+    // Hands over the typed passphrase (None means "use a key file") and clears it.
+    let take_passphrase = move || {
+        let value = passphrase.get_untracked();
+        set_passphrase.set(String::new());
+        (!value.is_empty()).then_some(value)
+    };
+
+    let summary = move || match status.get() {
+        None => "Credential store unavailable",
+        Some(s) if !s.exists => "No credential store yet",
+        Some(s) if !s.unlocked => "Credential store locked",
+        Some(s) => match s.key_source {
+            Some(KeySource::Passphrase) => "Unlocked (passphrase)",
+            _ => "Unlocked (key file)",
+        },
+    };
+    let unlocked = move || status.get().is_some_and(|s| s.unlocked);
+    let exists = move || status.get().is_some_and(|s| s.exists);
+
+    view! {
+        <div class="credential-vault">
+            <label>"Credentials"</label>
+            <p class="vault-status" class:unlocked=unlocked>{summary}</p>
+            <input
+                type="password"
+                placeholder=move || {
+                    if unlocked() {
+                        "New passphrase (empty = key file)"
+                    } else if exists() {
+                        "Passphrase"
+                    } else {
+                        "Passphrase (empty = key file)"
+                    }
+                }
+                prop:value=move || passphrase.get()
+                on:input=move |ev| set_passphrase.set(event_target_value(&ev))
+            />
+            <div class="vault-actions">
+                <Show
+                    when=unlocked
+                    fallback=move || {
+                        view! {
+                            <button
+                                type="button"
+                                class="browse-btn"
+                                on:click=move |_| on_unlock.run(take_passphrase())
+                            >
+                                {move || if exists() { "Unlock" } else { "Create" }}
+                            </button>
+                        }
+                    }
+                >
+                    <button type="button" class="browse-btn" on:click=move |_| on_lock.run(())>
+                        "Lock"
+                    </button>
+                    <button
+                        type="button"
+                        class="browse-btn"
+                        on:click=move |_| on_rotate.run(take_passphrase())
+                    >
+                        "Rotate Key"
+                    </button>
+                </Show>
+                <Show when=exists>
+                    <button type="button" class="browse-btn" on:click=move |_| on_wipe.run(())>
+                        "Wipe"
+                    </button>
+                </Show>
+            </div>
+        </div>
+    }
+}
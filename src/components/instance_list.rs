@@ -0,0 +1,96 @@
+use crate::types::InstanceInfo;
+use leptos::prelude::*;
+
+#[component]
+pub fn InstanceList(
+    #[prop(into)] instances: Signal<Vec<InstanceInfo>>,
+    #[prop(into)] selected: Signal<String>,
+    #[prop(into)] on_select: Callback<String>,
+    #[prop(into)] on_remove: Callback<String>,
+) -> impl IntoView {
+    let (new_name, set_new_name) = signal(String::new());
+
+    // The selected instance is listed even before it has been launched once.
+    let rows = move || {
+        let mut rows: Vec<(String, bool, Option<u32>)> = instances
+            .get()
+            .into_iter()
+            .map(|i| (i.name, i.running, i.pid))
+            .collect();
+        let current = selected.get();
+        if !rows.iter().any(|(name, _, _)| name == &current) {
+            rows.push((current, false, None));
+            rows.sort_by(|a, b| a.0.cmp(&b.0));
+        }
+        rows
+    };
+
+    let add_instance = move |_| {
+        let name = new_name.get().trim().to_string();
+        if !name.is_empty() {
+            on_select.run(name);
+            set_new_name.set(String::new());
+        }
+    };
+
+    view! {
+        <div class="instance-list">
+            <label>"Instances"</label>
+            <ul>
+                <For
+                    each=rows
+                    key=|row| row.clone()
+                    let:row
+                >
+                    {
+                        let (name, running, pid) = row;
+                        let select_name = name.clone();
+                        let remove_name = name.clone();
+                        let active_name = name.clone();
+                        view! {
+                            <li
+                                class="instance-item"
+                                class:active=move || selected.get() == active_name
+                                class:running=running
+                                on:click=move |_| on_select.run(select_name.clone())
+                            >
+                                <span class="status-dot"></span>
+                                <span class="instance-name">{name}</span>
+                                <span class="instance-pid">
+                                    {pid.map(|p| format!("PID {}", p)).unwrap_or_default()}
+                                </span>
+                                <Show when=move || !running>
+                                    <button
+                                        type="button"
+                                        class="instance-remove"
+                                        title="Remove Instance"
+                                        on:click={
+                                            let remove_name = remove_name.clone();
+                                            move |ev: leptos::ev::MouseEvent| {
+                                                ev.stop_propagation();
+                                                on_remove.run(remove_name.clone());
+                                            }
+                                        }
+                                    >
+                                        "×"
+                                    </button>
+                                </Show>
+                            </li>
+                        }
+                    }
+                </For>
+            </ul>
+            <div class="instance-add">
+                <input
+                    type="text"
+                    placeholder="New instance name..."
+                    prop:value=move || new_name.get()
+                    on:input=move |ev| set_new_name.set(event_target_value(&ev))
+                />
+                <button type="button" class="browse-btn" on:click=add_instance>
+                    "Add"
+                </button>
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,66 @@
+use crate::types::LogCapacity;
+use leptos::prelude::*;
+
+#[component]
+pub fn LogHistorySettings(
+    #[prop(into)] capacity: Signal<LogCapacity>,
+    #[prop(into)] on_apply: Callback<LogCapacity>,
+) -> impl IntoView {
+    let (app, set_app) = signal(String::new());
+    let (rustfs, set_rustfs) = signal(String::new());
+
+    // Show the limits in effect, including after the backend clamped them.
+    Effect::new(move |_| {
+        let capacity = capacity.get();
+        set_app.set(capacity.app.to_string());
+        set_rustfs.set(capacity.rustfs.to_string());
+    });
+
+    let apply = move |_| {
+        let current = capacity.get_untracked();
+        on_apply.run(LogCapacity {
+            app: app.get().trim().parse().unwrap_or(current.app),
+            rustfs: rustfs.get().trim().parse().unwrap_or(current.rustfs),
+        });
+    };
+
+    view! {
+        <details class="log-history-settings">
+            <summary>"Log History"</summary>
+            <p class="redaction-hint">
+                {format!(
+                    "Records kept in memory, {} to {} per log. Older ones stay in the log files.",
+                    LogCapacity::MIN,
+                    LogCapacity::MAX,
+                )}
+            </p>
+            <div class="form-group">
+                <label for="app-log-capacity">"Launcher Log"</label>
+                <input
+                    id="app-log-capacity"
+                    type="number"
+                    min=LogCapacity::MIN
+                    max=LogCapacity::MAX
+                    step="100"
+                    prop:value=move || app.get()
+                    on:input=move |ev| set_app.set(event_target_value(&ev))
+                />
+            </div>
+            <div class="form-group">
+                <label for="rustfs-log-capacity">"RustFS Output (per instance)"</label>
+                <input
+                    id="rustfs-log-capacity"
+                    type="number"
+                    min=LogCapacity::MIN
+                    max=LogCapacity::MAX
+                    step="100"
+                    prop:value=move || rustfs.get()
+                    on:input=move |ev| set_rustfs.set(event_target_value(&ev))
+                />
+            </div>
+            <button type="button" class="browse-btn" on:click=apply>
+                "Apply"
+            </button>
+        </details>
+    }
+}
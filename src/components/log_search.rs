@@ -0,0 +1,115 @@
+use leptos::prelude::*;
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+extern "C" {
+    /// `RegExp(pattern, flags)`, catching the `SyntaxError` of a bad pattern.
+    #[wasm_bindgen(catch, js_name = RegExp)]
+    fn new_regexp(pattern: &str, flags: &str) -> Result<js_sys::RegExp, JsValue>;
+}
+
+/// Byte ranges of the matches in a line, in order and not overlapping.
+pub type Matches = Vec<(usize, usize)>;
+
+/// What the search box asks for. Both modes ignore case.
+pub enum Search {
+    All,
+    Plain(String),
+    Regex(js_sys::RegExp),
+    /// A regex that does not compile; it filters nothing.
+    Invalid(String),
+}
+
+impl Search {
+    pub fn new(query: &str, regex: bool) -> Self {
+        if query.is_empty() {
+            return Search::All;
+        }
+        if !regex {
+            return Search::Plain(query.to_ascii_lowercase());
+        }
+        match new_regexp(query, "gi") {
+            Ok(regex) => Search::Regex(regex),
+            Err(err) => Search::Invalid(
+                js_sys::Error::from(err)
+                    .message()
+                    .as_string()
+                    .unwrap_or_else(|| "Invalid regular expression".to_string()),
+            ),
+        }
+    }
+
+    pub fn error(&self) -> Option<&str> {
+        match self {
+            Search::Invalid(message) => Some(message),
+            _ => None,
+        }
+    }
+
+    /// Where `text` matches, or `None` if it does not and should be hidden.
+    /// Lines always match an empty or invalid search, with nothing to mark.
+    pub fn find(&self, text: &str) -> Option<Matches> {
+        let matches = match self {
+            Search::All | Search::Invalid(_) => return Some(Vec::new()),
+            Search::Plain(needle) => text
+                .to_ascii_lowercase()
+                .match_indices(needle.as_str())
+                .map(|(start, found)| (start, start + found.len()))
+                .collect(),
+            Search::Regex(regex) => regex_matches(regex, text),
+        };
+        (!matches.is_empty()).then_some(matches)
+    }
+}
+
+/// Byte offset of the UTF-16 offset `units` in `text`, as JS reports them.
+fn utf16_to_byte(text: &str, units: usize) -> usize {
+    let mut seen = 0;
+    for (index, c) in text.char_indices() {
+        if seen >= units {
+            return index;
+        }
+        seen += c.len_utf16();
+    }
+    text.len()
+}
+
+fn regex_matches(regex: &js_sys::RegExp, text: &str) -> Matches {
+    regex.set_last_index(0);
+    let mut matches = Vec::new();
+    while let Some(found) = regex.exec(text) {
+        let start = js_sys::Reflect::get(&found, &"index".into())
+            .ok()
+            .and_then(|index| index.as_f64())
+            .unwrap_or_default() as usize;
+        let len = found
+            .get(0)
+            .as_string()
+            .map_or(0, |m| m.encode_utf16().count());
+        if len == 0 {
+            // An empty match would be found again at the same place.
+            regex.set_last_index(regex.last_index() + 1);
+            continue;
+        }
+        matches.push((utf16_to_byte(text, start), utf16_to_byte(text, start + len)));
+    }
+    matches
+}
+
+/// `text` with each match wrapped in `<mark>`.
+pub fn highlight(text: &str, matches: &[(usize, usize)]) -> Vec<AnyView> {
+    let mut parts = Vec::new();
+    let mut end_of_last = 0;
+    for &(start, end) in matches {
+        if start > end_of_last {
+            parts.push(text[end_of_last..start].to_string().into_any());
+        }
+        let found = text[start..end].to_string();
+        parts.push(view! { <mark class="log-match">{found}</mark> }.into_any());
+        end_of_last = end;
+    }
+    if end_of_last < text.len() {
+        parts.push(text[end_of_last..].to_string().into_any());
+    }
+    parts
+}
@@ -1,37 +1,302 @@
-use crate::types::LogType;
+use crate::components::log_search::{highlight, Matches, Search};
+use crate::types::{LogFileInfo, LogLevel, LogRecord, LogStream, LogType};
 use leptos::prelude::*;
-use std::collections::VecDeque;
+use std::collections::{HashMap, VecDeque};
+
+/// Height of one row in pixels; `.log-line` in logs.css has the same.
+const ROW_HEIGHT: f64 = 21.0;
+/// Rows rendered beyond each edge of the viewport, so fast scrolling does
+/// not show gaps.
+const OVERSCAN: usize = 20;
+
+/// What the log pane shows.
+#[derive(Clone, PartialEq)]
+enum Rows {
+    /// Every entry of the current tab: nothing is filtered, so the window
+    /// indexes the buffer directly. `last` is the newest record's sequence
+    /// number, which changes on every append even once the buffer is full.
+    All { count: usize, last: Option<u64> },
+    /// The entries that pass the filters, in order: a record's sequence
+    /// number, or a file line's index on the file tab, with where the search
+    /// matched it.
+    Filtered(Vec<(u64, Matches)>),
+}
+
+impl Rows {
+    fn len(&self) -> usize {
+        match self {
+            Rows::All { count, .. } => *count,
+            Rows::Filtered(rows) => rows.len(),
+        }
+    }
+}
+
+/// Level toggles, most severe first.
+const LEVELS: [LogLevel; 5] = [
+    LogLevel::Error,
+    LogLevel::Warn,
+    LogLevel::Info,
+    LogLevel::Debug,
+    LogLevel::Trace,
+];
 
 #[component]
 pub fn LogViewer(
-    #[prop(into)] app_logs: Signal<VecDeque<String>>,
-    #[prop(into)] set_app_logs: WriteSignal<VecDeque<String>>,
-    #[prop(into)] rustfs_logs: Signal<VecDeque<String>>,
-    #[prop(into)] set_rustfs_logs: WriteSignal<VecDeque<String>>,
+    #[prop(into)] app_logs: Signal<VecDeque<LogRecord>>,
+    #[prop(into)] set_app_logs: WriteSignal<VecDeque<LogRecord>>,
+    /// Buffered RustFS output of every instance, by name.
+    #[prop(into)]
+    rustfs_logs: Signal<HashMap<String, VecDeque<LogRecord>>>,
+    /// The instance whose output is shown.
+    #[prop(into)]
+    instance: Signal<String>,
+    #[prop(into)] on_clear_rustfs_logs: Callback<()>,
     #[prop(into)] current_log_type: Signal<LogType>,
     #[prop(into)] set_current_log_type: WriteSignal<LogType>,
+    #[prop(into)] log_files: Signal<Vec<LogFileInfo>>,
+    #[prop(into)] selected_file: Signal<Option<String>>,
+    #[prop(into)] file_logs: Signal<Vec<String>>,
+    #[prop(into)] on_open_file: Callback<String>,
+    #[prop(into)] on_refresh_files: Callback<()>,
 ) -> impl IntoView {
     let (auto_scroll, set_auto_scroll) = signal(true);
     let logs_ref = NodeRef::<leptos::html::Div>::new();
+    let (scroll_top, set_scroll_top) = signal(0.0);
+    let (viewport_height, set_viewport_height) = signal(800.0);
+
+    let clear_logs = move |_| {
+        set_app_logs.set(VecDeque::new());
+        on_clear_rustfs_logs.run(());
+    };
+
+    // Runs `f` on the records of the current tab, without copying them.
+    let with_records = move |f: &mut dyn FnMut(&VecDeque<LogRecord>)| match current_log_type.get() {
+        LogType::App => app_logs.with(|logs| f(logs)),
+        LogType::RustFS => {
+            let name = instance.get();
+            rustfs_logs.with(|all| f(all.get(&name).unwrap_or(&VecDeque::new())))
+        }
+        LogType::File => f(&VecDeque::new()),
+    };
+
+    // Search and filters only decide what is shown; the buffers keep every
+    // entry.
+    let (query, set_query) = signal(String::new());
+    let (regex_mode, set_regex_mode) = signal(false);
+    let (hidden_levels, set_hidden_levels) = signal(Vec::<LogLevel>::new());
+    let (stream_filter, set_stream_filter) = signal(None::<LogStream>);
+    let (current_match, set_current_match) = signal(None::<usize>);
+    let (selected, set_selected) = signal(None::<u64>);
+    // `LogRecord::text` of the records searched so far, by sequence number,
+    // so a search re-run for each appended record does not rebuild them all.
+    let text_cache = StoredValue::new(HashMap::<u64, String>::new());
+
+    let search_error = Memo::new(move |_| {
+        Search::new(&query.get(), regex_mode.get())
+            .error()
+            .map(str::to_string)
+    });
+
+    let visible = Memo::new(move |_| {
+        let search = Search::new(&query.get(), regex_mode.get());
+        let searching = !matches!(search, Search::All | Search::Invalid(_));
+        if current_log_type.get() == LogType::File {
+            return file_logs.with(|lines| {
+                if !searching {
+                    return Rows::All {
+                        count: lines.len(),
+                        last: None,
+                    };
+                }
+                Rows::Filtered(
+                    lines
+                        .iter()
+                        .enumerate()
+                        .filter_map(|(index, line)| Some((index as u64, search.find(line)?)))
+                        .collect(),
+                )
+            });
+        }
+        let hidden = hidden_levels.get();
+        let stream = stream_filter
+            .get()
+            .filter(|_| current_log_type.get() == LogType::RustFS);
+        let mut rows = Rows::Filtered(Vec::new());
+        if !searching && hidden.is_empty() && stream.is_none() {
+            with_records(&mut |logs| {
+                rows = Rows::All {
+                    count: logs.len(),
+                    last: logs.back().map(|record| record.seq),
+                };
+            });
+            return rows;
+        }
+        text_cache.update_value(|cache| {
+            with_records(&mut |logs| {
+                rows = Rows::Filtered(
+                    logs.iter()
+                        .filter(|record| !hidden.contains(&record.level))
+                        .filter(|record| stream.is_none_or(|stream| record.stream == stream))
+                        .filter_map(|record| {
+                            let matches = if searching {
+                                let text = cache.entry(record.seq).or_insert_with(|| record.text());
+                                search.find(text)?
+                            } else {
+                                Vec::new()
+                            };
+                            Some((record.seq, matches))
+                        })
+                        .collect(),
+                );
+                // Forget records that have since left the buffer.
+                if cache.len() > 2 * logs.len() {
+                    cache.retain(|seq, _| logs.binary_search_by_key(seq, |r| r.seq).is_ok());
+                }
+            });
+        });
+        rows
+    });
+
+    // Positions in `visible` of rows with a highlighted match.
+    let match_positions = Memo::new(move |_| {
+        visible.with(|rows| match rows {
+            Rows::All { .. } => Vec::new(),
+            Rows::Filtered(rows) => rows
+                .iter()
+                .enumerate()
+                .filter(|(_, (_, matches))| !matches.is_empty())
+                .map(|(position, _)| position)
+                .collect::<Vec<_>>(),
+        })
+    });
+    let current_row = Memo::new(move |_| {
+        let position =
+            match_positions.with(|positions| positions.get(current_match.get()?).copied())?;
+        visible.with(|rows| match rows {
+            Rows::All { .. } => None,
+            Rows::Filtered(rows) => rows.get(position).map(|(id, _)| *id),
+        })
+    });
+
+    // The slice of `visible` in or near the viewport: (first, last, total).
+    let window = Memo::new(move |_| {
+        let count = visible.with(Rows::len);
+        let first = ((scroll_top.get() / ROW_HEIGHT) as usize).saturating_sub(OVERSCAN);
+        let shown = (viewport_height.get() / ROW_HEIGHT).ceil() as usize + 2 * OVERSCAN;
+        (first.min(count), (first + shown).min(count), count)
+    });
+
+    let window_records = move || {
+        let (first, last, _) = window.get();
+        let rows = visible.with(|rows| match rows {
+            Rows::All { .. } => None,
+            Rows::Filtered(rows) => Some(rows[first..last].to_vec()),
+        });
+        let mut shown = Vec::new();
+        with_records(&mut |logs| {
+            shown = match &rows {
+                None => logs
+                    .range(first.min(logs.len())..last.min(logs.len()))
+                    .map(|record| (record.clone(), Vec::new()))
+                    .collect(),
+                Some(rows) => rows
+                    .iter()
+                    .filter_map(|(seq, matches)| {
+                        let index = logs.binary_search_by_key(seq, |r| r.seq).ok()?;
+                        Some((logs[index].clone(), matches.clone()))
+                    })
+                    .collect(),
+            };
+        });
+        shown
+    };
+
+    let window_lines = move || {
+        let (first, last, _) = window.get();
+        let rows = visible.with(|rows| match rows {
+            Rows::All { .. } => (first..last)
+                .map(|index| (index as u64, Vec::new()))
+                .collect(),
+            Rows::Filtered(rows) => rows[first..last].to_vec(),
+        });
+        file_logs.with(|lines| {
+            rows.into_iter()
+                .filter_map(|(index, matches)| {
+                    Some((index, lines.get(index as usize)?.clone(), matches))
+                })
+                .collect::<Vec<_>>()
+        })
+    };
+
+    let update_viewport = move || {
+        if let Some(element) = logs_ref.get_untracked() {
+            set_scroll_top.set(element.scroll_top() as f64);
+            set_viewport_height.set(element.client_height() as f64);
+        }
+    };
 
     // Auto-scroll effect
     Effect::new(move |_| {
-        // Track log changes
-        let _ = app_logs.get();
-        let _ = rustfs_logs.get();
+        // Track what is shown, not the buffers themselves
+        visible.track();
 
         if auto_scroll.get() {
             if let Some(element) = logs_ref.get() {
                 request_animation_frame(move || {
                     element.set_scroll_top(element.scroll_height());
+                    update_viewport();
                 });
             }
         }
     });
 
-    let clear_logs = move |_| {
-        set_app_logs.set(VecDeque::new());
-        set_rustfs_logs.set(VecDeque::new());
+    let goto_match = move |forward: bool| {
+        let count = match_positions.with_untracked(Vec::len);
+        if count == 0 {
+            return;
+        }
+        let next = match current_match.get_untracked() {
+            Some(i) if forward => (i + 1) % count,
+            Some(i) => (i + count - 1) % count,
+            None if forward => 0,
+            None => count - 1,
+        };
+        set_current_match.set(Some(next));
+        set_auto_scroll.set(false);
+        let position = match_positions.with_untracked(|positions| positions[next]);
+        if let Some(element) = logs_ref.get_untracked() {
+            let top = position as f64 * ROW_HEIGHT - viewport_height.get_untracked() / 2.0;
+            element.set_scroll_top(top.max(0.0) as i32);
+            update_viewport();
+        }
+    };
+
+    let match_count = move || {
+        if query.with(String::is_empty) || search_error.with(Option::is_some) {
+            return String::new();
+        }
+        let count = match_positions.with(Vec::len);
+        match current_match.get() {
+            Some(i) if i < count => format!("{}/{}", i + 1, count),
+            _ => format!("{} matches", count),
+        }
+    };
+
+    let on_select = Callback::new(move |seq: u64| {
+        set_selected.update(|selected| {
+            *selected = (*selected != Some(seq)).then_some(seq);
+        });
+    });
+    let selected_record = move || {
+        let seq = selected.get()?;
+        let mut record = None;
+        with_records(&mut |logs| {
+            record = logs
+                .binary_search_by_key(&seq, |r| r.seq)
+                .ok()
+                .map(|index| logs[index].clone());
+        });
+        record
     };
 
     view! {
@@ -52,8 +317,47 @@ pub fn LogViewer(
                     >
                         "RustFS Output"
                     </button>
+                    <button
+                        class="log-tab"
+                        class:active=move || current_log_type.get() == LogType::File
+                        on:click=move |_| {
+                            set_current_log_type.set(LogType::File);
+                            on_refresh_files.run(());
+                        }
+                    >
+                        "Log Files"
+                    </button>
                 </div>
                 <div class="log-actions">
+                    <Show when=move || current_log_type.get() == LogType::File>
+                        <select
+                            class="log-file-select"
+                            prop:value=move || selected_file.get().unwrap_or_default()
+                            on:change=move |ev| {
+                                let name = event_target_value(&ev);
+                                if !name.is_empty() {
+                                    on_open_file.run(name);
+                                }
+                            }
+                        >
+                            <option value="">"Choose a log file..."</option>
+                            <For
+                                each=move || log_files.get()
+                                key=|file| (file.name.clone(), file.size)
+                                let:file
+                            >
+                                <option value=file.name.clone()>
+                                    {format!(
+                                        "{} {} ({} KiB){}",
+                                        file.stream,
+                                        file.modified.get(..16).unwrap_or(&file.modified).replace('T', " "),
+                                        file.size.div_ceil(1024),
+                                        if file.active { ", current" } else { "" },
+                                    )}
+                                </option>
+                            </For>
+                        </select>
+                    </Show>
                     <label class="auto-scroll-toggle">
                         <input
                             type="checkbox"
@@ -62,35 +366,247 @@ pub fn LogViewer(
                         />
                         "Auto-scroll"
                     </label>
-                    <button class="clear-btn" on:click=clear_logs title="Clear Logs">
-                        "Clear"
-                    </button>
+                    <Show when=move || current_log_type.get() != LogType::File>
+                        <button class="clear-btn" on:click=clear_logs title="Clear Logs">
+                            "Clear"
+                        </button>
+                    </Show>
                 </div>
             </div>
-            <div class="log-output" node_ref=logs_ref>
-                <For
-                    each=move || {
-                        match current_log_type.get() {
-                            LogType::App => app_logs.get(),
-                            LogType::RustFS => rustfs_logs.get(),
+            <div class="log-filters">
+                <input
+                    type="search"
+                    class="log-search"
+                    class:field-error=move || search_error.with(Option::is_some)
+                    title=move || search_error.get().unwrap_or_default()
+                    placeholder=move || {
+                        if regex_mode.get() { "Search (regex)" } else { "Search" }
+                    }
+                    prop:value=move || query.get()
+                    on:input=move |ev| {
+                        set_query.set(event_target_value(&ev));
+                        set_current_match.set(None);
+                    }
+                    on:keydown=move |ev| {
+                        if ev.key() == "Enter" {
+                            goto_match(!ev.shift_key());
                         }
-                        .into_iter()
-                        .collect::<Vec<_>>()
                     }
-                    key=|log| log.clone()
-                    let:log
+                />
+                <label class="auto-scroll-toggle" title="Treat the search as a regular expression">
+                    <input
+                        type="checkbox"
+                        prop:checked=move || regex_mode.get()
+                        on:change=move |ev| {
+                            set_regex_mode.set(event_target_checked(&ev));
+                            set_current_match.set(None);
+                        }
+                    />
+                    ".*"
+                </label>
+                <span class="log-match-count">{match_count}</span>
+                <button
+                    type="button"
+                    class="log-nav-btn"
+                    title="Previous match (Shift+Enter)"
+                    on:click=move |_| goto_match(false)
+                >
+                    "\u{2191}"
+                </button>
+                <button
+                    type="button"
+                    class="log-nav-btn"
+                    title="Next match (Enter)"
+                    on:click=move |_| goto_match(true)
                 >
-                    <div class="log-line">{log}</div>
-                </For>
-                <Show when=move || {
-                    match current_log_type.get() {
-                        LogType::App => app_logs.get().is_empty(),
-                        LogType::RustFS => rustfs_logs.get().is_empty(),
+                    "\u{2193}"
+                </button>
+                <Show when=move || current_log_type.get() != LogType::File>
+                    <div class="log-level-toggles">
+                        {LEVELS
+                            .into_iter()
+                            .map(|level| {
+                                view! {
+                                    <button
+                                        type="button"
+                                        class=format!(
+                                            "log-level-toggle log-{}",
+                                            level.label().to_ascii_lowercase(),
+                                        )
+                                        class:active=move || {
+                                            !hidden_levels.with(|hidden| hidden.contains(&level))
+                                        }
+                                        on:click=move |_| {
+                                            set_hidden_levels
+                                                .update(|hidden| {
+                                                    match hidden.iter().position(|l| *l == level) {
+                                                        Some(i) => {
+                                                            hidden.remove(i);
+                                                        }
+                                                        None => hidden.push(level),
+                                                    }
+                                                });
+                                            set_current_match.set(None);
+                                        }
+                                    >
+                                        {level.label()}
+                                    </button>
+                                }
+                            })
+                            .collect_view()}
+                    </div>
+                </Show>
+                <Show when=move || current_log_type.get() == LogType::RustFS>
+                    <select
+                        class="log-file-select"
+                        prop:value=move || stream_filter.get().map_or("", |stream| stream.label())
+                        on:change=move |ev| {
+                            set_stream_filter
+                                .set(
+                                    match event_target_value(&ev).as_str() {
+                                        "stdout" => Some(LogStream::Stdout),
+                                        "stderr" => Some(LogStream::Stderr),
+                                        _ => None,
+                                    },
+                                );
+                            set_current_match.set(None);
+                        }
+                    >
+                        <option value="">"All output"</option>
+                        <option value="stdout">"stdout"</option>
+                        <option value="stderr">"stderr"</option>
+                    </select>
+                </Show>
+            </div>
+            <div class="log-output" node_ref=logs_ref on:scroll=move |_| update_viewport()>
+                <div style:height=move || {
+                    format!("{}px", window.get().0 as f64 * ROW_HEIGHT)
+                }></div>
+                <For
+                    each=window_records
+                    key=|(record, matches)| (record.seq, matches.clone())
+                    children=move |(record, matches)| {
+                        let seq = record.seq;
+                        view! {
+                            <LogLine
+                                record=record
+                                matches=matches
+                                current=Signal::derive(move || current_row.get() == Some(seq))
+                                selected=Signal::derive(move || selected.get() == Some(seq))
+                                on_select=on_select
+                            />
+                        }
                     }
-                }>
-                    <div class="empty-logs">"No logs available"</div>
+                />
+                <For
+                    each=window_lines
+                    key=|(index, line, matches)| (*index, line.clone(), matches.clone())
+                    children=move |(index, line, matches)| {
+                        view! {
+                            <div
+                                class="log-line"
+                                class:current-match=move || current_row.get() == Some(index)
+                                title=line.clone()
+                            >
+                                {highlight(&line, &matches)}
+                            </div>
+                        }
+                    }
+                />
+                <div style:height=move || {
+                    let (_, last, count) = window.get();
+                    format!("{}px", (count - last) as f64 * ROW_HEIGHT)
+                }></div>
+                <Show when=move || window.with(|(_, _, count)| *count == 0)>
+                    <div class="empty-logs">
+                        {move || {
+                            let mut buffered = false;
+                            with_records(&mut |logs| buffered = !logs.is_empty());
+                            if current_log_type.get() == LogType::File {
+                                buffered = !file_logs.with(Vec::is_empty);
+                            }
+                            if buffered { "No matching logs" } else { "No logs available" }
+                        }}
+                    </div>
                 </Show>
             </div>
+            {move || selected_record().map(|record| view! { <LogDetails record=record /> })}
+        </div>
+    }
+}
+
+/// One record on a single row: time, level, stream and target, then the
+/// message with search matches marked. Clicking selects it for the details
+/// pane.
+#[component]
+fn LogLine(
+    record: LogRecord,
+    matches: Matches,
+    /// Whether this is the match navigated to.
+    #[prop(into)]
+    current: Signal<bool>,
+    #[prop(into)] selected: Signal<bool>,
+    #[prop(into)] on_select: Callback<u64>,
+) -> impl IntoView {
+    let level = record.level.label();
+    let text = record.text();
+    let summary = highlight(&text, &matches);
+    let seq = record.seq;
+
+    view! {
+        <div
+            class=format!("log-line log-{}", level.to_ascii_lowercase())
+            class:current-match=move || current.get()
+            class:selected=move || selected.get()
+            title=text
+            on:click=move |_| on_select.run(seq)
+        >
+            <span class="log-time" title=record.timestamp.clone()>
+                {record.time_of_day().to_string()}
+            </span>
+            <span class="log-level">{level}</span>
+            {(record.stream != LogStream::Launcher)
+                .then(|| view! { <span class="log-stream">{record.stream.label()}</span> })}
+            {record.target.clone().map(|target| view! { <span class="log-target">{target}</span> })}
+            {summary}
+        </div>
+    }
+}
+
+/// Everything about the selected record, with the message unabridged.
+#[component]
+fn LogDetails(record: LogRecord) -> impl IntoView {
+    let context = [
+        ("time", Some(record.timestamp.clone())),
+        ("level", Some(record.level.label().to_string())),
+        ("stream", Some(record.stream.label().to_string())),
+        ("instance", record.instance.clone()),
+        ("target", record.target.clone()),
+        ("span", record.span.clone()),
+    ];
+    let fields = context
+        .into_iter()
+        .filter_map(|(name, value)| Some((name.to_string(), value?)))
+        .chain(
+            record
+                .fields
+                .iter()
+                .map(|field| (field.name.clone(), field.value.clone())),
+        )
+        .map(|(name, value)| {
+            view! {
+                <div class="log-field">
+                    <span class="log-field-name">{name}</span>
+                    <span class="log-field-value">{value}</span>
+                </div>
+            }
+        })
+        .collect_view();
+
+    view! {
+        <div class="log-details">
+            <div class="log-details-message">{record.message}</div>
+            {fields}
         </div>
     }
 }
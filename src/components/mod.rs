@@ -1,3 +1,14 @@
+pub mod binary_picker;
 pub mod config_form;
+pub mod config_transfer;
+pub mod credential_vault;
+pub mod instance_list;
+pub mod log_history_settings;
+pub mod log_search;
 pub mod log_viewer;
+pub mod orphan_banner;
+pub mod profile_picker;
+pub mod redaction_settings;
 pub mod toast;
+pub mod update_banner;
+pub mod version_manager;
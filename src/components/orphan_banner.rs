@@ -0,0 +1,55 @@
+use crate::types::OrphanRecord;
+use leptos::prelude::*;
+
+#[component]
+pub fn OrphanBanner(
+    #[prop(into)] orphans: Signal<Vec<OrphanRecord>>,
+    #[prop(into)] on_adopt: Callback<String>,
+    #[prop(into)] on_terminate: Callback<String>,
+) -> impl IntoView {
+    view! {
+        <Show when=move || !orphans.get().is_empty()>
+            <div class="orphan-banner">
+                <p class="orphan-title">"RustFS is still running from a previous session"</p>
+                <For
+                    each=move || orphans.get()
+                    key=|orphan| (orphan.pid, orphan.config.data_path.clone())
+                    let:orphan
+                >
+                    {
+                        let adopt_path = orphan.config.data_path.clone();
+                        let terminate_path = orphan.config.data_path.clone();
+                        view! {
+                            <div class="orphan-item">
+                                <span class="orphan-info">
+                                    {format!(
+                                        "'{}' (PID {}) on {}",
+                                        orphan.instance,
+                                        orphan.pid,
+                                        orphan.config.data_path,
+                                    )}
+                                </span>
+                                <div class="orphan-actions">
+                                    <button
+                                        type="button"
+                                        class="browse-btn"
+                                        on:click=move |_| on_adopt.run(adopt_path.clone())
+                                    >
+                                        "Reattach"
+                                    </button>
+                                    <button
+                                        type="button"
+                                        class="browse-btn"
+                                        on:click=move |_| on_terminate.run(terminate_path.clone())
+                                    >
+                                        "Terminate"
+                                    </button>
+                                </div>
+                            </div>
+                        }
+                    }
+                </For>
+            </div>
+        </Show>
+    }
+}
@@ -0,0 +1,101 @@
+use crate::types::ProfileList;
+use leptos::prelude::*;
+
+#[component]
+pub fn ProfilePicker(
+    #[prop(into)] profiles: Signal<ProfileList>,
+    #[prop(into)] active: Signal<Option<String>>,
+    #[prop(into)] on_select: Callback<String>,
+    #[prop(into)] on_create: Callback<String>,
+    #[prop(into)] on_save: Callback<()>,
+    #[prop(into)] on_clone: Callback<String>,
+    #[prop(into)] on_rename: Callback<String>,
+    #[prop(into)] on_delete: Callback<()>,
+    #[prop(into)] on_set_default: Callback<()>,
+) -> impl IntoView {
+    let (new_name, set_new_name) = signal(String::new());
+
+    // Runs `action` with the typed name, if any, and clears the input.
+    let with_name = move |action: Callback<String>| {
+        let name = new_name.get_untracked().trim().to_string();
+        if !name.is_empty() {
+            action.run(name);
+            set_new_name.set(String::new());
+        }
+    };
+    let has_active = move || active.get().is_some();
+    let is_default = move || {
+        let list = profiles.get();
+        active.get().is_some() && list.default_profile == active.get()
+    };
+
+    view! {
+        <div class="profile-picker">
+            <label for="profile-select">"Profile"</label>
+            <select
+                id="profile-select"
+                prop:value=move || active.get().unwrap_or_default()
+                on:change=move |ev| on_select.run(event_target_value(&ev))
+            >
+                <option value="">"(none)"</option>
+                <For
+                    each=move || {
+                        let list = profiles.get();
+                        list.profiles
+                            .into_iter()
+                            .map(|p| {
+                                let is_default = list.default_profile.as_deref() == Some(&p.name);
+                                (p.name, is_default)
+                            })
+                            .collect::<Vec<_>>()
+                    }
+                    key=|row| row.clone()
+                    let:row
+                >
+                    {
+                        let (name, is_default) = row;
+                        let label = if is_default { format!("{} ★", name) } else { name.clone() };
+                        view! { <option value=name>{label}</option> }
+                    }
+                </For>
+            </select>
+            <Show when=has_active>
+                <div class="profile-actions">
+                    <button type="button" class="browse-btn" on:click=move |_| on_save.run(())>
+                        "Save"
+                    </button>
+                    <button
+                        type="button"
+                        class="browse-btn"
+                        disabled=is_default
+                        on:click=move |_| on_set_default.run(())
+                    >
+                        "Make Default"
+                    </button>
+                    <button type="button" class="browse-btn" on:click=move |_| on_delete.run(())>
+                        "Delete"
+                    </button>
+                </div>
+            </Show>
+            <div class="profile-add">
+                <input
+                    type="text"
+                    placeholder="Profile name..."
+                    prop:value=move || new_name.get()
+                    on:input=move |ev| set_new_name.set(event_target_value(&ev))
+                />
+                <button type="button" class="browse-btn" on:click=move |_| with_name(on_create)>
+                    "New"
+                </button>
+                <Show when=has_active>
+                    <button type="button" class="browse-btn" on:click=move |_| with_name(on_clone)>
+                        "Clone"
+                    </button>
+                    <button type="button" class="browse-btn" on:click=move |_| with_name(on_rename)>
+                        "Rename"
+                    </button>
+                </Show>
+            </div>
+        </div>
+    }
+}
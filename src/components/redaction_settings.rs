@@ -0,0 +1,42 @@
+use leptos::prelude::*;
+
+#[component]
+pub fn RedactionSettings(
+    #[prop(into)] patterns: Signal<Vec<String>>,
+    #[prop(into)] error: Signal<Option<String>>,
+    #[prop(into)] on_apply: Callback<Vec<String>>,
+) -> impl IntoView {
+    let (draft, set_draft) = signal(patterns.get_untracked().join("\n"));
+
+    // Pick up the saved patterns once they have been loaded.
+    Effect::new(move |_| set_draft.set(patterns.get().join("\n")));
+
+    let apply = move |_| {
+        let lines = draft
+            .get()
+            .lines()
+            .map(|line| line.trim().to_string())
+            .filter(|line| !line.is_empty())
+            .collect();
+        on_apply.run(lines);
+    };
+
+    view! {
+        <details class="redaction-settings">
+            <summary>"Log Redaction"</summary>
+            <p class="redaction-hint">
+                "Secret keys are always masked. Add one regular expression per line to mask anything else before it reaches the logs."
+            </p>
+            <textarea
+                rows="3"
+                placeholder="e.g. internal-[a-z0-9]+"
+                prop:value=move || draft.get()
+                on:input=move |ev| set_draft.set(event_target_value(&ev))
+            ></textarea>
+            {move || error.get().map(|message| view! { <div class="field-error">{message}</div> })}
+            <button type="button" class="browse-btn" on:click=apply>
+                "Apply"
+            </button>
+        </details>
+    }
+}
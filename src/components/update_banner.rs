@@ -0,0 +1,53 @@
+use crate::types::UpdateInfo;
+use leptos::prelude::*;
+
+#[component]
+pub fn UpdateBanner(
+    #[prop(into)] update: Signal<Option<UpdateInfo>>,
+    #[prop(into)] upgrading: Signal<bool>,
+    #[prop(into)] on_upgrade: Callback<()>,
+) -> impl IntoView {
+    let available = move || update.get().filter(|u| u.available);
+
+    view! {
+        <Show when=move || available().is_some()>
+            <div class="update-banner">
+                <p class="update-title">
+                    {move || {
+                        available()
+                            .map(|u| {
+                                format!(
+                                    "RustFS {} is available (running {})",
+                                    u.latest,
+                                    u.current.unwrap_or_else(|| "unknown".to_string()),
+                                )
+                            })
+                    }}
+                </p>
+                {move || {
+                    available()
+                        .and_then(|u| u.release_url)
+                        .map(|url| view! { <p class="update-notes">{url}</p> })
+                }}
+                <Show
+                    when=move || available().is_some_and(|u| u.asset.is_some())
+                    fallback=|| {
+                        view! {
+                            <p class="update-notes">"No download for this platform in this release"</p>
+                        }
+                    }
+                >
+                    <button
+                        type="button"
+                        class="browse-btn"
+                        title="Download, stop RustFS, switch binaries and start it again"
+                        disabled=move || upgrading.get()
+                        on:click=move |_| on_upgrade.run(())
+                    >
+                        {move || if upgrading.get() { "Upgrading..." } else { "Upgrade & Restart" }}
+                    </button>
+                </Show>
+            </div>
+        </Show>
+    }
+}
@@ -0,0 +1,210 @@
+use crate::types::{DownloadEvent, DownloadPhase, UpdateInfo, VersionList};
+use leptos::prelude::*;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::spawn_local;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "dialog"])]
+    async fn open(options: JsValue) -> JsValue;
+}
+
+#[component]
+pub fn VersionManager(
+    #[prop(into)] versions: Signal<VersionList>,
+    #[prop(into)] on_install: Callback<String>,
+    #[prop(into)] on_activate: Callback<String>,
+    #[prop(into)] on_rollback: Callback<()>,
+    /// Release mirror base URL; empty for the official release site.
+    #[prop(into)]
+    release_url: Signal<String>,
+    set_release_url: WriteSignal<String>,
+    #[prop(into)] download: Signal<Option<DownloadEvent>>,
+    /// Downloads the given version, or `latest` when empty.
+    #[prop(into)]
+    on_download: Callback<String>,
+    /// Releases feed URL; empty for the official GitHub releases.
+    #[prop(into)]
+    feed_url: Signal<String>,
+    set_feed_url: WriteSignal<String>,
+    #[prop(into)] update: Signal<Option<UpdateInfo>>,
+    #[prop(into)] on_check_updates: Callback<()>,
+) -> impl IntoView {
+    let (download_version, set_download_version) = signal(String::new());
+    let downloading = move || {
+        download.with(|d| {
+            d.as_ref().is_some_and(|d| {
+                matches!(
+                    d.phase,
+                    DownloadPhase::Downloading | DownloadPhase::Installing
+                )
+            })
+        })
+    };
+
+    let download_status = move || {
+        download.get().map(|d| {
+            let text = match d.phase {
+                DownloadPhase::Downloading => match d.total {
+                    Some(total) if total > 0 => {
+                        format!("Downloading {}: {}%", d.asset, d.downloaded * 100 / total)
+                    }
+                    _ => format!("Downloading {}: {} KiB", d.asset, d.downloaded / 1024),
+                },
+                _ => d.message.clone(),
+            };
+            let failed = d.phase == DownloadPhase::Failed;
+            view! {
+                <Show when=move || d.phase == DownloadPhase::Downloading>
+                    <progress
+                        class="download-progress"
+                        max=d.total.unwrap_or_default() as f64
+                        value=d.downloaded as f64
+                    />
+                </Show>
+                <div class="binary-info" class:field-error=failed>{text}</div>
+            }
+        })
+    };
+
+    let install = move |_| {
+        spawn_local(async move {
+            let options = serde_wasm_bindgen::to_value(&serde_json::json!({
+                "title": "Install RustFS Release",
+                "filters": [{ "name": "RustFS Release", "extensions": ["zip", "gz", "tgz"] }]
+            }))
+            .unwrap();
+            if let Some(path) = open(options).await.as_string() {
+                if !path.is_empty() {
+                    on_install.run(path);
+                }
+            }
+        });
+    };
+
+    view! {
+        <details class="version-manager">
+            <summary>
+                "RustFS Versions"
+                <Show when=move || update.get().is_some_and(|u| u.available)>
+                    <span class="update-badge">"Update"</span>
+                </Show>
+            </summary>
+            <Show
+                when=move || versions.with(|list| !list.versions.is_empty())
+                fallback=|| view! { <p class="version-empty">"No managed versions installed"</p> }
+            >
+                <ul class="version-list">
+                    <For
+                        each=move || versions.get().versions
+                        key=|v| v.version.clone()
+                        children=move |installed| {
+                            let version = installed.version.clone();
+                            let is_active = {
+                                let version = version.clone();
+                                move || versions.with(|list| list.active.as_ref() == Some(&version))
+                            };
+                            let target = match (installed.os, installed.arch) {
+                                (Some(os), Some(arch)) => format!("{}-{}", os, arch),
+                                (Some(os), None) => os,
+                                _ => String::new(),
+                            };
+                            let item_active = is_active.clone();
+                            view! {
+                                <li class="version-item" class:active=item_active>
+                                    <span class="version-name">{installed.version}</span>
+                                    <span class="version-meta" title=installed.source>
+                                        {target}
+                                    </span>
+                                    <Show
+                                        when=is_active
+                                        fallback=move || {
+                                            let version = version.clone();
+                                            view! {
+                                                <button
+                                                    type="button"
+                                                    class="browse-btn"
+                                                    on:click=move |_| on_activate.run(version.clone())
+                                                >
+                                                    "Use"
+                                                </button>
+                                            }
+                                        }
+                                    >
+                                        <span class="version-active">"Active"</span>
+                                    </Show>
+                                </li>
+                            }
+                        }
+                    />
+                </ul>
+            </Show>
+            <div class="form-group">
+                <label for="release-url">"Release Mirror"</label>
+                <input
+                    id="release-url"
+                    type="text"
+                    placeholder="https://dl.rustfs.com/artifacts/rustfs/release"
+                    prop:value=move || release_url.get()
+                    on:change=move |ev| set_release_url.set(event_target_value(&ev).trim().to_string())
+                />
+            </div>
+            <div class="form-group">
+                <label for="feed-url">"Releases Feed"</label>
+                <input
+                    id="feed-url"
+                    type="text"
+                    placeholder="https://api.github.com/repos/rustfs/rustfs/releases"
+                    prop:value=move || feed_url.get()
+                    on:change=move |ev| set_feed_url.set(event_target_value(&ev).trim().to_string())
+                />
+            </div>
+            <div class="path-input-group">
+                <input
+                    type="text"
+                    placeholder="latest"
+                    prop:value=move || download_version.get()
+                    on:input=move |ev| set_download_version.set(event_target_value(&ev))
+                />
+                <button
+                    type="button"
+                    class="browse-btn"
+                    disabled=downloading
+                    on:click=move |_| on_download.run(download_version.get_untracked())
+                >
+                    "Download"
+                </button>
+            </div>
+            {download_status}
+            <div class="transfer-actions">
+                <button type="button" class="browse-btn" on:click=install>
+                    "Install from Archive..."
+                </button>
+                <button
+                    type="button"
+                    class="browse-btn"
+                    on:click=move |_| on_check_updates.run(())
+                >
+                    "Check for Updates"
+                </button>
+                {move || {
+                    versions
+                        .get()
+                        .previous
+                        .map(|previous| {
+                            view! {
+                                <button
+                                    type="button"
+                                    class="browse-btn"
+                                    title=format!("Switch back to {}", previous)
+                                    on:click=move |_| on_rollback.run(())
+                                >
+                                    "Roll Back"
+                                </button>
+                            }
+                        })
+                }}
+            </div>
+        </details>
+    }
+}
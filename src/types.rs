@@ -1,36 +1,106 @@
+pub use rustfs_launcher_shared::config::{BinarySource, RestartPolicy, RustFsConfig};
+pub use rustfs_launcher_shared::events::{
+    DownloadEvent, DownloadPhase, InstanceExitEvent, InstanceStateEvent, LifecycleEvent,
+    LifecyclePhase, ProcessState, SupervisorEvent, SupervisorState,
+};
+pub use rustfs_launcher_shared::validation::{ConfigField, Severity, ValidationReport};
+pub use rustfs_launcher_shared::{
+    BinaryInfo, CommandError, CommandResponse, InstalledVersion, LogCapacity, LogFileInfo,
+    LogLevel, LogRecord, LogStream, UpdateInfo, UpgradeOutcome, VersionList,
+};
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-pub struct RustFsConfig {
-    pub data_path: String,
-    pub port: Option<u16>,
-    pub host: Option<String>,
-    pub access_key: Option<String>,
-    pub secret_key: Option<String>,
-    pub console_enable: bool,
-}
-
-impl Default for RustFsConfig {
-    fn default() -> Self {
-        Self {
-            data_path: String::new(),
-            port: Some(9000),
-            host: Some("127.0.0.1".to_string()),
-            access_key: Some("rustfsadmin".to_string()),
-            secret_key: Some("rustfsadmin".to_string()),
-            console_enable: false,
-        }
-    }
-}
-
 #[derive(Clone, Copy, PartialEq, Debug)]
 pub enum LogType {
     App,
     RustFS,
+    /// A log file kept on disk, possibly from an earlier session.
+    File,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct InstanceInfo {
+    pub name: String,
+    pub running: bool,
+    pub state: ProcessState,
+    pub pid: Option<u32>,
+    #[serde(default)]
+    pub profile: Option<String>,
+    pub config: RustFsConfig,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct OrphanRecord {
+    pub pid: u32,
+    pub instance: String,
+    pub config: RustFsConfig,
+    pub started_at: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct PortOwner {
+    pub pid: u32,
+    pub name: Option<String>,
+}
+
+impl std::fmt::Display for PortOwner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.name {
+            Some(name) => write!(f, "{} (PID {})", name, self.pid),
+            None => write!(f, "PID {}", self.pid),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct PortCheck {
+    pub available: bool,
+    pub owner: Option<PortOwner>,
+    pub suggested_port: Option<u16>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum KeySource {
+    Passphrase,
+    KeyFile,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct CredentialStatus {
+    pub exists: bool,
+    pub unlocked: bool,
+    pub key_source: Option<KeySource>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Credentials {
+    pub access_key: Option<String>,
+    pub secret_key: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Profile {
+    pub name: String,
+    pub config: RustFsConfig,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+pub struct ProfileList {
+    pub profiles: Vec<Profile>,
+    pub default_profile: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum SecretMode {
+    Exclude,
+    Placeholder,
+    Include,
 }
 
-#[derive(Debug, Deserialize)]
-pub struct CommandResponse {
-    pub success: bool,
-    pub message: String,
+#[derive(Debug, Clone, Deserialize)]
+pub struct ImportSummary {
+    pub config: Option<RustFsConfig>,
+    pub profiles: Vec<String>,
 }